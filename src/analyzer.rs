@@ -1,34 +1,44 @@
-use std::collections::BTreeSet;
+use std::rc::Rc;
 
 use line_index::LineIndex;
 use oxc::{
   allocator::Allocator,
-  ast::{ast::Program, AstBuilder},
-  semantic::{Semantic, SymbolId},
+  ast::{
+    ast::{Program, Statement},
+    AstBuilder,
+  },
+  semantic::{ScopeId, Semantic, SymbolId},
   span::{GetSpan, Span, SPAN},
 };
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
   builtins::Builtins,
   config::Config,
+  diagnostic::{Diagnostic, DiagnosticCode},
+  query::SignatureHandle,
   scope::{
     call::CallScope,
     control::CfScopeKind,
     r#type::TypeScopeTree,
     runtime::{RuntimeScope, RuntimeScopeTree},
   },
-  ty::{accumulator::TypeAccumulator, ctx::CtxTy, Ty},
+  ty::{
+    accumulator::TypeAccumulator, ctx::CtxTy, interner::TypeInterner, namespace::NamespaceType, Ty,
+  },
 };
 
 pub struct Analyzer<'a> {
   pub allocator: &'a Allocator,
   pub config: &'a Config,
   pub line_index: LineIndex,
-  pub semantic: Semantic<'a>,
+  /// Shared, so that other tools (e.g. a linter) can work on the same semantic
+  pub semantic: Rc<Semantic<'a>>,
   pub ast_builder: AstBuilder<'a>,
 
   pub builtins: Builtins<'a>,
+  /// Structural types are allocated via the interner, so that equal types share one allocation
+  pub interner: TypeInterner<'a>,
 
   pub span_stack: Vec<Span>,
   pub call_scopes: Vec<CallScope<'a>>,
@@ -39,15 +49,41 @@ pub struct Analyzer<'a> {
   pub variables: FxHashMap<SymbolId, Ty<'a>>,
   /// Generic parameter with its constraint
   pub generic_constraints: FxHashMap<SymbolId, CtxTy<'a>>,
+  /// Overload signatures of function declarations
+  pub function_overloads: FxHashMap<SymbolId, Vec<Ty<'a>>>,
+  /// Signatures of `declare function` without an implementation, which have no symbols. Looked up
+  /// by name from the scope of the reference.
+  pub ambient_functions: FxHashMap<(ScopeId, &'a str), Vec<Ty<'a>>>,
+  /// The type `E` of each enum declaration, which is the union of its members
+  pub enums: FxHashMap<SymbolId, Ty<'a>>,
+  /// Namespaces, with all the declarations of the same symbol merged
+  pub namespaces: FxHashMap<SymbolId, &'a NamespaceType<'a>>,
+  /// Modules declared with `declare module "m" {}`, by specifier. Imports of them read their
+  /// exports
+  pub module_augmentations: FxHashMap<&'a str, &'a NamespaceType<'a>>,
+  /// Types declared in `declare global` blocks, by name
+  pub global_types: FxHashMap<&'a str, Ty<'a>>,
   pub type_placeholder_count: usize,
+  /// Pairs of interfaces being matched, which are assumed to match when visited again
+  pub matching_interfaces: Vec<(Ty<'a>, Ty<'a>)>,
+  /// Generic instances being unwrapped within each other
+  pub instantiation_depth: usize,
+  /// Generic instances unwrapped so far
+  pub instantiation_count: usize,
 
-  pub diagnostics: BTreeSet<String>,
+  /// In the order of reporting. See `sorted_diagnostics`
+  pub diagnostics: Vec<Diagnostic>,
+  reported: FxHashSet<Diagnostic>,
   pub span_to_type: FxHashMap<Span, TypeAccumulator<'a>>,
+  /// Types expected by the context of expressions
+  pub contextual_types: FxHashMap<Span, TypeAccumulator<'a>>,
+  /// Signatures chosen for call and `new` expressions
+  pub call_signatures: FxHashMap<Span, SignatureHandle<'a>>,
   pub pos_to_span: &'a mut [Span],
 }
 
 impl<'a> Analyzer<'a> {
-  pub fn new(allocator: &'a Allocator, config: Config, semantic: Semantic<'a>) -> Self {
+  pub fn new(allocator: &'a Allocator, config: Config, semantic: Rc<Semantic<'a>>) -> Self {
     let config = allocator.alloc(config);
 
     let mut runtime_scopes = RuntimeScopeTree::default();
@@ -58,12 +94,12 @@ impl<'a> Analyzer<'a> {
     });
 
     let root_call_scope =
-      CallScope::new(root_scope, true, false, /* TODO: globalThis */ Ty::Any, None);
+      CallScope::new(root_scope, true, false, /* TODO: globalThis */ Ty::Any, None, None);
 
     let ast_builder = AstBuilder::new(allocator);
     let pos_to_expr = allocator.alloc_slice_fill_default(semantic.source_text().len());
 
-    Analyzer {
+    let mut analyzer = Analyzer {
       allocator,
       config,
       line_index: LineIndex::new(semantic.source_text()),
@@ -71,6 +107,7 @@ impl<'a> Analyzer<'a> {
       ast_builder,
 
       builtins: Builtins::new(),
+      interner: Default::default(),
 
       span_stack: Vec::new(),
       call_scopes: Vec::from([root_call_scope]),
@@ -79,16 +116,42 @@ impl<'a> Analyzer<'a> {
 
       variables: Default::default(),
       generic_constraints: Default::default(),
+      function_overloads: Default::default(),
+      ambient_functions: Default::default(),
+      enums: Default::default(),
+      namespaces: Default::default(),
+      module_augmentations: Default::default(),
+      global_types: Default::default(),
       type_placeholder_count: 0,
+      matching_interfaces: Vec::new(),
+      instantiation_depth: 0,
+      instantiation_count: 0,
 
       diagnostics: Default::default(),
+      reported: Default::default(),
       span_to_type: Default::default(),
+      contextual_types: Default::default(),
+      call_signatures: Default::default(),
       pos_to_span: pos_to_expr,
-    }
+    };
+    analyzer.builtins.object_prototype = analyzer.create_object_prototype();
+    analyzer.builtins.string_prototype = analyzer.create_string_prototype();
+    analyzer.builtins.number_prototype = analyzer.create_number_prototype();
+    analyzer.builtins.boolean_prototype = analyzer.create_boolean_prototype();
+    analyzer
   }
 
   pub fn exec_program(&mut self, node: &'a Program<'a>) {
-    self.exec_statement_vec(&node.body);
+    for statement in &node.body {
+      self.declare_statement(statement);
+    }
+    // Module declarations are ambient, and their exports are read by imports before them
+    let (modules, statements): (Vec<_>, Vec<_>) = node.body.iter().partition(|statement| {
+      matches!(statement, Statement::TSModuleDeclaration(node) if node.id.is_string_literal())
+    });
+    for statement in modules.into_iter().chain(statements) {
+      self.init_statement(statement);
+    }
 
     assert_eq!(self.runtime_scopes.stack.len(), 1);
 
@@ -102,13 +165,40 @@ impl<'a> Analyzer<'a> {
     *self.span_stack.last().unwrap()
   }
 
-  pub fn add_diagnostic(&mut self, message: impl Into<String>) {
+  /// Reports an error at the current span.
+  pub fn add_diagnostic(&mut self, code: DiagnosticCode, message: impl Into<String>) {
     let span = self.current_span();
-    let start = self.line_index.line_col(span.start.into());
-    let end = self.line_index.line_col(span.end.into());
-    let span_text =
-      format!(" at {}:{}-{}:{}", start.line + 1, start.col + 1, end.line + 1, end.col + 1);
-    self.diagnostics.insert(message.into() + &span_text);
+    self.report(Diagnostic::error(code, message, span));
+  }
+
+  pub fn report(&mut self, diagnostic: Diagnostic) {
+    // The same node may be executed more than once
+    if self.reported.insert(diagnostic.clone()) {
+      self.diagnostics.push(diagnostic);
+    }
+  }
+
+  /// All diagnostics in the order of their spans.
+  pub fn sorted_diagnostics(&self) -> Vec<&Diagnostic> {
+    let mut diagnostics = self.diagnostics.iter().collect::<Vec<_>>();
+    diagnostics.sort_by_key(|d| (d.span.start, d.span.end));
+    diagnostics
+  }
+
+  /// Renders all diagnostics with source snippets.
+  pub fn render_diagnostics(&self, file_name: &str) -> String {
+    let source_text = self.semantic.source_text();
+    self.sorted_diagnostics().into_iter().map(|d| d.render(file_name, source_text)).collect()
+  }
+
+  /// Serializes all diagnostics as a JSON array.
+  pub fn diagnostics_to_json(&self) -> String {
+    let items = self
+      .sorted_diagnostics()
+      .into_iter()
+      .map(|d| d.to_json(&self.line_index))
+      .collect::<Vec<_>>();
+    format!("[{}]", items.join(","))
   }
 
   pub fn push_span(&mut self, node: &impl GetSpan) {
@@ -119,30 +209,52 @@ impl<'a> Analyzer<'a> {
     self.span_stack.pop();
   }
 
-  pub fn resolve_module(&mut self, specifier: &'a str) -> Option<()> {
-    todo!()
+  /// Handles a construct which is not supported yet. With `Config::error_recovery`, it reports a
+  /// diagnostic and returns `Ty::Error`, so that the analysis continues. Otherwise, it panics.
+  #[track_caller]
+  pub fn unsupported(&mut self, construct: &str) -> Ty<'a> {
+    if !self.config.error_recovery {
+      panic!("not yet implemented: {construct}");
+    }
+    let span = self.span_stack.last().copied().unwrap_or(SPAN);
+    let message = format!("Unsupported construct: {construct}");
+    self.report(Diagnostic::warning(DiagnosticCode::Unsupported, message, span));
+    Ty::Error
   }
 
-  pub fn resolve_global_variable(&mut self, id: &'a str) -> Ty<'a> {
-    todo!()
+  pub fn resolve_module(&mut self, _specifier: &'a str) -> Option<()> {
+    self.unsupported("module resolution");
+    None
   }
 
-  pub fn resolve_global_type(&mut self, id: &'a str) -> Ty<'a> {
-    todo!()
+  pub fn resolve_global_variable(&mut self, _id: &'a str) -> Ty<'a> {
+    self.unsupported("global variables")
+  }
+
+  pub fn resolve_global_type(&mut self, _id: &'a str) -> Ty<'a> {
+    self.unsupported("global types")
   }
 
   pub fn accumulate_type(&mut self, span: &impl GetSpan, ty: Ty<'a>) {
     let Analyzer { allocator, span_to_type: expr_types, pos_to_span: pos_to_expr, .. } = self;
     let span = span.span();
     let acc = expr_types.entry(span).or_insert_with(move || {
+      // The innermost span wins, since outer expressions like functions are accumulated last
       for pos in span.start..span.end {
-        pos_to_expr[pos as usize] = span;
+        let current = &mut pos_to_expr[pos as usize];
+        if *current == SPAN || (current.start <= span.start && span.end <= current.end) {
+          *current = span;
+        }
       }
       TypeAccumulator::default()
     });
     acc.add(ty, allocator);
   }
 
+  pub fn accumulate_contextual_type(&mut self, span: Span, ty: Ty<'a>) {
+    self.contextual_types.entry(span).or_default().add(ty, self.allocator);
+  }
+
   pub fn get_type_by_pos(&mut self, pos: usize) -> Option<Ty<'a>> {
     let span = self.pos_to_span[pos];
     if span == SPAN {
@@ -151,4 +263,8 @@ impl<'a> Analyzer<'a> {
       self.span_to_type.get_mut(&span).unwrap().to_ty()
     }
   }
+
+  pub fn get_type_by_span(&mut self, span: Span) -> Option<Ty<'a>> {
+    self.span_to_type.get_mut(&span).and_then(|acc| acc.to_ty())
+  }
 }
@@ -1,6 +1,17 @@
 mod globals;
+mod object;
+mod primitives;
+mod promise;
 
-use crate::ty::Ty;
+use rustc_hash::FxHashMap;
+
+use crate::{
+  analyzer::Analyzer,
+  ty::{
+    callable::CallableType, ctx::CtxTy, interface::InterfaceType, property_key::PropertyKeyType,
+    record::RecordTypeBuilder, Ty,
+  },
+};
 
 pub struct Builtins<'a> {
   pub string_prototype: Ty<'a>,
@@ -11,20 +22,67 @@ pub struct Builtins<'a> {
   pub function_prototype: Ty<'a>,
   pub array_prototype: Ty<'a>,
   pub symbol_prototype: Ty<'a>,
+  /// The instances of `Promise<T>`, keyed by `T`
+  pub promises: FxHashMap<Ty<'a>, &'a InterfaceType<'a>>,
+}
+
+impl Default for Builtins<'_> {
+  fn default() -> Self {
+    Self::new()
+  }
 }
 
 impl<'a> Builtins<'a> {
-  // TODO: Implement this
+  // TODO: Implement the rest. The prototypes which are implemented are created by `Analyzer::new`.
+  // The missing ones are `Ty::Error` rather than `Ty::Any`, so that reading their members is not
+  // mistaken for a genuine `any`.
   pub fn new() -> Self {
     Self {
-      string_prototype: Ty::Any,
-      number_prototype: Ty::Any,
-      bigint_prototype: Ty::Any,
-      boolean_prototype: Ty::Any,
-      object_prototype: Ty::Any,
-      function_prototype: Ty::Any,
-      array_prototype: Ty::Any,
-      symbol_prototype: Ty::Any,
+      string_prototype: Ty::Error,
+      number_prototype: Ty::Error,
+      bigint_prototype: Ty::Error,
+      boolean_prototype: Ty::Error,
+      object_prototype: Ty::Error,
+      function_prototype: Ty::Error,
+      array_prototype: Ty::Error,
+      symbol_prototype: Ty::Error,
+      promises: Default::default(),
     }
   }
 }
+
+impl<'a> Analyzer<'a> {
+  /// Creates a prototype record from its properties, and methods with at most one parameter.
+  fn create_prototype(
+    &mut self,
+    properties: &[(&str, Ty<'a>)],
+    methods: &[(&str, Option<Ty<'a>>, Ty<'a>)],
+  ) -> Ty<'a> {
+    let mut prototype = RecordTypeBuilder::default();
+    for &(name, value) in properties {
+      let key = PropertyKeyType::StringLiteral(self.allocator.alloc(self.ast_builder.atom(name)));
+      prototype.init_property(self, key, value, false, true);
+    }
+    for &(name, param, return_type) in methods {
+      let method =
+        self.create_method(param.map(|ty| (false, ty)).into_iter().collect(), return_type);
+      let key = PropertyKeyType::StringLiteral(self.allocator.alloc(self.ast_builder.atom(name)));
+      prototype.init_property(self, key, method, false, false);
+    }
+    self.intern_record(prototype.build())
+  }
+
+  /// A non-generic method with the given (optional, type) parameters.
+  fn create_method(&mut self, params: Vec<(bool, Ty<'a>)>, return_type: Ty<'a>) -> Ty<'a> {
+    self.intern_function(CallableType {
+      is_method: true,
+      scope: self.type_scopes.empty_scope,
+      type_params: vec![],
+      this_param: None,
+      params: params.into_iter().map(|(optional, ty)| (optional, CtxTy::Static(ty))).collect(),
+      rest_param: None,
+      return_type: CtxTy::Static(return_type),
+      param_names: vec![],
+    })
+  }
+}
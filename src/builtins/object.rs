@@ -0,0 +1,18 @@
+use crate::{analyzer::Analyzer, ty::Ty};
+
+impl<'a> Analyzer<'a> {
+  /// The members of `Object.prototype`, which are inherited by object types that do not declare
+  /// them.
+  pub fn create_object_prototype(&mut self) -> Ty<'a> {
+    let property_key = self.into_union([Ty::String, Ty::Number, Ty::Symbol]).unwrap();
+    let methods = [
+      ("toString", None, Ty::String),
+      ("toLocaleString", None, Ty::String),
+      ("valueOf", None, Ty::Object),
+      ("hasOwnProperty", Some(property_key), Ty::Boolean),
+      ("isPrototypeOf", Some(Ty::Object), Ty::Boolean),
+      ("propertyIsEnumerable", Some(property_key), Ty::Boolean),
+    ];
+    self.create_prototype(&[], &methods)
+  }
+}
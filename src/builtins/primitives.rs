@@ -0,0 +1,42 @@
+use crate::{analyzer::Analyzer, ty::Ty};
+
+impl<'a> Analyzer<'a> {
+  /// The commonly used members of `String.prototype`. The others are read as `Ty::Error`.
+  pub fn create_string_prototype(&mut self) -> Ty<'a> {
+    let methods = [
+      ("toString", None, Ty::String),
+      ("valueOf", None, Ty::String),
+      ("charAt", Some(Ty::Number), Ty::String),
+      ("charCodeAt", Some(Ty::Number), Ty::Number),
+      ("indexOf", Some(Ty::String), Ty::Number),
+      ("lastIndexOf", Some(Ty::String), Ty::Number),
+      ("includes", Some(Ty::String), Ty::Boolean),
+      ("startsWith", Some(Ty::String), Ty::Boolean),
+      ("endsWith", Some(Ty::String), Ty::Boolean),
+      ("toLowerCase", None, Ty::String),
+      ("toUpperCase", None, Ty::String),
+      ("trim", None, Ty::String),
+      ("trimStart", None, Ty::String),
+      ("trimEnd", None, Ty::String),
+    ];
+    self.create_prototype(&[("length", Ty::Number)], &methods)
+  }
+
+  /// The commonly used members of `Number.prototype`. The others are read as `Ty::Error`.
+  pub fn create_number_prototype(&mut self) -> Ty<'a> {
+    let methods = [
+      ("toString", None, Ty::String),
+      ("valueOf", None, Ty::Number),
+      ("toFixed", Some(Ty::Number), Ty::String),
+      ("toPrecision", Some(Ty::Number), Ty::String),
+      ("toExponential", Some(Ty::Number), Ty::String),
+    ];
+    self.create_prototype(&[], &methods)
+  }
+
+  /// The members of `Boolean.prototype`.
+  pub fn create_boolean_prototype(&mut self) -> Ty<'a> {
+    let methods = [("toString", None, Ty::String), ("valueOf", None, Ty::Boolean)];
+    self.create_prototype(&[], &methods)
+  }
+}
@@ -0,0 +1,61 @@
+use crate::{
+  analyzer::Analyzer,
+  ty::{
+    callable::CallableType,
+    ctx::CtxTy,
+    interface::{InterfaceType, InterfaceTypeInner},
+    property_key::PropertyKeyType,
+    record::RecordTypeBuilder,
+    Ty,
+  },
+};
+
+impl<'a> Analyzer<'a> {
+  /// `Promise<T>`, which is fulfilled with `value`. The callbacks of `then` and `catch` are not
+  /// generic, so chained promises are fulfilled with `unknown`.
+  pub fn get_promise_type(&mut self, value: Ty<'a>) -> Ty<'a> {
+    if let Some(promise) = self.builtins.promises.get(&value) {
+      return Ty::Interface(promise);
+    }
+    let name = self.allocator.alloc(self.ast_builder.atom("Promise"));
+    let promise = &*self.allocator.alloc(InterfaceType::builtin(
+      name,
+      self.type_scopes.empty_scope,
+      vec![value],
+    ));
+    // `finally` returns the promise itself
+    self.builtins.promises.insert(value, promise);
+
+    let on_fulfilled = self.create_callback(Some(value));
+    let on_rejected = self.create_callback(Some(Ty::Any));
+    let on_finally = self.create_callback(None);
+    let chained = self.get_promise_type(Ty::Unknown);
+    let methods = [
+      ("then", vec![(true, on_fulfilled), (true, on_rejected)], chained),
+      ("catch", vec![(true, on_rejected)], chained),
+      ("finally", vec![(true, on_finally)], Ty::Interface(promise)),
+    ];
+    let mut record = RecordTypeBuilder::default();
+    for (name, params, return_type) in methods {
+      let method = self.create_method(params, return_type);
+      let key = PropertyKeyType::StringLiteral(self.allocator.alloc(self.ast_builder.atom(name)));
+      record.init_property(self, key, method, false, false);
+    }
+    promise.set_builtin_members(InterfaceTypeInner { record: record.build(), callables: vec![] });
+    Ty::Interface(promise)
+  }
+
+  /// `(param) => unknown`
+  fn create_callback(&mut self, param: Option<Ty<'a>>) -> Ty<'a> {
+    self.intern_function(CallableType {
+      is_method: false,
+      scope: self.type_scopes.empty_scope,
+      type_params: vec![],
+      this_param: None,
+      params: param.map(|ty| (false, CtxTy::Static(ty))).into_iter().collect(),
+      rest_param: None,
+      return_type: CtxTy::Static(Ty::Unknown),
+      param_names: vec![],
+    })
+  }
+}
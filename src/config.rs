@@ -1,8 +1,27 @@
 #[derive(Debug, Clone)]
-pub struct Config {}
+pub struct Config {
+  /// Report values which are not assignable to their declared types
+  pub check_assignability: bool,
+  /// Continue with `Ty::Error` on unsupported constructs, instead of panicking
+  pub error_recovery: bool,
+  /// Add `undefined` to properties read via index signatures, like `noUncheckedIndexedAccess`
+  pub no_unchecked_indexed_access: bool,
+  /// Generic instances unwrapped within each other, before reporting an excessively deep
+  /// instantiation
+  pub max_instantiation_depth: usize,
+  /// Generic instances unwrapped in total, before reporting an excessively deep instantiation
+  pub max_instantiation_count: usize,
+}
 
 impl Default for Config {
   fn default() -> Self {
-    Self {}
+    Self {
+      check_assignability: false,
+      error_recovery: false,
+      no_unchecked_indexed_access: false,
+      // The same limits as `tsc`
+      max_instantiation_depth: 100,
+      max_instantiation_count: 5_000_000,
+    }
   }
 }
@@ -0,0 +1,279 @@
+use std::fmt;
+
+use line_index::LineIndex;
+use oxc::{
+  diagnostics::{GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource, OxcDiagnostic},
+  span::Span,
+};
+
+/// Stable codes of diagnostics. They follow the error codes of `tsc` where there is one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticCode {
+  /// `Type 'X' is not assignable to type 'Y'`
+  NotAssignable,
+  /// `Argument of type 'X' is not assignable to parameter of type 'Y'`
+  ArgumentNotAssignable,
+  /// A call which matches none of the overloads
+  NoMatchingOverload,
+  /// `delete this.#private`
+  DeletePrivateField,
+  /// `delete identifier` in strict mode
+  DeleteIdentifier,
+  /// `interface A extends B {}` where `B` is not an object type
+  InvalidInterfaceBase,
+  /// `interface A extends f() {}`
+  InvalidInterfaceHeritage,
+  /// `interface A extends B {}` and `interface B extends A {}`
+  RecursiveBaseType,
+  /// A property with different types in the bases of an interface
+  ConflictingInterfaceBases,
+  /// An own property of an interface which is not assignable to the inherited one
+  IncorrectlyExtends,
+  /// `N.T` where `T` is not exported from the namespace `N`
+  NoExportedMember,
+  /// `import { T } from "m"` where `T` is not exported from the module `m`
+  NoExportedModuleMember,
+  /// `type A = B` and `type B = A`
+  CircularTypeAlias,
+  /// A generic instance which unwraps to other instances too many times, e.g. `type A<T> = A<[T]>`
+  ExcessivelyDeepInstantiation,
+  /// A construct which is not supported by the analyzer yet
+  Unsupported,
+  /// An inferred type which cannot be written in the emitted declaration file
+  UnnameableType,
+  /// Reported by the lint rule with this name
+  Rule(&'static str),
+}
+
+impl DiagnosticCode {
+  /// `TS` for codes shared with `tsc`, `ST` for the analyzer's own ones, and `typescript-eslint`
+  /// for lint rules.
+  pub fn scope(self) -> &'static str {
+    match self {
+      DiagnosticCode::Unsupported | DiagnosticCode::UnnameableType => "ST",
+      DiagnosticCode::Rule(_) => "typescript-eslint",
+      _ => "TS",
+    }
+  }
+
+  /// The number of the code, or the name of the rule.
+  pub fn number(self) -> String {
+    match self {
+      DiagnosticCode::NotAssignable => "2322".into(),
+      DiagnosticCode::ArgumentNotAssignable => "2345".into(),
+      DiagnosticCode::NoMatchingOverload => "2769".into(),
+      DiagnosticCode::DeletePrivateField => "18011".into(),
+      DiagnosticCode::DeleteIdentifier => "1102".into(),
+      DiagnosticCode::InvalidInterfaceBase => "2312".into(),
+      DiagnosticCode::InvalidInterfaceHeritage => "2499".into(),
+      DiagnosticCode::RecursiveBaseType => "2310".into(),
+      DiagnosticCode::ConflictingInterfaceBases => "2320".into(),
+      DiagnosticCode::IncorrectlyExtends => "2430".into(),
+      DiagnosticCode::NoExportedMember => "2694".into(),
+      DiagnosticCode::NoExportedModuleMember => "2305".into(),
+      DiagnosticCode::CircularTypeAlias => "2456".into(),
+      DiagnosticCode::ExcessivelyDeepInstantiation => "2589".into(),
+      DiagnosticCode::Unsupported => "1001".into(),
+      DiagnosticCode::UnnameableType => "1002".into(),
+      DiagnosticCode::Rule(name) => name.into(),
+    }
+  }
+}
+
+impl fmt::Display for DiagnosticCode {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      // The same format as oxc_linter
+      DiagnosticCode::Rule(name) => write!(f, "{}({})", self.scope(), name),
+      _ => write!(f, "{}{}", self.scope(), self.number()),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+  Error,
+  Warning,
+}
+
+impl Severity {
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Severity::Error => "error",
+      Severity::Warning => "warning",
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiagnosticLabel {
+  pub span: Span,
+  pub message: String,
+}
+
+/// Replaces the source text at `span`. An empty `replacement` deletes it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextEdit {
+  pub span: Span,
+  pub replacement: String,
+}
+
+/// A change of the source which resolves the diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiagnosticFix {
+  /// What the fix does, e.g. `Remove the type assertion`
+  pub message: String,
+  /// Non-overlapping edits
+  pub edits: Vec<TextEdit>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+  pub code: DiagnosticCode,
+  pub severity: Severity,
+  pub message: String,
+  /// Where the problem is
+  pub span: Span,
+  /// Related locations, e.g. where the expected type comes from
+  pub labels: Vec<DiagnosticLabel>,
+  pub fix: Option<DiagnosticFix>,
+}
+
+impl Diagnostic {
+  pub fn error(code: DiagnosticCode, message: impl Into<String>, span: Span) -> Self {
+    Self {
+      code,
+      severity: Severity::Error,
+      message: message.into(),
+      span,
+      labels: Vec::new(),
+      fix: None,
+    }
+  }
+
+  pub fn warning(code: DiagnosticCode, message: impl Into<String>, span: Span) -> Self {
+    Self {
+      code,
+      severity: Severity::Warning,
+      message: message.into(),
+      span,
+      labels: Vec::new(),
+      fix: None,
+    }
+  }
+
+  pub fn with_label(mut self, span: Span, message: impl Into<String>) -> Self {
+    self.labels.push(DiagnosticLabel { span, message: message.into() });
+    self
+  }
+
+  pub fn with_fix(mut self, message: impl Into<String>, edits: Vec<TextEdit>) -> Self {
+    self.fix = Some(DiagnosticFix { message: message.into(), edits });
+    self
+  }
+
+  pub fn to_oxc_diagnostic(&self) -> OxcDiagnostic {
+    let diagnostic = match self.severity {
+      Severity::Error => OxcDiagnostic::error(self.message.clone()),
+      Severity::Warning => OxcDiagnostic::warn(self.message.clone()),
+    };
+    let diagnostic = match &self.fix {
+      Some(fix) => diagnostic.with_help(fix.message.clone()),
+      None => diagnostic,
+    };
+    diagnostic
+      .with_error_code(self.code.scope(), self.code.number())
+      .with_label(to_labeled_span(self.span, None))
+      .and_labels(
+        self.labels.iter().map(|label| to_labeled_span(label.span, Some(label.message.clone()))),
+      )
+  }
+
+  /// Renders the diagnostic with the source snippet, without colors.
+  pub fn render(&self, file_name: &str, source_text: &str) -> String {
+    let report = self
+      .to_oxc_diagnostic()
+      .with_source_code(NamedSource::new(file_name, source_text.to_string()));
+    let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
+    let mut output = String::new();
+    handler.render_report(&mut output, &*report).unwrap();
+    output
+  }
+
+  /// Serializes the diagnostic as a JSON object. Lines and columns are 1-based.
+  pub fn to_json(&self, line_index: &LineIndex) -> String {
+    let labels = self
+      .labels
+      .iter()
+      .map(|label| {
+        format!(
+          r#"{{"span":{},"message":{}}}"#,
+          span_to_json(label.span, line_index),
+          json_string(&label.message)
+        )
+      })
+      .collect::<Vec<_>>();
+    let fix = self.fix.as_ref().map_or_else(
+      || "null".to_string(),
+      |fix| {
+        let edits = fix
+          .edits
+          .iter()
+          .map(|edit| {
+            format!(
+              r#"{{"span":{},"replacement":{}}}"#,
+              span_to_json(edit.span, line_index),
+              json_string(&edit.replacement)
+            )
+          })
+          .collect::<Vec<_>>();
+        format!(r#"{{"message":{},"edits":[{}]}}"#, json_string(&fix.message), edits.join(","))
+      },
+    );
+    format!(
+      r#"{{"code":"{}","severity":"{}","message":{},"span":{},"labels":[{}],"fix":{}}}"#,
+      self.code,
+      self.severity.as_str(),
+      json_string(&self.message),
+      span_to_json(self.span, line_index),
+      labels.join(","),
+      fix
+    )
+  }
+}
+
+fn to_labeled_span(span: Span, label: Option<String>) -> LabeledSpan {
+  LabeledSpan::new(label, span.start as usize, span.size() as usize)
+}
+
+fn span_to_json(span: Span, line_index: &LineIndex) -> String {
+  let start = line_index.line_col(span.start.into());
+  let end = line_index.line_col(span.end.into());
+  format!(
+    r#"{{"start":{},"end":{},"start_line":{},"start_column":{},"end_line":{},"end_column":{}}}"#,
+    span.start,
+    span.end,
+    start.line + 1,
+    start.col + 1,
+    end.line + 1,
+    end.col + 1
+  )
+}
+
+fn json_string(s: &str) -> String {
+  let mut escaped = String::with_capacity(s.len() + 2);
+  escaped.push('"');
+  for c in s.chars() {
+    match c {
+      '"' => escaped.push_str("\\\""),
+      '\\' => escaped.push_str("\\\\"),
+      '\n' => escaped.push_str("\\n"),
+      '\r' => escaped.push_str("\\r"),
+      '\t' => escaped.push_str("\\t"),
+      c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+      c => escaped.push(c),
+    }
+  }
+  escaped.push('"');
+  escaped
+}
@@ -0,0 +1,105 @@
+use oxc::{
+  allocator::{Box, CloneIn},
+  ast::ast::{
+    Class, ClassElement, MethodDefinition, MethodDefinitionKind, MethodDefinitionType, PropertyKey,
+  },
+  span::{GetSpan, SPAN},
+};
+
+use crate::analyzer::Analyzer;
+
+impl<'a> Analyzer<'a> {
+  /// Classes are not analyzed yet, so only the annotated members can be emitted precisely.
+  pub fn emit_dts_class(&mut self, node: &'a Class<'a>, ambient: bool) -> Box<'a, Class<'a>> {
+    let scope = node.scope_id.get().unwrap();
+
+    let mut elements = self.ast_builder.vec();
+    for element in &node.body.body {
+      match element {
+        ClassElement::MethodDefinition(method) => {
+          // Private names are not visible, and neither is the implementation of overloads
+          if method.key.is_private_identifier() || is_overload_implementation(node, method) {
+            continue;
+          }
+          let mut emitted = method.clone_in(self.allocator);
+          emitted.decorators = self.ast_builder.vec();
+          emitted.value.body = None;
+          self.emit_dts_formal_parameters(
+            &mut emitted.value.params,
+            &method.value.params,
+            None,
+            scope,
+          );
+          if method.value.return_type.is_none()
+            && matches!(method.kind, MethodDefinitionKind::Method | MethodDefinitionKind::Get)
+          {
+            let name = get_property_key_name(&method.key);
+            let type_annotation = self.serialize_dts_type(None, &name, method.key.span(), scope);
+            emitted.value.return_type =
+              Some(self.ast_builder.alloc_ts_type_annotation(SPAN, type_annotation));
+          }
+          elements.push(ClassElement::MethodDefinition(emitted));
+        }
+        ClassElement::PropertyDefinition(property) => {
+          if property.key.is_private_identifier() {
+            continue;
+          }
+          let mut emitted = property.clone_in(self.allocator);
+          emitted.decorators = self.ast_builder.vec();
+          emitted.value = None;
+          emitted.definite = false;
+          if property.type_annotation.is_none() {
+            let name = get_property_key_name(&property.key);
+            let type_annotation = self.serialize_dts_type(None, &name, property.key.span(), scope);
+            emitted.type_annotation =
+              Some(self.ast_builder.alloc_ts_type_annotation(SPAN, type_annotation));
+          }
+          elements.push(ClassElement::PropertyDefinition(emitted));
+        }
+        ClassElement::AccessorProperty(property) => {
+          if property.key.is_private_identifier() {
+            continue;
+          }
+          let mut emitted = property.clone_in(self.allocator);
+          emitted.decorators = self.ast_builder.vec();
+          emitted.value = None;
+          if property.type_annotation.is_none() {
+            let name = get_property_key_name(&property.key);
+            let type_annotation = self.serialize_dts_type(None, &name, property.key.span(), scope);
+            emitted.type_annotation =
+              Some(self.ast_builder.alloc_ts_type_annotation(SPAN, type_annotation));
+          }
+          elements.push(ClassElement::AccessorProperty(emitted));
+        }
+        ClassElement::TSIndexSignature(_) => elements.push(element.clone_in(self.allocator)),
+        ClassElement::StaticBlock(_) => {}
+      }
+    }
+
+    let mut emitted = node.clone_in(self.allocator);
+    emitted.declare = !ambient;
+    emitted.decorators = self.ast_builder.vec();
+    emitted.body.body = elements;
+    self.ast_builder.alloc(emitted)
+  }
+}
+
+fn is_overload_implementation(class: &Class, method: &MethodDefinition) -> bool {
+  let Some(name) = method.key.static_name() else {
+    return false;
+  };
+  method.value.body.is_some()
+    && class.body.body.iter().any(|element| match element {
+      ClassElement::MethodDefinition(other) => {
+        other.value.body.is_none()
+          && !matches!(other.r#type, MethodDefinitionType::TSAbstractMethodDefinition)
+          && other.r#static == method.r#static
+          && other.key.static_name().is_some_and(|other_name| other_name == name)
+      }
+      _ => false,
+    })
+}
+
+fn get_property_key_name(key: &PropertyKey) -> String {
+  key.static_name().map_or_else(|| "computed property".to_string(), |name| name.into_owned())
+}
@@ -0,0 +1,200 @@
+use oxc::{
+  allocator::{Box, CloneIn},
+  ast::ast::{
+    BindingPatternKind, Declaration, FormalParameters, Function, TSModuleDeclaration,
+    TSModuleDeclarationBody, VariableDeclaration,
+  },
+  semantic::ScopeId,
+  span::{GetSpan, SPAN},
+};
+
+use oxc_ecmascript::BoundNames;
+
+use crate::{
+  analyzer::Analyzer,
+  ty::{callable::FunctionType, Ty},
+};
+
+impl<'a> Analyzer<'a> {
+  /// Returns `None` if the declaration is not visible in the declaration file.
+  pub fn emit_dts_declaration(
+    &mut self,
+    node: &'a Declaration<'a>,
+    ambient: bool,
+  ) -> Option<Declaration<'a>> {
+    match node {
+      Declaration::VariableDeclaration(node) => {
+        Some(Declaration::VariableDeclaration(self.emit_dts_variable_declaration(node, ambient)))
+      }
+      Declaration::FunctionDeclaration(node) => {
+        self.emit_dts_function(node, ambient).map(Declaration::FunctionDeclaration)
+      }
+      Declaration::ClassDeclaration(node) => {
+        Some(Declaration::ClassDeclaration(self.emit_dts_class(node, ambient)))
+      }
+      Declaration::TSEnumDeclaration(node) => {
+        let mut node = node.clone_in(self.allocator);
+        node.declare = !ambient;
+        Some(Declaration::TSEnumDeclaration(node))
+      }
+      Declaration::TSModuleDeclaration(node) => {
+        Some(Declaration::TSModuleDeclaration(self.emit_dts_module_declaration(node, ambient)))
+      }
+      Declaration::TSTypeAliasDeclaration(_)
+      | Declaration::TSInterfaceDeclaration(_)
+      | Declaration::TSImportEqualsDeclaration(_) => Some(node.clone_in(self.allocator)),
+    }
+  }
+
+  /// Destructuring patterns are flattened, e.g. `const { a, b } = x` becomes
+  /// `declare const a: A, b: B`.
+  pub fn emit_dts_variable_declaration(
+    &mut self,
+    node: &'a VariableDeclaration<'a>,
+    ambient: bool,
+  ) -> Box<'a, VariableDeclaration<'a>> {
+    let mut declarations = self.ast_builder.vec();
+    for declarator in &node.declarations {
+      if let (BindingPatternKind::BindingIdentifier(_), Some(type_annotation)) =
+        (&declarator.id.kind, &declarator.id.type_annotation)
+      {
+        let id = self.ast_builder.binding_pattern(
+          declarator.id.kind.clone_in(self.allocator),
+          Some(type_annotation.clone_in(self.allocator)),
+          false,
+        );
+        declarations.push(self.ast_builder.variable_declarator(SPAN, node.kind, id, None, false));
+        continue;
+      }
+
+      let mut ids = vec![];
+      declarator.id.bound_names(&mut |id| ids.push((id.name.clone(), id.span, id.symbol_id())));
+      for (name, span, symbol) in ids {
+        let scope = self.semantic.symbols().get_scope_id(symbol);
+        let ty = self.get_type_by_span(span);
+        let type_annotation = self.serialize_dts_type(ty, &name, span, scope);
+        let id = self.ast_builder.binding_pattern(
+          self.ast_builder.binding_pattern_kind_binding_identifier(SPAN, name.as_str()),
+          Some(self.ast_builder.alloc_ts_type_annotation(SPAN, type_annotation)),
+          false,
+        );
+        declarations.push(self.ast_builder.variable_declarator(SPAN, node.kind, id, None, false));
+      }
+    }
+    self.ast_builder.alloc_variable_declaration(SPAN, node.kind, declarations, !ambient)
+  }
+
+  /// Returns `None` for the implementation of overloads, which is not visible from outside.
+  pub fn emit_dts_function(
+    &mut self,
+    node: &'a Function<'a>,
+    ambient: bool,
+  ) -> Option<Box<'a, Function<'a>>> {
+    // `declare function` and overload signatures have no symbols
+    let symbol = node.id.as_ref().and_then(|id| id.symbol_id.get());
+    if node.body.is_some() && symbol.is_some_and(|s| self.function_overloads.contains_key(&s)) {
+      return None;
+    }
+
+    let name = node.id.as_ref().map_or("default", |id| id.name.as_str());
+    let scope = node.scope_id.get().unwrap();
+    // Anonymous default exports are not recorded, so only the annotations are known
+    let callable = match symbol.and_then(|symbol| self.variables.get(&symbol)) {
+      Some(Ty::Function(callable)) if node.body.is_some() => Some(*callable),
+      _ => None,
+    };
+
+    let mut emitted = node.clone_in(self.allocator);
+    emitted.declare = !ambient;
+    emitted.body = None;
+    // Both modifiers are implementation details, which are not allowed in ambient contexts
+    emitted.r#async = false;
+    emitted.generator = false;
+    self.emit_dts_formal_parameters(&mut emitted.params, &node.params, callable, scope);
+    if node.return_type.is_none() && node.body.is_some() {
+      // The inferred return type of generators is not wrapped yet
+      let ty = callable
+        .filter(|_| !node.generator)
+        .map(|callable| self.resolve_ctx_ty(self.type_scopes.empty_scope, callable.return_type));
+      let span = node.id.as_ref().map_or(node.span, |id| id.span);
+      let type_annotation = self.serialize_dts_type(ty, name, span, scope);
+      emitted.return_type = Some(self.ast_builder.alloc_ts_type_annotation(SPAN, type_annotation));
+    }
+    Some(self.ast_builder.alloc(emitted))
+  }
+
+  /// Fills the missing annotations of `emitted`, which is cloned from `node`. Parameters with
+  /// default values become optional.
+  pub fn emit_dts_formal_parameters(
+    &mut self,
+    emitted: &mut FormalParameters<'a>,
+    node: &'a FormalParameters<'a>,
+    callable: Option<&'a FunctionType<'a>>,
+    scope: ScopeId,
+  ) {
+    for (index, (param, original)) in emitted.items.iter_mut().zip(&node.items).enumerate() {
+      param.decorators = self.ast_builder.vec();
+      if let BindingPatternKind::AssignmentPattern(pattern) = &original.pattern.kind {
+        let type_annotation = param.pattern.type_annotation.take();
+        param.pattern = pattern.left.clone_in(self.allocator);
+        param.pattern.type_annotation = param.pattern.type_annotation.take().or(type_annotation);
+        param.pattern.optional = true;
+      }
+      if param.pattern.type_annotation.is_none() {
+        let ty = callable
+          .and_then(|callable| callable.params.get(index))
+          .map(|(_, ty)| self.resolve_ctx_ty(self.type_scopes.empty_scope, *ty));
+        let name = get_parameter_name(&original.pattern.kind);
+        let type_annotation = self.serialize_dts_type(ty, &name, original.span, scope);
+        param.pattern.type_annotation =
+          Some(self.ast_builder.alloc_ts_type_annotation(SPAN, type_annotation));
+      }
+    }
+
+    if let (Some(rest), Some(original)) = (&mut emitted.rest, &node.rest) {
+      if rest.argument.type_annotation.is_none() {
+        let ty = callable
+          .and_then(|callable| callable.rest_param)
+          .map(|ty| self.resolve_ctx_ty(self.type_scopes.empty_scope, ty));
+        let name = get_parameter_name(&original.argument.kind);
+        let type_annotation = self.serialize_dts_type(ty, &name, original.span(), scope);
+        rest.argument.type_annotation =
+          Some(self.ast_builder.alloc_ts_type_annotation(SPAN, type_annotation));
+      }
+    }
+  }
+
+  pub fn emit_dts_module_declaration(
+    &mut self,
+    node: &'a TSModuleDeclaration<'a>,
+    ambient: bool,
+  ) -> Box<'a, TSModuleDeclaration<'a>> {
+    let mut emitted = node.clone_in(self.allocator);
+    emitted.declare = !ambient;
+    match (&mut emitted.body, &node.body) {
+      (
+        Some(TSModuleDeclarationBody::TSModuleBlock(block)),
+        Some(TSModuleDeclarationBody::TSModuleBlock(original)),
+      ) => {
+        block.body = self.emit_dts_statements(&original.body, true);
+      }
+      (
+        Some(TSModuleDeclarationBody::TSModuleDeclaration(nested)),
+        Some(TSModuleDeclarationBody::TSModuleDeclaration(original)),
+      ) => {
+        // `namespace A.B {}`
+        *nested = self.emit_dts_module_declaration(original, true);
+      }
+      _ => {}
+    }
+    self.ast_builder.alloc(emitted)
+  }
+}
+
+fn get_parameter_name(kind: &BindingPatternKind) -> String {
+  match kind {
+    BindingPatternKind::BindingIdentifier(id) => id.name.to_string(),
+    BindingPatternKind::AssignmentPattern(pattern) => get_parameter_name(&pattern.left.kind),
+    _ => "destructured parameter".to_string(),
+  }
+}
@@ -0,0 +1,172 @@
+mod class;
+mod declaration;
+mod nameable;
+
+use oxc::{
+  allocator::{CloneIn, Vec},
+  ast::{
+    ast::{
+      ExportDefaultDeclaration, ExportDefaultDeclarationKind, Program, Statement, TSType,
+      VariableDeclarationKind,
+    },
+    match_declaration,
+  },
+  codegen::Codegen,
+  semantic::ScopeId,
+  span::{GetSpan, SourceType, Span, SPAN},
+};
+
+use crate::{
+  analyzer::Analyzer,
+  diagnostic::{Diagnostic, DiagnosticCode},
+  ty::Ty,
+};
+
+impl<'a> Analyzer<'a> {
+  /// Emits the declaration file of the analyzed program. Missing annotations are filled with the
+  /// inferred types.
+  pub fn emit_dts(&mut self, program: &'a Program<'a>) -> String {
+    let body = self.emit_dts_statements(&program.body, false);
+    let program = self.ast_builder.program(
+      SPAN,
+      SourceType::d_ts(),
+      "",
+      self.ast_builder.vec(),
+      None,
+      self.ast_builder.vec(),
+      body,
+    );
+    Codegen::new().build(&program).code
+  }
+
+  /// `ambient` is true inside `declare namespace`, where `declare` is implied.
+  pub fn emit_dts_statements(
+    &mut self,
+    statements: &'a Vec<'a, Statement<'a>>,
+    ambient: bool,
+  ) -> Vec<'a, Statement<'a>> {
+    let mut emitted = self.ast_builder.vec();
+    for statement in statements {
+      match statement {
+        match_declaration!(Statement) => {
+          let node = statement.to_declaration();
+          if let Some(declaration) = self.emit_dts_declaration(node, ambient) {
+            emitted.push(Statement::from(declaration));
+          }
+        }
+        Statement::ExportNamedDeclaration(node) => {
+          if let Some(declaration) = &node.declaration {
+            let Some(declaration) = self.emit_dts_declaration(declaration, ambient) else {
+              continue;
+            };
+            let mut node = node.clone_in(self.allocator);
+            node.declaration = Some(declaration);
+            emitted.push(Statement::ExportNamedDeclaration(node));
+          } else {
+            emitted.push(statement.clone_in(self.allocator));
+          }
+        }
+        Statement::ExportDefaultDeclaration(node) => {
+          self.emit_dts_export_default_declaration(node, ambient, &mut emitted);
+        }
+        Statement::ImportDeclaration(_)
+        | Statement::ExportAllDeclaration(_)
+        | Statement::TSExportAssignment(_)
+        | Statement::TSNamespaceExportDeclaration(_) => {
+          emitted.push(statement.clone_in(self.allocator));
+        }
+        _ => {
+          // Nothing to declare
+        }
+      }
+    }
+    emitted
+  }
+
+  fn emit_dts_export_default_declaration(
+    &mut self,
+    node: &'a ExportDefaultDeclaration<'a>,
+    ambient: bool,
+    emitted: &mut Vec<'a, Statement<'a>>,
+  ) {
+    let declaration = match &node.declaration {
+      ExportDefaultDeclarationKind::FunctionDeclaration(function) => {
+        let Some(function) = self.emit_dts_function(function, true) else {
+          return;
+        };
+        ExportDefaultDeclarationKind::FunctionDeclaration(function)
+      }
+      ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+        ExportDefaultDeclarationKind::ClassDeclaration(self.emit_dts_class(class, true))
+      }
+      ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {
+        node.declaration.clone_in(self.allocator)
+      }
+      expression => {
+        // `export default expr` becomes `declare const _default: T; export default _default;`
+        let expression = expression.to_expression();
+        let ty = self.get_type_by_span(expression.span());
+        let scope = self.semantic.scopes().root_scope_id();
+        let type_annotation = self.serialize_dts_type(ty, "default", expression.span(), scope);
+        let id = self.ast_builder.binding_pattern(
+          self.ast_builder.binding_pattern_kind_binding_identifier(SPAN, "_default"),
+          Some(self.ast_builder.alloc_ts_type_annotation(SPAN, type_annotation)),
+          false,
+        );
+        let declarator = self.ast_builder.variable_declarator(
+          SPAN,
+          VariableDeclarationKind::Const,
+          id,
+          None,
+          false,
+        );
+        emitted.push(Statement::VariableDeclaration(self.ast_builder.alloc_variable_declaration(
+          SPAN,
+          VariableDeclarationKind::Const,
+          self.ast_builder.vec1(declarator),
+          !ambient,
+        )));
+        ExportDefaultDeclarationKind::Identifier(
+          self.ast_builder.alloc_identifier_reference(SPAN, "_default"),
+        )
+      }
+    };
+    let mut node = node.clone_in(self.allocator);
+    node.declaration = declaration;
+    emitted.push(Statement::ExportDefaultDeclaration(self.ast_builder.alloc(node)));
+  }
+
+  /// Serializes an inferred type for the declaration file, written in `scope`. Reports the cases
+  /// where the type can not be written there.
+  pub fn serialize_dts_type(
+    &mut self,
+    ty: Option<Ty<'a>>,
+    name: &str,
+    span: Span,
+    scope: ScopeId,
+  ) -> TSType<'a> {
+    let Some(ty) = ty.filter(|ty| !matches!(ty, Ty::Error)) else {
+      self.report(Diagnostic::error(
+        DiagnosticCode::UnnameableType,
+        format!("The type of '{name}' can not be inferred, and needs an explicit annotation"),
+        span,
+      ));
+      return self.ast_builder.ts_type_any_keyword(SPAN);
+    };
+
+    self.push_span(&span);
+    let ts_type = self.serialize_type(ty);
+    self.pop_span();
+
+    if let Some(reference) = self.find_inaccessible_reference(&ts_type, scope) {
+      self.report(Diagnostic::error(
+        DiagnosticCode::UnnameableType,
+        format!(
+          "The inferred type of '{name}' references '{reference}', which can not be named here"
+        ),
+        span,
+      ));
+    }
+    ts_type
+  }
+}
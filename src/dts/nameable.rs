@@ -0,0 +1,60 @@
+use oxc::{
+  ast::{
+    ast::{BindingIdentifier, IdentifierReference, TSType},
+    Visit,
+  },
+  semantic::{ScopeId, Semantic, SymbolId},
+};
+
+use crate::analyzer::Analyzer;
+
+impl<'a> Analyzer<'a> {
+  /// Finds a reference in the serialized type, whose symbol is not visible from `scope`. For
+  /// example, a type parameter of another function.
+  pub fn find_inaccessible_reference(
+    &self,
+    ts_type: &TSType<'a>,
+    scope: ScopeId,
+  ) -> Option<String> {
+    let mut finder =
+      ReferenceFinder { semantic: &self.semantic, scope, declared: Vec::new(), inaccessible: None };
+    finder.visit_ts_type(ts_type);
+    finder.inaccessible
+  }
+}
+
+struct ReferenceFinder<'s, 'a> {
+  semantic: &'s Semantic<'a>,
+  scope: ScopeId,
+  /// Symbols declared in the type itself, e.g. by `infer U`
+  declared: Vec<SymbolId>,
+  inaccessible: Option<String>,
+}
+
+impl<'a> Visit<'a> for ReferenceFinder<'_, 'a> {
+  fn visit_binding_identifier(&mut self, it: &BindingIdentifier<'a>) {
+    if let Some(symbol) = it.symbol_id.get() {
+      self.declared.push(symbol);
+    }
+  }
+
+  fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+    if self.inaccessible.is_some() {
+      return;
+    }
+    let Some(reference_id) = it.reference_id.get() else {
+      return;
+    };
+    let symbols = self.semantic.symbols();
+    let Some(symbol) = symbols.get_reference(reference_id).symbol_id() else {
+      return;
+    };
+    if self.declared.contains(&symbol) {
+      return;
+    }
+    let declared_scope = symbols.get_scope_id(symbol);
+    if !self.semantic.scopes().ancestors(self.scope).any(|scope| scope == declared_scope) {
+      self.inaccessible = Some(it.name.to_string());
+    }
+  }
+}
@@ -1,19 +1,71 @@
+// `Ty` is hashed by the identity of its interior-mutable parts, e.g. interfaces
+#![allow(clippy::mutable_key_type)]
+
 mod analyzer;
 mod builtins;
 mod config;
+mod diagnostic;
+mod dts;
+pub mod lint;
 mod nodes;
+mod query;
 mod scope;
 pub mod ty;
 mod utils;
 
+use std::rc::Rc;
+
 pub use analyzer::Analyzer;
 pub use config::Config;
-use oxc::{allocator::Allocator, parser::Parser, semantic::SemanticBuilder, span::SourceType};
+pub use diagnostic::{
+  Diagnostic, DiagnosticCode, DiagnosticFix, DiagnosticLabel, Severity, TextEdit,
+};
+use oxc::{
+  allocator::Allocator,
+  ast::ast::Program,
+  parser::Parser,
+  semantic::{Semantic, SemanticBuilder},
+  span::SourceType,
+};
+pub use query::{SignatureHandle, TypeHandle};
 
+/// Parses the code as TSX, and analyzes it.
 pub fn analyze<'a>(allocator: &'a Allocator, code: &'a str, config: Config) -> Analyzer<'a> {
-  let parsed = allocator.alloc(Parser::new(&allocator, code, SourceType::tsx()).parse());
-  let semantic = SemanticBuilder::new().build(&parsed.program);
-  let mut analyzer = Analyzer::new(&allocator, config, semantic.semantic);
-  analyzer.exec_program(&parsed.program);
+  parse_and_analyze(allocator, code, config).1
+}
+
+/// Analyzes a program which is already parsed, with the semantic built for it. This allows
+/// sharing the AST with other tools, e.g. a linter.
+pub fn analyze_program<'a>(
+  allocator: &'a Allocator,
+  program: &'a Program<'a>,
+  semantic: Rc<Semantic<'a>>,
+  config: Config,
+) -> Analyzer<'a> {
+  let mut analyzer = Analyzer::new(allocator, config, semantic);
+  analyzer.exec_program(program);
   analyzer
 }
+
+/// Analyzes the code and emits its declaration file. Places where the inferred types can not be
+/// written are reported as diagnostics of the returned analyzer.
+pub fn emit_dts<'a>(
+  allocator: &'a Allocator,
+  code: &'a str,
+  config: Config,
+) -> (String, Analyzer<'a>) {
+  let (program, mut analyzer) = parse_and_analyze(allocator, code, config);
+  let dts = analyzer.emit_dts(program);
+  (dts, analyzer)
+}
+
+fn parse_and_analyze<'a>(
+  allocator: &'a Allocator,
+  code: &'a str,
+  config: Config,
+) -> (&'a Program<'a>, Analyzer<'a>) {
+  let parsed = allocator.alloc(Parser::new(allocator, code, SourceType::tsx()).parse());
+  let semantic = SemanticBuilder::new().build(&parsed.program);
+  let analyzer = analyze_program(allocator, &parsed.program, Rc::new(semantic.semantic), config);
+  (&parsed.program, analyzer)
+}
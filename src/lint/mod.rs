@@ -0,0 +1,110 @@
+pub mod rules;
+mod utils;
+
+use std::rc::Rc;
+
+use oxc::{
+  allocator::Allocator,
+  ast::ast::Program,
+  semantic::{AstNode, NodeId, Semantic},
+  span::{GetSpan, Span},
+};
+pub use rules::{all_rules, get_rule};
+
+use crate::{
+  analyze_program,
+  analyzer::Analyzer,
+  config::Config,
+  diagnostic::{Diagnostic, DiagnosticCode, TextEdit},
+  query::TypeHandle,
+};
+
+/// A lint rule which uses the types from the analyzer.
+pub trait Rule {
+  /// The name of the rule, e.g. `await-thenable`
+  fn name(&self) -> &'static str;
+
+  /// Called for every node of the program, in the order of `AstNodes`.
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>);
+}
+
+/// Runs type-aware rules on programs parsed by the caller, e.g. inside oxc_linter. Each program is
+/// analyzed once, and the types are shared by all the rules.
+pub struct TypeAwareLinter {
+  config: Config,
+  rules: Vec<Box<dyn Rule>>,
+}
+
+impl TypeAwareLinter {
+  /// Unsupported constructs in the linted code are analyzed as `Ty::Error`, instead of panicking.
+  pub fn new(rules: Vec<Box<dyn Rule>>) -> Self {
+    Self { config: Config { error_recovery: true, ..Default::default() }, rules }
+  }
+
+  pub fn with_config(mut self, config: Config) -> Self {
+    self.config = config;
+    self
+  }
+
+  /// `semantic` should be the one built for `program`, so that node IDs match.
+  pub fn run<'a>(
+    &self,
+    allocator: &'a Allocator,
+    program: &'a Program<'a>,
+    semantic: Rc<Semantic<'a>>,
+  ) -> Vec<Diagnostic> {
+    let mut analyzer =
+      analyze_program(allocator, program, Rc::clone(&semantic), self.config.clone());
+    let mut diagnostics = vec![];
+    for node in semantic.nodes().iter() {
+      for rule in &self.rules {
+        let mut ctx =
+          LintContext { analyzer: &mut analyzer, rule: rule.name(), diagnostics: &mut diagnostics };
+        rule.run(node, &mut ctx);
+      }
+    }
+    diagnostics
+  }
+}
+
+pub struct LintContext<'r, 'a> {
+  analyzer: &'r mut Analyzer<'a>,
+  rule: &'static str,
+  diagnostics: &'r mut Vec<Diagnostic>,
+}
+
+impl<'a> LintContext<'_, 'a> {
+  pub fn semantic(&self) -> &Semantic<'a> {
+    &self.analyzer.semantic
+  }
+
+  /// For the type queries, and the predicates of `TypeHandle`.
+  pub fn analyzer(&mut self) -> &mut Analyzer<'a> {
+    self.analyzer
+  }
+
+  /// The type of the expression node. See `Analyzer::type_of_expression`.
+  pub fn type_of_node(&mut self, node_id: NodeId) -> Option<TypeHandle<'a>> {
+    let span = self.analyzer.semantic.nodes().get_node(node_id).kind().span();
+    self.analyzer.type_of_span(span)
+  }
+
+  pub fn type_of_span(&mut self, span: Span) -> Option<TypeHandle<'a>> {
+    self.analyzer.type_of_span(span)
+  }
+
+  pub fn diagnostic(&mut self, message: impl Into<String>, span: Span) {
+    self.diagnostics.push(Diagnostic::warning(DiagnosticCode::Rule(self.rule), message, span));
+  }
+
+  pub fn diagnostic_with_fix(
+    &mut self,
+    message: impl Into<String>,
+    span: Span,
+    fix_message: impl Into<String>,
+    edits: Vec<TextEdit>,
+  ) {
+    let diagnostic = Diagnostic::warning(DiagnosticCode::Rule(self.rule), message, span);
+    self.diagnostics.push(diagnostic.with_fix(fix_message, edits));
+  }
+}
@@ -0,0 +1,28 @@
+use oxc::{ast::AstKind, semantic::AstNode, span::GetSpan};
+
+use crate::lint::{utils::is_promise_valued, LintContext, Rule};
+
+/// `await` on a value which can not be a thenable.
+pub struct AwaitThenable;
+
+impl Rule for AwaitThenable {
+  fn name(&self) -> &'static str {
+    "await-thenable"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let AstKind::AwaitExpression(node) = node.kind() else {
+      return;
+    };
+    if is_promise_valued(ctx, &node.argument) {
+      return;
+    }
+    let Some(ty) = ctx.type_of_span(node.argument.without_parentheses().span()) else {
+      return;
+    };
+    if ty.is_any() || ty.is_unknown() || ty.is_error() || ty.is_type_parameter() {
+      return;
+    }
+    ctx.diagnostic("Unexpected `await` of a non-Promise (non-\"Thenable\") value.", node.span);
+  }
+}
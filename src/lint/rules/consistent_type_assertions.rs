@@ -0,0 +1,134 @@
+use oxc::{
+  ast::{
+    ast::{Expression, TSType},
+    AstKind,
+  },
+  semantic::{AstNode, NodeId},
+  span::{GetSpan, Span},
+};
+
+use crate::{
+  diagnostic::TextEdit,
+  lint::{LintContext, Rule},
+};
+
+/// Type assertions use one syntax, and object literals can be required to be annotated instead of
+/// asserted, so that their properties are checked.
+#[derive(Debug, Default)]
+pub struct ConsistentTypeAssertions {
+  pub assertion_style: AssertionStyle,
+  pub object_literal_type_assertions: ObjectLiteralTypeAssertions,
+}
+
+/// The syntax of type assertions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AssertionStyle {
+  /// `value as T`
+  #[default]
+  As,
+  /// `<T>value`. Unlike the other direction, `as` is not fixed, as the expression may need
+  /// parentheses.
+  AngleBracket,
+  /// No type assertions, except for `as const`.
+  Never,
+}
+
+/// Where `{ ... } as T` is allowed, unless assertions are never allowed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectLiteralTypeAssertions {
+  #[default]
+  Allow,
+  /// Only as arguments, e.g. `f({ ... } as T)`
+  AllowAsParameter,
+  Never,
+}
+
+impl Rule for ConsistentTypeAssertions {
+  fn name(&self) -> &'static str {
+    "consistent-type-assertions"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let (span, expression, type_annotation) = match node.kind() {
+      AstKind::TSAsExpression(as_expression) => {
+        (as_expression.span, &as_expression.expression, &as_expression.type_annotation)
+      }
+      AstKind::TSTypeAssertion(assertion) => {
+        (assertion.span, &assertion.expression, &assertion.type_annotation)
+      }
+      _ => return,
+    };
+    let is_as = matches!(node.kind(), AstKind::TSAsExpression(_));
+    let source_text = ctx.semantic().source_text();
+    let type_text = type_annotation.span().source_text(source_text);
+    match self.assertion_style {
+      AssertionStyle::Never => {
+        if !type_annotation.is_const_type_reference() {
+          ctx.diagnostic("Do not use any type assertions.", span);
+        }
+        return;
+      }
+      AssertionStyle::As if !is_as => {
+        // `<T>value` -> `value as T`
+        let expression = expression.span().source_text(source_text);
+        let cast = format!("'as {type_text}'");
+        let message = format!("Use {cast} instead of '<{type_text}>'.");
+        let edits = vec![TextEdit { span, replacement: format!("{expression} as {type_text}") }];
+        ctx.diagnostic_with_fix(message, span, format!("Replace with {cast}"), edits);
+      }
+      AssertionStyle::AngleBracket if is_as => {
+        ctx.diagnostic(format!("Use '<{type_text}>' instead of 'as {type_text}'."), span);
+      }
+      _ => {}
+    }
+    self.check_object_literal(ctx, node.id(), span, expression, type_annotation);
+  }
+}
+
+impl ConsistentTypeAssertions {
+  fn check_object_literal<'a>(
+    &self,
+    ctx: &mut LintContext<'_, 'a>,
+    node: NodeId,
+    span: Span,
+    expression: &Expression<'a>,
+    type_annotation: &TSType<'a>,
+  ) {
+    if self.object_literal_type_assertions == ObjectLiteralTypeAssertions::Allow
+      || !matches!(expression.without_parentheses(), Expression::ObjectExpression(_))
+      || type_annotation.is_const_type_reference()
+      || matches!(type_annotation, TSType::TSAnyKeyword(_) | TSType::TSUnknownKeyword(_))
+    {
+      return;
+    }
+    if self.object_literal_type_assertions == ObjectLiteralTypeAssertions::AllowAsParameter
+      && is_argument(ctx, node, span)
+    {
+      return;
+    }
+    let type_annotation = type_annotation.span().source_text(ctx.semantic().source_text());
+    ctx.diagnostic(format!("Always prefer const x: {type_annotation} = {{ ... }}."), span);
+  }
+}
+
+/// Whether the assertion is an argument of a call or `new` expression.
+fn is_argument(ctx: &LintContext, node: NodeId, span: Span) -> bool {
+  let nodes = ctx.semantic().nodes();
+  let mut current = span;
+  let mut id = node;
+  while let Some(parent) = nodes.parent_node(id) {
+    match parent.kind() {
+      AstKind::ParenthesizedExpression(_) | AstKind::Argument(_) => {}
+      AstKind::CallExpression(call) => {
+        return call.arguments.iter().any(|argument| argument.span() == current);
+      }
+      AstKind::NewExpression(new) => {
+        return new.arguments.iter().any(|argument| argument.span() == current);
+      }
+      _ => return false,
+    }
+    current = parent.kind().span();
+    id = parent.id();
+  }
+  false
+}
@@ -0,0 +1,92 @@
+mod await_thenable;
+mod consistent_type_assertions;
+mod no_base_to_string;
+mod no_confusing_void_expression;
+mod no_floating_promises;
+mod no_meaningless_void_operator;
+mod no_misused_promises;
+mod no_mixed_enums;
+mod no_unnecessary_boolean_literal_compare;
+mod no_unnecessary_condition;
+mod no_unnecessary_type_assertion;
+mod no_unsafe_argument;
+mod no_unsafe_assignment;
+mod no_unsafe_call;
+mod no_unsafe_enum_comparison;
+mod no_unsafe_member_access;
+mod no_unsafe_return;
+mod no_unsafe_unary_minus;
+mod non_nullable_type_assertion_style;
+mod prefer_nullish_coalescing;
+mod require_await;
+mod restrict_plus_operands;
+mod restrict_template_expressions;
+mod return_await;
+mod strict_boolean_expressions;
+mod switch_exhaustiveness_check;
+
+pub use await_thenable::AwaitThenable;
+pub use consistent_type_assertions::{
+  AssertionStyle, ConsistentTypeAssertions, ObjectLiteralTypeAssertions,
+};
+pub use no_base_to_string::NoBaseToString;
+pub use no_confusing_void_expression::NoConfusingVoidExpression;
+pub use no_floating_promises::NoFloatingPromises;
+pub use no_meaningless_void_operator::NoMeaninglessVoidOperator;
+pub use no_misused_promises::NoMisusedPromises;
+pub use no_mixed_enums::NoMixedEnums;
+pub use no_unnecessary_boolean_literal_compare::NoUnnecessaryBooleanLiteralCompare;
+pub use no_unnecessary_condition::NoUnnecessaryCondition;
+pub use no_unnecessary_type_assertion::NoUnnecessaryTypeAssertion;
+pub use no_unsafe_argument::NoUnsafeArgument;
+pub use no_unsafe_assignment::NoUnsafeAssignment;
+pub use no_unsafe_call::NoUnsafeCall;
+pub use no_unsafe_enum_comparison::NoUnsafeEnumComparison;
+pub use no_unsafe_member_access::NoUnsafeMemberAccess;
+pub use no_unsafe_return::NoUnsafeReturn;
+pub use no_unsafe_unary_minus::NoUnsafeUnaryMinus;
+pub use non_nullable_type_assertion_style::NonNullableTypeAssertionStyle;
+pub use prefer_nullish_coalescing::PreferNullishCoalescing;
+pub use require_await::RequireAwait;
+pub use restrict_plus_operands::RestrictPlusOperands;
+pub use restrict_template_expressions::RestrictTemplateExpressions;
+pub use return_await::ReturnAwait;
+pub use strict_boolean_expressions::StrictBooleanExpressions;
+pub use switch_exhaustiveness_check::SwitchExhaustivenessCheck;
+
+use super::Rule;
+
+pub fn all_rules() -> Vec<Box<dyn Rule>> {
+  vec![
+    Box::new(AwaitThenable),
+    Box::new(ConsistentTypeAssertions::default()),
+    Box::new(NoBaseToString),
+    Box::new(NoConfusingVoidExpression),
+    Box::new(NoFloatingPromises),
+    Box::new(NoMeaninglessVoidOperator),
+    Box::new(NoMisusedPromises),
+    Box::new(NoMixedEnums),
+    Box::new(NoUnnecessaryBooleanLiteralCompare),
+    Box::new(NoUnnecessaryCondition),
+    Box::new(NoUnnecessaryTypeAssertion),
+    Box::new(NoUnsafeArgument),
+    Box::new(NoUnsafeAssignment),
+    Box::new(NoUnsafeCall),
+    Box::new(NoUnsafeEnumComparison),
+    Box::new(NoUnsafeMemberAccess),
+    Box::new(NoUnsafeReturn),
+    Box::new(NoUnsafeUnaryMinus),
+    Box::new(NonNullableTypeAssertionStyle),
+    Box::new(PreferNullishCoalescing),
+    Box::new(RequireAwait),
+    Box::new(RestrictPlusOperands),
+    Box::new(RestrictTemplateExpressions),
+    Box::new(ReturnAwait),
+    Box::new(StrictBooleanExpressions),
+    Box::new(SwitchExhaustivenessCheck),
+  ]
+}
+
+pub fn get_rule(name: &str) -> Option<Box<dyn Rule>> {
+  all_rules().into_iter().find(|rule| rule.name() == name)
+}
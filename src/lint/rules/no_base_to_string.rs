@@ -0,0 +1,78 @@
+use oxc::{
+  ast::{
+    ast::{AssignmentOperator, BinaryOperator, Expression},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{LintContext, Rule};
+
+/// Stringifying an object whose `toString` is the one inherited from `Object.prototype`, which
+/// results in `[object Object]`.
+pub struct NoBaseToString;
+
+impl Rule for NoBaseToString {
+  fn name(&self) -> &'static str {
+    "no-base-to-string"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    match node.kind() {
+      AstKind::TemplateLiteral(node) => {
+        for expression in &node.expressions {
+          check_stringified(ctx, expression);
+        }
+      }
+      AstKind::BinaryExpression(node) if node.operator == BinaryOperator::Addition => {
+        if is_string_like(ctx, &node.left) {
+          check_stringified(ctx, &node.right);
+        }
+        if is_string_like(ctx, &node.right) {
+          check_stringified(ctx, &node.left);
+        }
+      }
+      AstKind::AssignmentExpression(node) if node.operator == AssignmentOperator::Addition => {
+        // The type of the target is the contextual type of the value
+        let target = ctx.analyzer().contextual_type_of_span(node.right.span());
+        if target.is_some_and(|target| target.is_string_like(ctx.analyzer())) {
+          check_stringified(ctx, &node.right);
+        }
+      }
+      AstKind::CallExpression(node) if node.arguments.is_empty() => {
+        let Some(member) = node.callee.without_parentheses().as_member_expression() else {
+          return;
+        };
+        if matches!(member.static_property_name(), Some("toString" | "toLocaleString")) {
+          check_stringified(ctx, member.object());
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+fn is_string_like<'a>(ctx: &mut LintContext<'_, 'a>, node: &Expression<'a>) -> bool {
+  ctx.type_of_span(node.span()).is_some_and(|ty| ty.is_string_like(ctx.analyzer()))
+}
+
+fn check_stringified<'a>(ctx: &mut LintContext<'_, 'a>, node: &Expression<'a>) {
+  let Some(ty) = ctx.type_of_span(node.span()) else {
+    return;
+  };
+  let members = ty.union_members();
+  let count = members.iter().filter(|member| member.uses_object_to_string(ctx.analyzer())).count();
+  let certainty = match count {
+    0 => return,
+    _ if count == members.len() => "will",
+    _ => "may",
+  };
+  let name = node.span().source_text(ctx.semantic().source_text());
+  ctx.diagnostic(
+    format!(
+      "'{name}' {certainty} use Object's default stringification format ('[object Object]') when stringified."
+    ),
+    node.span(),
+  );
+}
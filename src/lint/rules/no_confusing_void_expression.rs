@@ -0,0 +1,68 @@
+use oxc::{
+  ast::{
+    ast::{Expression, UnaryOperator},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{LintContext, Rule};
+
+/// Calls and `await` of `void` values outside of expression statements, where the result looks
+/// like it is used.
+pub struct NoConfusingVoidExpression;
+
+impl Rule for NoConfusingVoidExpression {
+  fn name(&self) -> &'static str {
+    "no-confusing-void-expression"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let span = match node.kind() {
+      AstKind::CallExpression(call) => call.span,
+      AstKind::AwaitExpression(await_expression) => await_expression.span,
+      _ => return,
+    };
+    if !ctx.type_of_span(span).is_some_and(|ty| ty.is_void()) {
+      return;
+    }
+    let nodes = ctx.semantic().nodes();
+    let mut current = node;
+    // The node whose value is the value of the void expression
+    let parent = loop {
+      let Some(parent) = nodes.parent_node(current.id()) else {
+        return;
+      };
+      match parent.kind() {
+        AstKind::ParenthesizedExpression(_) => {}
+        AstKind::ConditionalExpression(conditional)
+          if conditional.test.span() != current.kind().span() => {}
+        AstKind::LogicalExpression(logical) if logical.right.span() == current.kind().span() => {}
+        AstKind::SequenceExpression(sequence)
+          if sequence.expressions.last().map(Expression::span) == Some(current.kind().span()) => {}
+        _ => break parent,
+      }
+      current = parent;
+    };
+    let message = match parent.kind() {
+      AstKind::ExpressionStatement(_) => {
+        let arrow_body = nodes.parent_node(parent.id()).and_then(|body| nodes.parent_node(body.id()));
+        match arrow_body.map(AstNode::kind) {
+          Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression => {
+            "Returning a void expression from an arrow function shorthand is forbidden. Please add braces to the arrow function."
+          }
+          _ => return,
+        }
+      }
+      AstKind::ReturnStatement(_) => {
+        "Returning a void expression from a function is forbidden. Please move it before the `return` statement."
+      }
+      AstKind::UnaryExpression(unary) if unary.operator == UnaryOperator::Void => return,
+      _ => {
+        "Placing a void expression inside another expression is forbidden. Move it to its own statement instead."
+      }
+    };
+    ctx.diagnostic(message, span);
+  }
+}
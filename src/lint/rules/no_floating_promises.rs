@@ -0,0 +1,52 @@
+use oxc::{
+  ast::{
+    ast::{Expression, UnaryOperator},
+    AstKind,
+  },
+  semantic::AstNode,
+};
+
+use crate::lint::{utils::is_promise_valued, LintContext, Rule};
+
+/// A promise created by an expression statement must be handled, or explicitly ignored with `void`.
+pub struct NoFloatingPromises;
+
+impl Rule for NoFloatingPromises {
+  fn name(&self) -> &'static str {
+    "no-floating-promises"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let AstKind::ExpressionStatement(node) = node.kind() else {
+      return;
+    };
+    let expression = node.expression.without_parentheses();
+    if is_handled(expression) {
+      return;
+    }
+    if is_promise_valued(ctx, expression) {
+      ctx.diagnostic(
+        "Promises must be awaited, end with a call to .catch, end with a call to .then with a rejection handler or be explicitly marked as ignored with the `void` operator.",
+        node.span,
+      );
+    }
+  }
+}
+
+/// `void promise`, `promise.catch(onrejected)` or `promise.then(onfulfilled, onrejected)`
+fn is_handled(node: &Expression) -> bool {
+  match node {
+    Expression::UnaryExpression(unary) => unary.operator == UnaryOperator::Void,
+    Expression::CallExpression(call) => {
+      let Some(member) = call.callee.without_parentheses().as_member_expression() else {
+        return false;
+      };
+      match member.static_property_name() {
+        Some("catch") => !call.arguments.is_empty(),
+        Some("then") => call.arguments.len() >= 2,
+        _ => false,
+      }
+    }
+    _ => false,
+  }
+}
@@ -0,0 +1,41 @@
+use oxc::{
+  ast::{ast::UnaryOperator, AstKind},
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{LintContext, Rule};
+
+/// `void` on a value which is already `void` or `undefined` does not discard anything.
+pub struct NoMeaninglessVoidOperator;
+
+impl Rule for NoMeaninglessVoidOperator {
+  fn name(&self) -> &'static str {
+    "no-meaningless-void-operator"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let AstKind::UnaryExpression(node) = node.kind() else {
+      return;
+    };
+    if node.operator != UnaryOperator::Void {
+      return;
+    }
+    let Some(ty) = ctx.type_of_span(node.argument.span()) else {
+      return;
+    };
+    let meaningless = ty
+      .union_members()
+      .into_iter()
+      .all(|member| member.is_void() || member.is_undefined() || member.is_never());
+    if meaningless {
+      let printed = ty.print(ctx.analyzer());
+      ctx.diagnostic(
+        format!(
+          "void operator shouldn't be used on {printed}; it should convey that a return value is being ignored"
+        ),
+        node.span,
+      );
+    }
+  }
+}
@@ -0,0 +1,81 @@
+use oxc::{
+  ast::{
+    ast::{Argument, Expression, UnaryOperator},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{utils::is_promise_valued, LintContext, Rule};
+
+/// Promises in places which do not handle them: conditions, and callbacks whose return value is
+/// ignored by the callee.
+pub struct NoMisusedPromises;
+
+impl Rule for NoMisusedPromises {
+  fn name(&self) -> &'static str {
+    "no-misused-promises"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    match node.kind() {
+      AstKind::IfStatement(node) => check_condition(ctx, &node.test),
+      AstKind::WhileStatement(node) => check_condition(ctx, &node.test),
+      AstKind::DoWhileStatement(node) => check_condition(ctx, &node.test),
+      AstKind::ForStatement(node) => {
+        if let Some(test) = &node.test {
+          check_condition(ctx, test);
+        }
+      }
+      AstKind::ConditionalExpression(node) => check_condition(ctx, &node.test),
+      AstKind::UnaryExpression(node) if node.operator == UnaryOperator::LogicalNot => {
+        check_condition(ctx, &node.argument)
+      }
+      AstKind::CallExpression(node) => check_arguments(ctx, node, &node.arguments),
+      AstKind::NewExpression(node) => check_arguments(ctx, node, &node.arguments),
+      _ => {}
+    }
+  }
+}
+
+fn check_condition<'a>(ctx: &mut LintContext<'_, 'a>, test: &Expression<'a>) {
+  if is_promise_valued(ctx, test) {
+    ctx.diagnostic("Expected non-Promise value in a boolean conditional.", test.span());
+  }
+}
+
+fn check_arguments<'a>(
+  ctx: &mut LintContext<'_, 'a>,
+  call: &impl GetSpan,
+  arguments: &[Argument<'a>],
+) {
+  let Some(signature) = ctx.analyzer().resolved_signature(call).cloned() else {
+    return;
+  };
+  for (index, argument) in arguments.iter().enumerate() {
+    let Some(argument) = argument.as_expression() else {
+      // Spread arguments are not matched with the parameters
+      return;
+    };
+    let Some(param) = signature.param(index) else {
+      return;
+    };
+    let expects_void =
+      param.call_return_type(ctx.analyzer()).is_some_and(|return_type| return_type.is_void());
+    if expects_void && returns_promise(ctx, argument) {
+      ctx.diagnostic(
+        "Promise returned in function argument where a void return was expected.",
+        argument.span(),
+      );
+    }
+  }
+}
+
+fn returns_promise<'a>(ctx: &mut LintContext<'_, 'a>, node: &Expression<'a>) -> bool {
+  let Some(ty) = ctx.type_of_span(node.span()) else {
+    return false;
+  };
+  let analyzer = ctx.analyzer();
+  ty.call_return_type(analyzer).is_some_and(|return_type| return_type.is_promise_like(analyzer))
+}
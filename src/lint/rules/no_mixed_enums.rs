@@ -0,0 +1,47 @@
+use oxc::{
+  ast::{ast::TSEnumMemberName, AstKind},
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{LintContext, Rule};
+
+/// Enums whose members are not all numbers or all strings.
+pub struct NoMixedEnums;
+
+impl Rule for NoMixedEnums {
+  fn name(&self) -> &'static str {
+    "no-mixed-enums"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let AstKind::TSEnumDeclaration(node) = node.kind() else {
+      return;
+    };
+    let symbol = node.id.symbol_id();
+    // Whether the first member is a string
+    let mut expected = None;
+    for member in &node.members {
+      let name = match &member.id {
+        TSEnumMemberName::Identifier(id) => id.name.as_str(),
+        TSEnumMemberName::String(s) => s.value.as_str(),
+      };
+      let Some(value) = ctx
+        .analyzer()
+        .type_of_enum_member(symbol, name)
+        .and_then(|member| member.enum_member_value())
+      else {
+        continue;
+      };
+      let is_string = value.is_string_like(ctx.analyzer());
+      match expected {
+        None => expected = Some(is_string),
+        Some(expected) if expected != is_string => {
+          let span = member.initializer.as_ref().map_or(member.span, GetSpan::span);
+          ctx.diagnostic("Mixing number and string enums can be confusing.", span);
+        }
+        Some(_) => {}
+      }
+    }
+  }
+}
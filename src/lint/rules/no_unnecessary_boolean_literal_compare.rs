@@ -0,0 +1,50 @@
+use oxc::{
+  ast::{
+    ast::{BinaryOperator, Expression},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{LintContext, Rule};
+
+/// Comparing a boolean value to `true` or `false`, which can be written as the value itself, or
+/// its negation. Nullable booleans are allowed, because the comparison also handles `null` and
+/// `undefined`.
+pub struct NoUnnecessaryBooleanLiteralCompare;
+
+impl Rule for NoUnnecessaryBooleanLiteralCompare {
+  fn name(&self) -> &'static str {
+    "no-unnecessary-boolean-literal-compare"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let AstKind::BinaryExpression(node) = node.kind() else {
+      return;
+    };
+    let negative = match node.operator {
+      BinaryOperator::Equality | BinaryOperator::StrictEquality => false,
+      BinaryOperator::Inequality | BinaryOperator::StrictInequality => true,
+      _ => return,
+    };
+    let (literal, value) =
+      match (node.left.get_inner_expression(), node.right.get_inner_expression()) {
+        (Expression::BooleanLiteral(literal), _) => (literal.value, &node.right),
+        (_, Expression::BooleanLiteral(literal)) => (literal.value, &node.left),
+        _ => return,
+      };
+    let Some(ty) = ctx.type_of_span(value.span()) else {
+      return;
+    };
+    if !ty.is_boolean_like(ctx.analyzer()) {
+      return;
+    }
+    let message = if literal != negative {
+      "This expression unnecessarily compares a boolean value to a boolean instead of using it directly."
+    } else {
+      "This expression unnecessarily compares a boolean value to a boolean instead of negating it."
+    };
+    ctx.diagnostic(message, node.span);
+  }
+}
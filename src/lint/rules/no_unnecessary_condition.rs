@@ -0,0 +1,99 @@
+use oxc::{
+  ast::{
+    ast::{Expression, LogicalOperator},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::{GetSpan, Span},
+};
+
+use crate::lint::{
+  utils::{find_operator, get_condition_test, get_tested_operands, is_in_condition},
+  LintContext, Rule,
+};
+
+/// Conditions which are always truthy or always falsy, `??` whose left side is always or never
+/// nullish, and optional chains on non-nullish values.
+pub struct NoUnnecessaryCondition;
+
+impl Rule for NoUnnecessaryCondition {
+  fn name(&self) -> &'static str {
+    "no-unnecessary-condition"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    if let Some(test) = get_condition_test(node.kind()) {
+      for operand in get_tested_operands(test) {
+        check_truthiness(ctx, operand);
+      }
+      return;
+    }
+    match node.kind() {
+      AstKind::LogicalExpression(logical) => {
+        if logical.operator == LogicalOperator::Coalesce {
+          check_nullishness(ctx, &logical.left);
+        } else if !is_in_condition(ctx, node.id()) {
+          for operand in get_tested_operands(&logical.left) {
+            check_truthiness(ctx, operand);
+          }
+        }
+      }
+      AstKind::MemberExpression(member) if member.optional() => {
+        check_optional_chain(ctx, member.object(), member.span());
+      }
+      AstKind::CallExpression(call) if call.optional => {
+        check_optional_chain(ctx, &call.callee, call.span);
+      }
+      _ => {}
+    }
+  }
+}
+
+fn check_truthiness<'a>(ctx: &mut LintContext<'_, 'a>, node: &Expression<'a>) {
+  let Some(ty) = ctx.type_of_span(node.span()) else {
+    return;
+  };
+  let message = if ty.is_never() {
+    "Unnecessary conditional, value is `never`."
+  } else {
+    match ty.test_truthy(ctx.analyzer()) {
+      Some(true) => "Unnecessary conditional, value is always truthy.",
+      Some(false) => "Unnecessary conditional, value is always falsy.",
+      None => return,
+    }
+  };
+  ctx.diagnostic(message, node.span());
+}
+
+fn check_nullishness<'a>(ctx: &mut LintContext<'_, 'a>, node: &Expression<'a>) {
+  let Some(ty) = ctx.type_of_span(node.span()) else {
+    return;
+  };
+  if ty.is_never() {
+    return;
+  }
+  let message = match ty.test_nullish(ctx.analyzer()) {
+    Some(true) => {
+      "Unnecessary conditional, left-hand side of `??` operator is always `null` or `undefined`."
+    }
+    Some(false) => {
+      "Unnecessary conditional, expected left-hand side of `??` operator to be possibly null or undefined."
+    }
+    None => return,
+  };
+  ctx.diagnostic(message, node.span());
+}
+
+/// Reports the `?.` token of `object?.property` or `callee?.()` in `span`.
+fn check_optional_chain<'a>(ctx: &mut LintContext<'_, 'a>, object: &Expression<'a>, span: Span) {
+  let Some(ty) = ctx.type_of_span(object.span()) else {
+    return;
+  };
+  if ty.is_never() || ty.test_nullish(ctx.analyzer()) != Some(false) {
+    return;
+  }
+  let Some(operator) = find_operator(ctx, Span::new(object.span().end, span.end), "?.") else {
+    return;
+  };
+  ctx.diagnostic("Unnecessary optional chain on a non-nullish value.", operator);
+}
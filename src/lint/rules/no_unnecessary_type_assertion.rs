@@ -0,0 +1,84 @@
+use oxc::{
+  ast::{
+    ast::{Expression, TSType},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::{GetSpan, Span},
+};
+
+use crate::{
+  diagnostic::TextEdit,
+  lint::{LintContext, Rule},
+};
+
+/// Type assertions which do not change the type of the expression, and non-null assertions on
+/// values which are never nullish. The fix removes the assertion.
+pub struct NoUnnecessaryTypeAssertion;
+
+const MESSAGE: &str =
+  "This assertion is unnecessary since it does not change the type of the expression.";
+
+impl Rule for NoUnnecessaryTypeAssertion {
+  fn name(&self) -> &'static str {
+    "no-unnecessary-type-assertion"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    match node.kind() {
+      AstKind::TSNonNullExpression(node) => {
+        let Some(ty) = ctx.type_of_span(node.expression.span()) else {
+          return;
+        };
+        if ty.is_any()
+          || ty.is_unknown()
+          || ty.is_error()
+          || ty.is_never()
+          || ty.is_type_parameter()
+          || ty.is_nullable(ctx.analyzer())
+        {
+          return;
+        }
+        // `value!` -> `value`
+        let assertion = Span::new(node.expression.span().end, node.span.end);
+        report(ctx, node.span, assertion);
+      }
+      AstKind::TSAsExpression(node) => {
+        // `value as T` -> `value`
+        let assertion = Span::new(node.expression.span().end, node.span.end);
+        check_assertion(ctx, node.span, &node.expression, &node.type_annotation, assertion);
+      }
+      AstKind::TSTypeAssertion(node) => {
+        // `<T>value` -> `value`
+        let assertion = Span::new(node.span.start, node.expression.span().start);
+        check_assertion(ctx, node.span, &node.expression, &node.type_annotation, assertion);
+      }
+      _ => {}
+    }
+  }
+}
+
+fn check_assertion<'a>(
+  ctx: &mut LintContext<'_, 'a>,
+  span: Span,
+  expression: &Expression<'a>,
+  type_annotation: &TSType<'a>,
+  assertion: Span,
+) {
+  if type_annotation.is_const_type_reference() {
+    return;
+  }
+  let (Some(asserted), Some(ty)) = (ctx.type_of_span(span), ctx.type_of_span(expression.span()))
+  else {
+    return;
+  };
+  if ty.is_error() || asserted.is_error() || ty != asserted {
+    return;
+  }
+  report(ctx, span, assertion);
+}
+
+fn report(ctx: &mut LintContext, span: Span, assertion: Span) {
+  let edits = vec![TextEdit { span: assertion, replacement: String::new() }];
+  ctx.diagnostic_with_fix(MESSAGE, span, "Remove the type assertion", edits);
+}
@@ -0,0 +1,56 @@
+use oxc::{
+  ast::{ast::Argument, AstKind},
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{utils::is_typed_position, LintContext, Rule};
+
+/// An `any` argument passed to a parameter with a declared type.
+pub struct NoUnsafeArgument;
+
+impl Rule for NoUnsafeArgument {
+  fn name(&self) -> &'static str {
+    "no-unsafe-argument"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    match node.kind() {
+      AstKind::CallExpression(node) => check_arguments(ctx, node, &node.arguments),
+      AstKind::NewExpression(node) => check_arguments(ctx, node, &node.arguments),
+      _ => {}
+    }
+  }
+}
+
+fn check_arguments<'a>(
+  ctx: &mut LintContext<'_, 'a>,
+  call: &impl GetSpan,
+  arguments: &[Argument<'a>],
+) {
+  // Calls on `any` have no signature
+  let Some(signature) = ctx.analyzer().resolved_signature(call).cloned() else {
+    return;
+  };
+  for (index, argument) in arguments.iter().enumerate() {
+    let Some(argument) = argument.as_expression() else {
+      // Spread arguments are not matched with the parameters
+      continue;
+    };
+    // The parameter of an argument after a spread depends on the spread length
+    if arguments[..index].iter().any(Argument::is_spread) {
+      return;
+    }
+    let Some(param) = signature.param(index) else {
+      return;
+    };
+    let is_any = ctx.type_of_span(argument.span()).is_some_and(|ty| ty.is_any());
+    if is_any && is_typed_position(param) {
+      let printed = param.print(ctx.analyzer());
+      ctx.diagnostic(
+        format!("Unsafe argument of type `any` assigned to a parameter of type `{printed}`."),
+        argument.span(),
+      );
+    }
+  }
+}
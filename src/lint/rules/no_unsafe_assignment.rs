@@ -0,0 +1,45 @@
+use oxc::{
+  ast::{
+    ast::{AssignmentOperator, Expression},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::{GetSpan, Span},
+};
+
+use crate::lint::{utils::is_typed_position, LintContext, Rule};
+
+/// An `any` value assigned to a variable or an assignment target. Targets of type `unknown` are
+/// allowed.
+pub struct NoUnsafeAssignment;
+
+impl Rule for NoUnsafeAssignment {
+  fn name(&self) -> &'static str {
+    "no-unsafe-assignment"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    match node.kind() {
+      AstKind::VariableDeclarator(node) => {
+        if let Some(init) = &node.init {
+          check_assignment(ctx, init, node.span);
+        }
+      }
+      AstKind::AssignmentExpression(node) if node.operator == AssignmentOperator::Assign => {
+        check_assignment(ctx, &node.right, node.span);
+      }
+      _ => {}
+    }
+  }
+}
+
+fn check_assignment<'a>(ctx: &mut LintContext<'_, 'a>, value: &Expression<'a>, span: Span) {
+  if !ctx.type_of_span(value.span()).is_some_and(|ty| ty.is_any()) {
+    return;
+  }
+  // The declared type of the target. Unannotated variables take the type of the value.
+  let target = ctx.analyzer().contextual_type_of_span(value.span());
+  if target.map_or(true, is_typed_position) {
+    ctx.diagnostic("Unsafe assignment of an `any` value.", span);
+  }
+}
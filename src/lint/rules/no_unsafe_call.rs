@@ -0,0 +1,38 @@
+use oxc::{
+  ast::{ast::Expression, AstKind},
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{LintContext, Rule};
+
+/// Calling or constructing an `any` value.
+pub struct NoUnsafeCall;
+
+impl Rule for NoUnsafeCall {
+  fn name(&self) -> &'static str {
+    "no-unsafe-call"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let (callee, message) = match node.kind() {
+      AstKind::CallExpression(node) => (&node.callee, "Unsafe call of an `any` typed value."),
+      AstKind::NewExpression(node) => (&node.callee, "Unsafe construction of an any type value."),
+      _ => return,
+    };
+    if is_any(ctx, callee) {
+      ctx.diagnostic(message, callee.span());
+    }
+  }
+}
+
+fn is_any<'a>(ctx: &mut LintContext<'_, 'a>, callee: &Expression<'a>) -> bool {
+  if let Some(ty) = ctx.type_of_span(callee.span()) {
+    return ty.is_any();
+  }
+  // The type of a member expression callee is not recorded, but any member of `any` is `any`
+  match callee.without_parentheses().as_member_expression() {
+    Some(member) => ctx.type_of_span(member.object().span()).is_some_and(|ty| ty.is_any()),
+    None => false,
+  }
+}
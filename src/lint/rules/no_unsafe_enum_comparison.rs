@@ -0,0 +1,80 @@
+use oxc::{
+  ast::AstKind,
+  semantic::{AstNode, SymbolId},
+  span::GetSpan,
+};
+use rustc_hash::FxHashSet;
+
+use crate::{
+  lint::{LintContext, Rule},
+  query::TypeHandle,
+};
+
+/// Comparisons of enum values with raw literals, or with members of another enum.
+pub struct NoUnsafeEnumComparison;
+
+impl Rule for NoUnsafeEnumComparison {
+  fn name(&self) -> &'static str {
+    "no-unsafe-enum-comparison"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let AstKind::BinaryExpression(node) = node.kind() else {
+      return;
+    };
+    if !(node.operator.is_equality() || node.operator.is_compare()) {
+      return;
+    }
+    let (Some(left), Some(right)) =
+      (ctx.type_of_span(node.left.span()), ctx.type_of_span(node.right.span()))
+    else {
+      return;
+    };
+    if [left, right].iter().any(|ty| ty.is_any() || ty.is_unknown() || ty.is_error()) {
+      return;
+    }
+
+    let left_members = left.union_members();
+    let right_members = right.union_members();
+    let left_enums = get_enum_symbols(ctx, &left_members);
+    let right_enums = get_enum_symbols(ctx, &right_members);
+    if left_enums.is_empty() && right_enums.is_empty() {
+      return;
+    }
+    // Comparing values of the same enum
+    if !left_enums.is_disjoint(&right_enums) {
+      return;
+    }
+    if left_members.iter().any(|ty| right_members.contains(ty)) {
+      return;
+    }
+    if violates(ctx, &left_members, right) || violates(ctx, &right_members, left) {
+      ctx
+        .diagnostic("The two values in this comparison do not have a shared enum type.", node.span);
+    }
+  }
+}
+
+fn get_enum_symbols<'a>(
+  ctx: &mut LintContext<'_, 'a>,
+  members: &[TypeHandle<'a>],
+) -> FxHashSet<SymbolId> {
+  members
+    .iter()
+    .filter(|ty| ty.is_enum_member())
+    .filter_map(|ty| ty.enum_symbol(ctx.analyzer()))
+    .collect()
+}
+
+/// A number enum compared with a number, or a string enum with a string.
+fn violates<'a>(
+  ctx: &mut LintContext<'_, 'a>,
+  members: &[TypeHandle<'a>],
+  other: TypeHandle<'a>,
+) -> bool {
+  let analyzer = ctx.analyzer();
+  members.iter().filter_map(|ty| ty.enum_member_value()).any(|value| {
+    (value.is_number_like(analyzer) && other.is_number_like(analyzer))
+      || (value.is_string_like(analyzer) && other.is_string_like(analyzer))
+  })
+}
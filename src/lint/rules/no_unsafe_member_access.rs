@@ -0,0 +1,51 @@
+use oxc::{
+  ast::{
+    ast::{Expression, MemberExpression},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{LintContext, Rule};
+
+/// Member access on an `any` value. For a chain like `value.a.b`, only the first access is
+/// reported.
+pub struct NoUnsafeMemberAccess;
+
+impl Rule for NoUnsafeMemberAccess {
+  fn name(&self) -> &'static str {
+    "no-unsafe-member-access"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let AstKind::MemberExpression(node) = node.kind() else {
+      return;
+    };
+    if !is_any(ctx, node.object()) {
+      return;
+    }
+    if let Some(object) = node.object().without_parentheses().as_member_expression() {
+      if is_any(ctx, object.object()) {
+        return;
+      }
+    }
+    let (property, span) = match node {
+      MemberExpression::StaticMemberExpression(node) => {
+        (format!(".{}", node.property.name), node.property.span)
+      }
+      MemberExpression::ComputedMemberExpression(node) => {
+        let span = node.expression.span();
+        (format!("[{}]", span.source_text(ctx.semantic().source_text())), span)
+      }
+      MemberExpression::PrivateFieldExpression(node) => {
+        (format!(".#{}", node.field.name), node.field.span)
+      }
+    };
+    ctx.diagnostic(format!("Unsafe member access {property} on an `any` value."), span);
+  }
+}
+
+fn is_any<'a>(ctx: &mut LintContext<'_, 'a>, node: &Expression<'a>) -> bool {
+  ctx.type_of_span(node.span()).is_some_and(|ty| ty.is_any())
+}
@@ -0,0 +1,46 @@
+use oxc::{
+  ast::{
+    ast::{Expression, Statement},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{utils::is_typed_position, LintContext, Rule};
+
+/// Returning an `any` value from a function, unless its return type is `any` or `unknown`.
+pub struct NoUnsafeReturn;
+
+impl Rule for NoUnsafeReturn {
+  fn name(&self) -> &'static str {
+    "no-unsafe-return"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    match node.kind() {
+      AstKind::ReturnStatement(node) => {
+        if let Some(argument) = &node.argument {
+          check_return(ctx, argument);
+        }
+      }
+      AstKind::ArrowFunctionExpression(node) if node.expression => {
+        if let Some(Statement::ExpressionStatement(body)) = node.body.statements.first() {
+          check_return(ctx, &body.expression);
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+fn check_return<'a>(ctx: &mut LintContext<'_, 'a>, argument: &Expression<'a>) {
+  if !ctx.type_of_span(argument.span()).is_some_and(|ty| ty.is_any()) {
+    return;
+  }
+  // The annotated return type, or the return type of the contextual signature
+  let expected = ctx.analyzer().contextual_type_of_span(argument.span());
+  if expected.map_or(true, is_typed_position) {
+    ctx.diagnostic("Unsafe return of an `any` typed value.", argument.span());
+  }
+}
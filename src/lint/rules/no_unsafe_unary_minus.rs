@@ -0,0 +1,44 @@
+use oxc::{
+  ast::{ast::UnaryOperator, AstKind},
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{LintContext, Rule};
+
+/// Unary `-` on a value which is not a `number` or a `bigint`.
+pub struct NoUnsafeUnaryMinus;
+
+impl Rule for NoUnsafeUnaryMinus {
+  fn name(&self) -> &'static str {
+    "no-unsafe-unary-minus"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let AstKind::UnaryExpression(node) = node.kind() else {
+      return;
+    };
+    if node.operator != UnaryOperator::UnaryNegation {
+      return;
+    }
+    let Some(ty) = ctx.type_of_span(node.argument.span()) else {
+      return;
+    };
+    if ty.is_any() || ty.is_error() {
+      return;
+    }
+    let numeric = ty.union_members().into_iter().all(|member| {
+      let analyzer = ctx.analyzer();
+      member.is_never() || member.is_number_like(analyzer) || member.is_bigint_like(analyzer)
+    });
+    if !numeric {
+      let printed = ty.print(ctx.analyzer());
+      ctx.diagnostic(
+        format!(
+          "Argument of unary negation should be assignable to number | bigint but is {printed} instead."
+        ),
+        node.span,
+      );
+    }
+  }
+}
@@ -0,0 +1,64 @@
+use oxc::{
+  ast::{
+    ast::{Expression, TSType},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::{GetSpan, Span},
+};
+
+use crate::{
+  diagnostic::TextEdit,
+  lint::{LintContext, Rule},
+};
+
+/// `value as T`, where `T` is the type of `value` without `null` and `undefined`, can be written
+/// as `value!`.
+pub struct NonNullableTypeAssertionStyle;
+
+impl Rule for NonNullableTypeAssertionStyle {
+  fn name(&self) -> &'static str {
+    "non-nullable-type-assertion-style"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    match node.kind() {
+      AstKind::TSAsExpression(node) => {
+        check_assertion(ctx, node.span, &node.expression, &node.type_annotation)
+      }
+      AstKind::TSTypeAssertion(node) => {
+        check_assertion(ctx, node.span, &node.expression, &node.type_annotation)
+      }
+      _ => {}
+    }
+  }
+}
+
+fn check_assertion<'a>(
+  ctx: &mut LintContext<'_, 'a>,
+  span: Span,
+  expression: &Expression<'a>,
+  type_annotation: &TSType<'a>,
+) {
+  if type_annotation.is_const_type_reference() {
+    return;
+  }
+  let (Some(asserted), Some(ty)) = (ctx.type_of_span(span), ctx.type_of_span(expression.span()))
+  else {
+    return;
+  };
+  if ty.is_any() || ty.is_unknown() || ty.is_error() || !ty.is_nullable(ctx.analyzer()) {
+    return;
+  }
+  if ty.non_nullable(ctx.analyzer()) != Some(asserted) {
+    return;
+  }
+  let source = expression.span().source_text(ctx.semantic().source_text());
+  let edits = vec![TextEdit { span, replacement: format!("{source}!") }];
+  ctx.diagnostic_with_fix(
+    "Use a ! assertion to more succinctly remove null and undefined from the type.",
+    span,
+    "Replace with a non-null assertion",
+    edits,
+  );
+}
@@ -0,0 +1,64 @@
+use oxc::{
+  ast::{
+    ast::{AssignmentOperator, LogicalOperator},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::{GetSpan, Span},
+};
+
+use crate::lint::{
+  utils::{find_operator, is_in_condition},
+  LintContext, Rule,
+};
+
+/// `||` and `||=` on a possibly nullish left side, where `??` and `??=` do not also replace other
+/// falsy values. Conditions are ignored, like the default options of typescript-eslint.
+pub struct PreferNullishCoalescing;
+
+impl Rule for PreferNullishCoalescing {
+  fn name(&self) -> &'static str {
+    "prefer-nullish-coalescing"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let (ty, left, right, operator, message) = match node.kind() {
+      AstKind::LogicalExpression(logical) if logical.operator == LogicalOperator::Or => {
+        if is_in_condition(ctx, node.id()) {
+          return;
+        }
+        (
+          ctx.type_of_span(logical.left.span()),
+          logical.left.span(),
+          logical.right.span(),
+          "||",
+          "Prefer using nullish coalescing operator (`??`) instead of a logical or (`||`), as it is a safer operator.",
+        )
+      }
+      AstKind::AssignmentExpression(assignment)
+        if assignment.operator == AssignmentOperator::LogicalOr =>
+      {
+        (
+          // The type of the target is the contextual type of the value
+          ctx.analyzer().contextual_type_of_span(assignment.right.span()),
+          assignment.left.span(),
+          assignment.right.span(),
+          "||=",
+          "Prefer using nullish coalescing operator (`??=`) instead of a logical assignment (`||=`), as it is a safer operator.",
+        )
+      }
+      _ => return,
+    };
+    let Some(ty) = ty else {
+      return;
+    };
+    if ty.is_unknown() || ty.is_type_parameter() || !ty.is_nullable(ctx.analyzer()) {
+      return;
+    }
+    // Reported at the operator
+    let Some(operator) = find_operator(ctx, Span::new(left.end, right.start), operator) else {
+      return;
+    };
+    ctx.diagnostic(message, operator);
+  }
+}
@@ -0,0 +1,104 @@
+use oxc::{
+  ast::{
+    ast::{Expression, PropertyKey, Statement},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::{GetSpan, Span},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::lint::{
+  utils::{get_enclosing_function, is_promise_valued},
+  LintContext, Rule,
+};
+
+/// An async function should `await` something, or return a promise. Otherwise, it does not need
+/// to be async.
+pub struct RequireAwait;
+
+impl Rule for RequireAwait {
+  fn name(&self) -> &'static str {
+    "require-await"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    if !matches!(node.kind(), AstKind::Program(_)) {
+      return;
+    }
+
+    // The awaits and returned values of every function, collected in a single pass
+    let mut functions = vec![];
+    let mut awaiting = FxHashSet::<Span>::default();
+    let mut returned = FxHashMap::<Span, Vec<&'a Expression<'a>>>::default();
+    for node in ctx.semantic().nodes().iter() {
+      let argument = match node.kind() {
+        AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+          functions.push((node.id(), node.kind()));
+          continue;
+        }
+        AstKind::AwaitExpression(_) => None,
+        AstKind::ForOfStatement(for_of) if for_of.r#await => None,
+        AstKind::ReturnStatement(statement) => match &statement.argument {
+          Some(argument) => Some(argument),
+          None => continue,
+        },
+        _ => continue,
+      };
+      let Some(function) = get_enclosing_function(ctx, node.id()) else {
+        continue;
+      };
+      match argument {
+        Some(argument) => returned.entry(function.span()).or_default().push(argument),
+        // An `await` or `for await`
+        None => {
+          awaiting.insert(function.span());
+        }
+      }
+    }
+
+    for (id, kind) in functions {
+      let (span, head, description) = match kind {
+        AstKind::Function(function) => {
+          if !function.r#async || function.generator || function.body.is_none() {
+            continue;
+          }
+          let description = match (&function.id, ctx.semantic().nodes().parent_kind(id)) {
+            (Some(id), _) => format!("Async function '{}'", id.name),
+            (None, Some(AstKind::MethodDefinition(method))) => match &method.key {
+              PropertyKey::StaticIdentifier(key) => format!("Async method '{}'", key.name),
+              _ => "Async method".to_string(),
+            },
+            (None, _) => "Async function".to_string(),
+          };
+          (function.span, Span::new(function.span.start, function.params.span.end), description)
+        }
+        AstKind::ArrowFunctionExpression(arrow) => {
+          if !arrow.r#async {
+            continue;
+          }
+          let head = Span::new(arrow.span.start, arrow.params.span.end);
+          (arrow.span, head, "Async arrow function".to_string())
+        }
+        _ => unreachable!(),
+      };
+      if awaiting.contains(&span) {
+        continue;
+      }
+
+      let mut returned = returned.remove(&span).unwrap_or_default();
+      if let AstKind::ArrowFunctionExpression(arrow) = kind {
+        if arrow.expression {
+          if let Some(Statement::ExpressionStatement(body)) = arrow.body.statements.first() {
+            returned.push(&body.expression);
+          }
+        }
+      }
+      // Returning a promise from an async function is fine
+      if returned.into_iter().any(|argument| is_promise_valued(ctx, argument)) {
+        continue;
+      }
+      ctx.diagnostic(format!("{description} has no 'await' expression."), head);
+    }
+  }
+}
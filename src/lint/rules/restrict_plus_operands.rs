@@ -0,0 +1,69 @@
+use oxc::{
+  ast::{
+    ast::{AssignmentOperator, BinaryOperator},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{utils::has_unstringifiable_member, LintContext, Rule};
+
+/// Operands of `+` and `+=` which are neither strings nor numbers, and mixing `bigint` with
+/// `number`. Like the default options of typescript-eslint, `any`, booleans and nullish values are
+/// allowed.
+pub struct RestrictPlusOperands;
+
+impl Rule for RestrictPlusOperands {
+  fn name(&self) -> &'static str {
+    "restrict-plus-operands"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let (left, left_ty, right, span) = match node.kind() {
+      AstKind::BinaryExpression(node) if node.operator == BinaryOperator::Addition => {
+        (node.left.span(), ctx.type_of_span(node.left.span()), node.right.span(), node.span)
+      }
+      AstKind::AssignmentExpression(node) if node.operator == AssignmentOperator::Addition => {
+        // The type of the target is the contextual type of the value
+        let left_ty = ctx.analyzer().contextual_type_of_span(node.right.span());
+        (node.left.span(), left_ty, node.right.span(), node.span)
+      }
+      _ => return,
+    };
+    let (Some(left_ty), Some(right_ty)) = (left_ty, ctx.type_of_span(right)) else {
+      return;
+    };
+
+    let mut valid = true;
+    for (operand, ty) in [(left, left_ty), (right, right_ty)] {
+      if has_unstringifiable_member(ctx, ty) {
+        let printed = ty.print(ctx.analyzer());
+        ctx.diagnostic(
+          format!(
+            "Invalid operand for a '+' operation. Operands must each be a number or string. Got `{printed}`."
+          ),
+          operand,
+        );
+        valid = false;
+      }
+    }
+    if !valid {
+      return;
+    }
+
+    let analyzer = ctx.analyzer();
+    let mismatched = (left_ty.is_bigint_like(analyzer) && right_ty.is_number_like(analyzer))
+      || (left_ty.is_number_like(analyzer) && right_ty.is_bigint_like(analyzer));
+    if mismatched {
+      let left = left_ty.print(analyzer);
+      let right = right_ty.print(analyzer);
+      ctx.diagnostic(
+        format!(
+          "Numeric '+' operations must either be both bigints or both numbers. Got `{left}` + `{right}`."
+        ),
+        span,
+      );
+    }
+  }
+}
@@ -0,0 +1,31 @@
+use oxc::{ast::AstKind, semantic::AstNode, span::GetSpan};
+
+use crate::lint::{utils::has_unstringifiable_member, LintContext, Rule};
+
+/// Values in template literals which are not meant to be stringified. Like the default options of
+/// typescript-eslint, `any`, booleans, numbers and nullish values are allowed.
+pub struct RestrictTemplateExpressions;
+
+impl Rule for RestrictTemplateExpressions {
+  fn name(&self) -> &'static str {
+    "restrict-template-expressions"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let AstKind::TemplateLiteral(node) = node.kind() else {
+      return;
+    };
+    for expression in &node.expressions {
+      let Some(ty) = ctx.type_of_span(expression.span()) else {
+        continue;
+      };
+      if has_unstringifiable_member(ctx, ty) {
+        let printed = ty.print(ctx.analyzer());
+        ctx.diagnostic(
+          format!("Invalid type \"{printed}\" of template literal expression."),
+          expression.span(),
+        );
+      }
+    }
+  }
+}
@@ -0,0 +1,59 @@
+use oxc::{
+  ast::{ast::Expression, AstKind},
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{utils::is_promise_valued, LintContext, Rule};
+
+/// Inside `try`, a returned promise must be awaited, so that its rejection is caught, and the
+/// `finally` block runs after it settles. Outside of `try`, either way is allowed.
+pub struct ReturnAwait;
+
+impl Rule for ReturnAwait {
+  fn name(&self) -> &'static str {
+    "return-await"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let AstKind::ReturnStatement(statement) = node.kind() else {
+      return;
+    };
+    let Some(argument) = &statement.argument else {
+      return;
+    };
+    if matches!(argument.without_parentheses(), Expression::AwaitExpression(_)) {
+      return;
+    }
+    if !in_try_of_async_function(ctx, node) {
+      return;
+    }
+    if is_promise_valued(ctx, argument) {
+      ctx.diagnostic("Returning an awaited promise is required in this context.", argument.span());
+    }
+  }
+}
+
+/// Whether the node is in the `try` block, or in the `catch` block with a `finally` block, and
+/// the enclosing function is async.
+fn in_try_of_async_function(ctx: &LintContext, node: &AstNode) -> bool {
+  let nodes = ctx.semantic().nodes();
+  let mut in_try = false;
+  let mut child = node.kind().span();
+  let mut current = node.id();
+  while let Some(parent) = nodes.parent_node(current) {
+    match parent.kind() {
+      AstKind::TryStatement(statement) => {
+        in_try |= statement.block.span == child
+          || (statement.handler.as_ref().is_some_and(|handler| handler.span == child)
+            && statement.finalizer.is_some());
+      }
+      AstKind::Function(function) => return in_try && function.r#async,
+      AstKind::ArrowFunctionExpression(arrow) => return in_try && arrow.r#async,
+      _ => {}
+    }
+    child = parent.kind().span();
+    current = parent.id();
+  }
+  false
+}
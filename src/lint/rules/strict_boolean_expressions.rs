@@ -0,0 +1,101 @@
+use oxc::{
+  ast::{
+    ast::{Expression, LogicalOperator, UnaryOperator},
+    AstKind,
+  },
+  semantic::AstNode,
+  span::GetSpan,
+};
+
+use crate::lint::{
+  utils::{get_condition_test, get_tested_operands, is_in_condition},
+  LintContext, Rule,
+};
+
+/// Values in conditions whose truthiness is ambiguous. Like the default options of
+/// typescript-eslint, strings, numbers and nullable objects are allowed.
+pub struct StrictBooleanExpressions;
+
+impl Rule for StrictBooleanExpressions {
+  fn name(&self) -> &'static str {
+    "strict-boolean-expressions"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let operands = if let Some(test) = get_condition_test(node.kind()) {
+      get_tested_operands(test)
+    } else {
+      match node.kind() {
+        AstKind::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+          get_tested_operands(&unary.argument)
+        }
+        AstKind::LogicalExpression(logical)
+          if logical.operator != LogicalOperator::Coalesce && !is_in_condition(ctx, node.id()) =>
+        {
+          get_tested_operands(&logical.left)
+        }
+        _ => return,
+      }
+    };
+    for operand in operands {
+      check_operand(ctx, operand);
+    }
+  }
+}
+
+fn check_operand<'a>(ctx: &mut LintContext<'_, 'a>, node: &Expression<'a>) {
+  let Some(ty) = ctx.type_of_span(node.span()) else {
+    return;
+  };
+  let (mut nullish, mut boolean, mut string, mut number, mut object, mut other) =
+    (false, false, false, false, false, false);
+  for member in ty.union_members() {
+    if member.is_error() || member.is_never() || member.is_type_parameter() {
+      return;
+    }
+    if member.is_any() || member.is_unknown() {
+      ctx.diagnostic(
+        "Unexpected any value in conditional. An explicit comparison or type conversion is required.",
+        node.span(),
+      );
+      return;
+    }
+    let analyzer = ctx.analyzer();
+    if member.is_void() || member.test_nullish(analyzer) == Some(true) {
+      nullish = true;
+    } else if member.is_boolean_like(analyzer) {
+      boolean = true;
+    } else if member.is_string_like(analyzer) {
+      string = true;
+    } else if member.is_number_like(analyzer) || member.is_bigint_like(analyzer) {
+      number = true;
+    } else if member.is_object_like(analyzer) {
+      object = true;
+    } else {
+      other = true;
+    }
+  }
+  let message = match (nullish, boolean, string, number, object, other) {
+    (true, false, false, false, false, false) => {
+      "Unexpected nullish value in conditional. The condition is always false."
+    }
+    (true, true, false, false, false, false) => {
+      "Unexpected nullable boolean value in conditional. Please handle the nullish case explicitly."
+    }
+    (true, false, true, false, false, false) => {
+      "Unexpected nullable string value in conditional. Please handle the nullish/empty cases explicitly."
+    }
+    (true, false, false, true, false, false) => {
+      "Unexpected nullable number value in conditional. Please handle the nullish/zero/NaN cases explicitly."
+    }
+    (false, false, false, false, true, false) => {
+      "Unexpected object value in conditional. The condition is always true."
+    }
+    (false, true, false, false, false, false)
+    | (false, false, true, false, false, false)
+    | (false, false, false, true, false, false)
+    | (true, false, false, false, true, false) => return,
+    _ => "Unexpected value in conditional. A boolean expression is required.",
+  };
+  ctx.diagnostic(message, node.span());
+}
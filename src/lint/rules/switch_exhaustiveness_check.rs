@@ -0,0 +1,76 @@
+use oxc::{
+  ast::AstKind,
+  semantic::AstNode,
+  span::{GetSpan, Span},
+};
+
+use crate::{
+  diagnostic::TextEdit,
+  lint::{LintContext, Rule},
+};
+
+/// `switch` statements on unions of literals, without a `default` case, which do not handle all
+/// the members. The fix adds a stub for each missing case.
+pub struct SwitchExhaustivenessCheck;
+
+impl Rule for SwitchExhaustivenessCheck {
+  fn name(&self) -> &'static str {
+    "switch-exhaustiveness-check"
+  }
+
+  fn run<'a>(&self, node: &AstNode<'a>, ctx: &mut LintContext<'_, 'a>) {
+    let AstKind::SwitchStatement(node) = node.kind() else {
+      return;
+    };
+    if node.cases.iter().any(|case| case.test.is_none()) {
+      return;
+    }
+    let Some(discriminant) = ctx.type_of_span(node.discriminant.span()) else {
+      return;
+    };
+    let mut covered = vec![];
+    for case in &node.cases {
+      if let Some(ty) = case.test.as_ref().and_then(|test| ctx.type_of_span(test.span())) {
+        covered.push(ty);
+      }
+    }
+    let remaining = discriminant.remaining_union_members(ctx.analyzer(), &covered);
+    // Other types can not be listed as cases
+    if remaining.iter().any(|ty| !(ty.is_literal() || ty.is_null() || ty.is_undefined())) {
+      return;
+    }
+    let mut missing = remaining.into_iter().map(|ty| ty.print(ctx.analyzer())).collect::<Vec<_>>();
+    if missing.is_empty() {
+      return;
+    }
+    // The order of union members is not the declared one
+    missing.sort();
+
+    let source_text = ctx.semantic().source_text();
+    let switch_indent = get_indent(source_text, node.span.start);
+    let (position, case_indent, suffix) = match node.cases.last() {
+      Some(case) => (case.span.end, get_indent(source_text, case.span.start), String::new()),
+      None => (node.span.end - 1, format!("{switch_indent}  "), format!("\n{switch_indent}")),
+    };
+    let mut replacement = String::new();
+    for case in &missing {
+      replacement.push_str(&format!(
+        "\n{case_indent}case {case}: {{ throw new Error('Not implemented yet: {case} case'); }}"
+      ));
+    }
+    replacement.push_str(&suffix);
+    let edits = vec![TextEdit { span: Span::new(position, position), replacement }];
+    ctx.diagnostic_with_fix(
+      format!("Switch is not exhaustive. Cases not matched: {}", missing.join(" | ")),
+      node.discriminant.span(),
+      "Add branches for missing cases",
+      edits,
+    );
+  }
+}
+
+/// The whitespace at the start of the line which contains the offset.
+fn get_indent(source_text: &str, offset: u32) -> String {
+  let line_start = source_text[..offset as usize].rfind('\n').map_or(0, |index| index + 1);
+  source_text[line_start..].chars().take_while(|c| *c == ' ' || *c == '\t').collect()
+}
@@ -0,0 +1,115 @@
+use oxc::{
+  ast::{
+    ast::{Expression, LogicalOperator},
+    AstKind,
+  },
+  semantic::NodeId,
+  span::{GetSpan, Span},
+};
+
+use crate::{lint::LintContext, query::TypeHandle};
+
+/// Whether the type of the expression is a thenable, e.g. `Promise<T>` or the result of calling an
+/// async function.
+pub fn is_promise_valued<'a>(ctx: &mut LintContext<'_, 'a>, node: &Expression<'a>) -> bool {
+  let Some(ty) = ctx.type_of_span(node.without_parentheses().span()) else {
+    return false;
+  };
+  ty.is_promise_like(ctx.analyzer())
+}
+
+/// The closest function or arrow function which contains the node.
+pub fn get_enclosing_function<'a>(ctx: &LintContext<'_, 'a>, node: NodeId) -> Option<AstKind<'a>> {
+  let nodes = ctx.semantic().nodes();
+  let mut current = node;
+  while let Some(parent) = nodes.parent_node(current) {
+    if let kind @ (AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)) = parent.kind() {
+      return Some(kind);
+    }
+    current = parent.id();
+  }
+  None
+}
+
+/// Whether an `any` value which flows into a position of this type can not be checked. `any` and
+/// `unknown` accept everything, and `Ty::Error` is a failure of the analyzer, not a declared type.
+pub fn is_typed_position(ty: TypeHandle) -> bool {
+  !(ty.is_any() || ty.is_unknown() || ty.is_error())
+}
+
+/// The expression tested for truthiness by a statement or a conditional expression.
+pub fn get_condition_test(kind: AstKind<'_>) -> Option<&Expression<'_>> {
+  match kind {
+    AstKind::IfStatement(node) => Some(&node.test),
+    AstKind::WhileStatement(node) => Some(&node.test),
+    AstKind::DoWhileStatement(node) => Some(&node.test),
+    AstKind::ForStatement(node) => node.test.as_ref(),
+    AstKind::ConditionalExpression(node) => Some(&node.test),
+    _ => None,
+  }
+}
+
+/// The operands of a condition which are tested for truthiness. For `a && (b || c)`, these are
+/// `a`, `b` and `c`.
+pub fn get_tested_operands<'a>(node: &'a Expression<'a>) -> Vec<&'a Expression<'a>> {
+  match node.without_parentheses() {
+    Expression::LogicalExpression(logical) if logical.operator != LogicalOperator::Coalesce => {
+      let mut operands = get_tested_operands(&logical.left);
+      operands.extend(get_tested_operands(&logical.right));
+      operands
+    }
+    node => vec![node],
+  }
+}
+
+/// Whether the node is a condition, or an operand of `&&` or `||` in a condition. These are
+/// covered by `get_tested_operands` of the condition.
+pub fn is_in_condition(ctx: &LintContext, node: NodeId) -> bool {
+  let nodes = ctx.semantic().nodes();
+  let mut current = nodes.get_node(node);
+  while let Some(parent) = nodes.parent_node(current.id()) {
+    match parent.kind() {
+      AstKind::ParenthesizedExpression(_) => {}
+      AstKind::LogicalExpression(logical) if logical.operator != LogicalOperator::Coalesce => {}
+      kind => {
+        return get_condition_test(kind).is_some_and(|test| test.span() == current.kind().span());
+      }
+    }
+    current = parent;
+  }
+  false
+}
+
+/// Whether some member of the type is not meant to be stringified or added: objects, symbols,
+/// `unknown` and `never`. `any` is allowed.
+pub fn has_unstringifiable_member<'a>(ctx: &mut LintContext<'_, 'a>, ty: TypeHandle<'a>) -> bool {
+  if ty.is_any() || ty.is_error() {
+    return false;
+  }
+  ty.union_members().into_iter().any(|member| {
+    let analyzer = ctx.analyzer();
+    member.is_unknown()
+      || member.is_never()
+      || member.is_symbol_like(analyzer)
+      || member.is_object_like(analyzer)
+  })
+}
+
+/// The span of the operator token in `gap`, which contains only the operator, whitespace and
+/// comments, e.g. between the operands of a binary expression.
+pub fn find_operator(ctx: &LintContext, gap: Span, operator: &str) -> Option<Span> {
+  let semantic = ctx.semantic();
+  let search = |span: Span| {
+    let offset = span.source_text(semantic.source_text()).find(operator)?;
+    let start = span.start + offset as u32;
+    Some(Span::new(start, start + operator.len() as u32))
+  };
+  let mut start = gap.start;
+  for comment in semantic.comments_range(gap.start..gap.end) {
+    if let Some(span) = search(Span::new(start, comment.span.start)) {
+      return Some(span);
+    }
+    start = comment.span.end;
+  }
+  search(Span::new(start, gap.end))
+}
@@ -5,6 +5,7 @@ use crate::{
   ty::{
     property_key::PropertyKeyType,
     tuple::{TupleElement, TupleType},
+    union::UnionReduction,
     Ty,
   },
 };
@@ -34,22 +35,22 @@ impl<'a> Analyzer<'a> {
     }
 
     if as_const {
-      Ty::Tuple(
-        self.allocator.alloc(TupleType {
-          elements: values
-            .into_iter()
-            .map(|(spread, ty)| TupleElement { name: None, spread, ty, optional: false })
-            .collect(),
-          readonly: true,
-        }),
-      )
+      self.intern_tuple(TupleType {
+        elements: values
+          .into_iter()
+          .map(|(spread, ty)| TupleElement { name: None, spread, ty, optional: false })
+          .collect(),
+        readonly: true,
+      })
     } else {
       let types = values
         .into_iter()
         .map(|(spread, ty)| if spread { self.iterate_result_union(ty) } else { ty })
         .collect::<Vec<_>>();
-      let el_type = self.into_union(types).unwrap_or(Ty::Never);
-      todo!("Array<{:?}>", el_type);
+      let _el_type =
+        self.into_union_with_reduction(types, UnionReduction::Subtype).unwrap_or(Ty::Never);
+      // TODO: Array<el_type>
+      self.unsupported("array types")
     }
   }
 }
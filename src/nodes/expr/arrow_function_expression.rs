@@ -1,13 +1,62 @@
 use oxc::ast::ast::ArrowFunctionExpression;
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  ty::{assignable::AssignmentKind, callable::CallableType, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_arrow_function_expression(
     &mut self,
     node: &'a ArrowFunctionExpression<'a>,
-    _sat: Option<Ty<'a>>,
+    sat: Option<Ty<'a>>,
   ) -> Ty<'a> {
-    todo!()
+    let type_params = node
+      .type_parameters
+      .as_ref()
+      .map(|type_parameters| self.resolve_type_parameter_declaration(type_parameters))
+      .unwrap_or_default();
+
+    let (contextual_params, contextual_ret) = self.get_contextual_signature(sat);
+    let (_, params, rest_param) = self.exec_formal_parameters(&node.params, contextual_params);
+
+    let annotated_ret = node.return_type.as_ref().map(|n| self.resolve_type(&n.type_annotation));
+    let contextual_ret = contextual_ret.filter(|_| !node.r#async);
+    let inferred_ret = if node.expression {
+      self.exec_function_expression_body(&node.body, node.r#async, annotated_ret.or(contextual_ret))
+    } else {
+      // Arrow functions capture `this` lexically
+      let this = self.call_scopes.last().unwrap().this;
+      self.exec_function_body(
+        &node.body,
+        node.r#async,
+        false,
+        Some(this),
+        annotated_ret,
+        contextual_ret,
+      )
+    };
+    if let (Some(annotated_ret), Some(expression)) = (annotated_ret, node.get_expression()) {
+      if !node.r#async {
+        self.check_expression_assignable(
+          expression,
+          AssignmentKind::Value,
+          inferred_ret,
+          annotated_ret,
+        );
+      }
+    }
+    let return_type = self.ctx_ty_from_annotation(&node.return_type, Some(inferred_ret));
+
+    self.intern_function(CallableType {
+      is_method: false,
+      scope: self.type_scopes.top(),
+      type_params,
+      this_param: None,
+      params,
+      rest_param,
+      return_type,
+      param_names: self.get_parameter_names(&node.params),
+    })
   }
 }
@@ -6,7 +6,7 @@ impl<'a> Analyzer<'a> {
   pub fn exec_assignment_expression(
     &mut self,
     node: &'a AssignmentExpression<'a>,
-    sat: Option<Ty<'a>>,
+    _sat: Option<Ty<'a>>,
   ) -> Ty<'a> {
     if node.operator == AssignmentOperator::Assign {
       let (left, cache) = self.exec_assignment_target_read(&node.left);
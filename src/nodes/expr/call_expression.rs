@@ -22,11 +22,15 @@ impl<'a> Analyzer<'a> {
     node: &'a CallExpression<'a>,
     sat: Option<Ty<'a>>,
   ) -> (bool, Ty<'a>) {
-    let (mut indeterminate, callee, this_arg) = self.exec_callee(&node.callee);
-
-    if !indeterminate && node.optional {
-      self.push_indeterminate_scope();
-      indeterminate = true;
+    let (mut indeterminate, mut callee, this_arg) = self.exec_callee(&node.callee);
+
+    // The rest of the chain is short-circuited if the callee may be nullish
+    if node.optional && self.test_nullish(callee) != Some(false) {
+      callee = self.non_nullable(callee);
+      if !indeterminate {
+        self.push_indeterminate_scope();
+        indeterminate = true;
+      }
     }
 
     let callable = self.extract_callable_function(callee);
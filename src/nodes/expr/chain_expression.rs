@@ -32,7 +32,8 @@ impl<'a> Analyzer<'a> {
     node: &'a Expression<'a>,
     sat: Option<Ty<'a>>,
   ) -> (bool, Ty<'a>) {
-    match node {
+    self.push_span(node);
+    let (indeterminate, value) = match node {
       match_member_expression!(Expression) => {
         self.exec_member_expression_read_in_chain(node.to_member_expression(), None).0
       }
@@ -42,7 +43,15 @@ impl<'a> Analyzer<'a> {
         (indeterminate, self.non_nullable(value))
       }
       Expression::ChainExpression(_) => unreachable!(),
-      _ => (false, self.exec_expression(node, sat)),
-    }
+      _ => {
+        self.pop_span();
+        return (false, self.exec_expression(node, sat));
+      }
+    };
+    // The links inside a chain have their types when it is not short-circuited, e.g. `a.b` in
+    // `a?.b.c` is not `undefined`
+    self.accumulate_type(node, value);
+    self.pop_span();
+    (indeterminate, value)
   }
 }
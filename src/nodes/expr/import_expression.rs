@@ -8,13 +8,13 @@ impl<'a> Analyzer<'a> {
     node: &'a ImportExpression<'a>,
     _sat: Option<Ty<'a>>,
   ) -> Ty<'a> {
-    let source = self.exec_expression(&node.source, Some(Ty::String));
+    self.exec_expression(&node.source, Some(Ty::String));
 
     for argument in &node.arguments {
       // FIXME: This first argument is `ImportCallOptions`
       self.exec_expression(argument, Some(Ty::Any));
     }
 
-    todo!()
+    self.unsupported("dynamic import")
   }
 }
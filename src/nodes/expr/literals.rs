@@ -2,7 +2,10 @@ use oxc::ast::ast::{
   BigIntLiteral, BooleanLiteral, NullLiteral, NumericLiteral, RegExpLiteral, StringLiteral,
 };
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  ty::{unresolved::UnresolvedType, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_string_literal(&mut self, node: &'a StringLiteral, _sat: Option<Ty<'a>>) -> Ty<'a> {
@@ -30,6 +33,45 @@ impl<'a> Analyzer<'a> {
     _node: &'a RegExpLiteral<'a>,
     _sat: Option<Ty<'a>>,
   ) -> Ty<'a> {
-    todo!()
+    self.unsupported("RegExp")
+  }
+
+  /// Literal expressions keep their literal types only if the contextual type expects literals of
+  /// the same primitive, e.g. `"a" | "b"`. Otherwise they are widened. Enum members are literals of
+  /// their enum. Type parameters being inferred decide whether to widen later.
+  ///
+  /// This does not depend on `check_assignability`, since the inferred types should be the same
+  /// with and without the checks, e.g. `identity(1)` is `1` in both.
+  pub fn get_contextual_literal_type(&mut self, literal: Ty<'a>, sat: Option<Ty<'a>>) -> Ty<'a> {
+    let widened = self.get_widened_type(literal);
+    let is_expected = |ty: Ty<'a>| match (ty, literal) {
+      (Ty::EnumMember(expected), Ty::EnumMember(literal)) => {
+        expected.enum_symbol == literal.enum_symbol
+      }
+      (Ty::Unresolved(UnresolvedType::InferType(_)), _) => true,
+      _ => matches!(
+        (ty, widened),
+        (Ty::StringLiteral(_), Ty::String)
+          | (Ty::NumericLiteral(_), Ty::Number)
+          | (Ty::BigIntLiteral(_), Ty::BigInt)
+          | (Ty::BooleanLiteral(_), Ty::Boolean)
+          // `boolean` is the union `true | false`, so it expects its literals
+          | (Ty::Boolean, Ty::Boolean)
+      ),
+    };
+    let expected = match sat {
+      Some(Ty::Union(u)) => {
+        let mut expected = false;
+        u.for_each(|ty| expected |= is_expected(ty));
+        expected
+      }
+      Some(sat) => is_expected(sat),
+      None => false,
+    };
+    if expected {
+      literal
+    } else {
+      widened
+    }
   }
 }
@@ -1,6 +1,9 @@
-use oxc::ast::ast::LogicalExpression;
+use oxc::ast::ast::{LogicalExpression, LogicalOperator};
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  ty::{facts::Facts, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_logical_expression(
@@ -11,9 +14,21 @@ impl<'a> Analyzer<'a> {
     let left = self.exec_expression(&node.left, sat);
 
     self.push_indeterminate_scope();
+    match node.operator {
+      LogicalOperator::And => self.narrow_by_condition(&node.left, true),
+      LogicalOperator::Or => self.narrow_by_condition(&node.left, false),
+      LogicalOperator::Coalesce => {}
+    }
     let right = self.exec_expression(&node.right, sat);
     self.pop_scope();
 
+    // Only the part of the left operand which short-circuits is the result
+    let excluded = match node.operator {
+      LogicalOperator::And => Facts::TRUTHY,
+      LogicalOperator::Or => Facts::FALSY,
+      LogicalOperator::Coalesce => Facts::IS_NULLISH,
+    };
+    let left = self.narrow_by_facts(left, excluded);
     self.into_union([left, right]).unwrap()
   }
 }
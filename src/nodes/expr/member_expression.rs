@@ -27,11 +27,15 @@ impl<'a> Analyzer<'a> {
     node: &'a MemberExpression<'a>,
     _sat: Option<Ty<'a>>,
   ) -> ((bool, Ty<'a>), (Ty<'a>, PropertyKeyType<'a>)) {
-    let (mut indeterminate, object) = self.exec_expression_in_chain(node.object(), None);
+    let (mut indeterminate, mut object) = self.exec_expression_in_chain(node.object(), None);
 
-    if !indeterminate && node.optional() {
-      self.push_indeterminate_scope();
-      indeterminate = true;
+    // The rest of the chain is short-circuited if the object may be nullish
+    if node.optional() && self.test_nullish(object) != Some(false) {
+      object = self.non_nullable(object);
+      if !indeterminate {
+        self.push_indeterminate_scope();
+        indeterminate = true;
+      }
     }
 
     let key = self.exec_key(node);
@@ -59,7 +63,8 @@ impl<'a> Analyzer<'a> {
   fn exec_key(&mut self, node: &'a MemberExpression<'a>) -> PropertyKeyType<'a> {
     let value = match node {
       MemberExpression::ComputedMemberExpression(node) => {
-        self.exec_expression(&node.expression, None)
+        // Literal keys are not widened, so that they select keyed properties and template patterns
+        self.exec_expression_with_as_const(&node.expression, None, true)
       }
       MemberExpression::StaticMemberExpression(node) => self.exec_identifier_name(&node.property),
       MemberExpression::PrivateFieldExpression(node) => self.exec_private_identifier(&node.field),
@@ -35,6 +35,9 @@ impl<'a> Analyzer<'a> {
   pub fn exec_expression(&mut self, node: &'a Expression<'a>, sat: Option<Ty<'a>>) -> Ty<'a> {
     let span = node.span();
     self.push_span(&span);
+    if let Some(sat) = sat {
+      self.accumulate_contextual_type(span, sat);
+    }
     let value = self.exec_expression_impl(node, sat);
     self.accumulate_type(&span, value);
     self.pop_span();
@@ -53,11 +56,13 @@ impl<'a> Analyzer<'a> {
 
     let span = node.span();
     self.push_span(&span);
+    if let Some(sat) = sat {
+      self.accumulate_contextual_type(span, sat);
+    }
 
     let value = match node {
       match_member_expression!(Expression) => {
-        // TODO: Enum member
-        todo!()
+        self.exec_member_expression_read(node.to_member_expression(), sat).0
       }
       Expression::StringLiteral(node) => Ty::StringLiteral(&node.value),
       Expression::NumericLiteral(node) => Ty::NumericLiteral(node.value.into()),
@@ -83,14 +88,25 @@ impl<'a> Analyzer<'a> {
   fn exec_expression_impl(&mut self, node: &'a Expression<'a>, sat: Option<Ty<'a>>) -> Ty<'a> {
     match node {
       match_member_expression!(Expression) => {
-        self.exec_member_expression_read(node.to_member_expression(), sat).0
+        match self.exec_member_expression_read(node.to_member_expression(), sat).0 {
+          member @ Ty::EnumMember(_) => self.get_contextual_literal_type(member, sat),
+          value => value,
+        }
+      }
+      Expression::StringLiteral(node) => {
+        self.get_contextual_literal_type(Ty::StringLiteral(&node.value), sat)
+      }
+      Expression::NumericLiteral(node) => {
+        self.get_contextual_literal_type(Ty::NumericLiteral(node.value.into()), sat)
+      }
+      Expression::BigIntLiteral(node) => {
+        self.get_contextual_literal_type(Ty::BigIntLiteral(&node.raw), sat)
+      }
+      Expression::BooleanLiteral(node) => {
+        self.get_contextual_literal_type(Ty::BooleanLiteral(node.value), sat)
       }
-      Expression::StringLiteral(_) => Ty::String,
-      Expression::NumericLiteral(_) => Ty::Number,
-      Expression::BigIntLiteral(_) => Ty::BigInt,
-      Expression::BooleanLiteral(_) => Ty::Boolean,
       Expression::NullLiteral(_) => Ty::Null,
-      Expression::RegExpLiteral(_) => todo!("RegExp type"),
+      Expression::RegExpLiteral(_) => self.unsupported("RegExp"),
       Expression::TemplateLiteral(node) => self.exec_template_literal(node, sat),
       Expression::Identifier(node) => self.exec_identifier_reference_read(node, sat),
       Expression::FunctionExpression(node) => self.exec_function(node, sat),
@@ -6,14 +6,14 @@ impl<'a> Analyzer<'a> {
   pub fn exec_new_expression(
     &mut self,
     node: &'a NewExpression<'a>,
-    sat: Option<Ty<'a>>,
+    _sat: Option<Ty<'a>>,
   ) -> Ty<'a> {
     let callee = self.exec_expression(&node.callee, None);
 
     let callable = self.extract_callable_function(callee);
 
-    self.exec_call(callable, &node.type_parameters, Ty::Error, &node.arguments, sat);
+    self.exec_call(callable, &node.type_parameters, Ty::Error, &node.arguments, None);
 
-    todo!()
+    self.unsupported("`new` expressions")
   }
 }
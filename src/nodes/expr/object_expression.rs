@@ -2,7 +2,7 @@ use oxc::ast::ast::{ObjectExpression, ObjectPropertyKind, PropertyKind};
 
 use crate::{
   analyzer::Analyzer,
-  ty::{record::RecordTypeBuilder, Ty},
+  ty::{assignable::AssignmentKind, record::RecordTypeBuilder, Ty},
 };
 
 impl<'a> Analyzer<'a> {
@@ -20,7 +20,9 @@ impl<'a> Analyzer<'a> {
           let key = self.exec_property_key(&node.key);
           let sat = sat.map(|sat| self.get_property(sat, key));
           let value = self.exec_expression_with_as_const(&node.value, sat, as_const);
-          let value = value;
+          if let Some(sat) = sat {
+            self.check_expression_assignable(&node.value, AssignmentKind::Value, value, sat);
+          }
 
           // tsc doesn't care. So we don't care either.
           // if matches!(&node.key, PropertyKey::StaticIdentifier(node) if node.name == "__proto__") {
@@ -29,7 +31,7 @@ impl<'a> Analyzer<'a> {
 
           let value = match node.kind {
             PropertyKind::Init => value,
-            PropertyKind::Get | PropertyKind::Set => todo!(),
+            PropertyKind::Get | PropertyKind::Set => self.unsupported("accessors"),
           };
           object.init_property(self, key, value, false, as_const);
         }
@@ -40,6 +42,6 @@ impl<'a> Analyzer<'a> {
       }
     }
 
-    Ty::Record(self.allocator.alloc(object.build()))
+    self.intern_record(object.build())
   }
 }
@@ -4,6 +4,6 @@ use crate::{analyzer::Analyzer, ty::Ty};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_super(&mut self, _node: &'a Super, _sat: Option<Ty<'a>>) -> Ty<'a> {
-    todo!()
+    self.unsupported("super")
   }
 }
@@ -8,19 +8,17 @@ impl<'a> Analyzer<'a> {
     node: &'a TaggedTemplateExpression<'a>,
     _sat: Option<Ty<'a>>,
   ) -> Ty<'a> {
-    let (indeterminate, tag, this) = self.exec_callee(&node.tag);
+    let (indeterminate, _tag, _this) = self.exec_callee(&node.tag);
 
     if indeterminate {
       self.pop_scope();
     }
 
-    let mut arguments = vec![todo!()];
-
     for expr in &node.quasi.expressions {
-      let value = self.exec_expression(expr, todo!());
-      arguments.push((false, value));
+      self.exec_expression(expr, None);
     }
 
-    todo!()
+    // TODO: TemplateStringsArray
+    self.unsupported("tagged templates")
   }
 }
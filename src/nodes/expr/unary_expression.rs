@@ -5,6 +5,7 @@ use oxc::{
 
 use crate::{
   analyzer::Analyzer,
+  diagnostic::DiagnosticCode,
   ty::{facts::Facts, property_key::PropertyKeyType, Ty},
 };
 
@@ -22,7 +23,10 @@ impl<'a> Analyzer<'a> {
           self.delete_property(object, key)
         }
         Expression::PrivateFieldExpression(node) => {
-          self.add_diagnostic("SyntaxError: private fields can't be deleted");
+          self.add_diagnostic(
+            DiagnosticCode::DeletePrivateField,
+            "The operand of a 'delete' operator cannot be a private identifier",
+          );
           let _object = self.exec_expression(&node.object, None);
         }
         Expression::ComputedMemberExpression(node) => {
@@ -32,7 +36,10 @@ impl<'a> Analyzer<'a> {
           self.delete_property(object, key)
         }
         Expression::Identifier(_node) => {
-          self.add_diagnostic("SyntaxError: Delete of an unqualified identifier in strict mode");
+          self.add_diagnostic(
+            DiagnosticCode::DeleteIdentifier,
+            "'delete' cannot be called on an identifier in strict mode",
+          );
         }
         expr => {
           self.exec_expression(expr, None);
@@ -45,7 +52,7 @@ impl<'a> Analyzer<'a> {
     let argument = self.exec_expression(&node.argument, None);
 
     match &node.operator {
-      UnaryOperator::UnaryNegation => todo!(),
+      UnaryOperator::UnaryNegation => self.get_to_numeric(argument),
       UnaryOperator::UnaryPlus => self.get_to_numeric(argument),
       UnaryOperator::LogicalNot => Ty::Boolean,
       UnaryOperator::BitwiseNot => self.get_to_numeric(argument),
@@ -53,9 +60,9 @@ impl<'a> Analyzer<'a> {
         let facts = self.get_facts(argument);
         let values = TYPEOF_VALUES
           .iter()
-          .filter_map(|(fact, value)| facts.contains(*fact).then_some(Ty::StringLiteral(value)))
+          .filter_map(|(fact, value)| (!facts.contains(*fact)).then_some(Ty::StringLiteral(value)))
           .collect::<Vec<_>>();
-        self.into_union(values).unwrap()
+        self.into_union(values).unwrap_or(Ty::Never)
       }
       UnaryOperator::Void => Ty::Undefined,
       UnaryOperator::Delete => unreachable!(),
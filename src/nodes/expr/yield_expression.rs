@@ -6,8 +6,11 @@ impl<'a> Analyzer<'a> {
   pub fn exec_yield_expression(
     &mut self,
     node: &'a YieldExpression<'a>,
-    sat: Option<Ty<'a>>,
+    _sat: Option<Ty<'a>>,
   ) -> Ty<'a> {
-    todo!()
+    if let Some(argument) = &node.argument {
+      self.exec_expression(argument, None);
+    }
+    self.unsupported("generators")
   }
 }
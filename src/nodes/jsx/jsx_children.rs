@@ -19,6 +19,6 @@ impl<'a> Analyzer<'a> {
         JSXChild::Spread(node) => self.exec_jsx_spread_child(node),
       };
     }
-    todo!("JSXElement");
+    self.unsupported("JSX elements")
   }
 }
@@ -9,8 +9,8 @@ impl<'a> Analyzer<'a> {
       // FIXME: Spread
       self.get_callable_parameter_types(self.type_scopes.constraints_scope, &func)[0].1
     });
-    let attributes = self.exec_jsx_attributes(&node.opening_element, attr_sat);
-    let children = self.exec_jsx_children(&node.children);
+    let _attributes = self.exec_jsx_attributes(&node.opening_element, attr_sat);
+    let _children = self.exec_jsx_children(&node.children);
     // attributes.init_property(
     //   self,
     //   PropertyKind::Init,
@@ -19,6 +19,6 @@ impl<'a> Analyzer<'a> {
     //   true,
     // );
     // self.factory.react_element(tag, attributes)
-    todo!()
+    self.unsupported("JSX elements")
   }
 }
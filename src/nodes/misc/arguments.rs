@@ -1,35 +1,53 @@
 use oxc::{allocator, ast::ast::Argument};
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  ty::{assignable::AssignmentKind, tuple::TupleType, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_arguments(
     &mut self,
     node: &'a allocator::Vec<'a, Argument<'a>>,
-    mut sat: Option<Vec<(bool, Ty<'a>)>>,
+    sat: Option<Vec<(bool, Ty<'a>)>>,
   ) {
-    for (i, arg) in node.iter().enumerate() {
+    let mut index = sat.as_ref().map(|_| 0);
+    for arg in node {
       match arg {
         Argument::SpreadElement(node) => {
-          self.exec_expression(&node.argument, None);
-          sat = None;
+          let value = self.exec_expression(&node.argument, None);
+          index = self.get_index_after_spread(index, value);
         }
         node => {
-          self.exec_expression(
-            node.to_expression(),
-            if let Some(s) = sat.as_ref() {
-              if let Some((false, ty)) = s.get(i) {
-                Some(*ty)
-              } else {
-                sat = None;
-                None
-              }
-            } else {
-              None
+          let node = node.to_expression();
+          let sat = match (sat.as_ref(), index) {
+            (Some(sat), Some(i)) => match sat.get(i) {
+              Some((false, ty)) => Some(*ty),
+              _ => None,
             },
-          );
+            _ => None,
+          };
+          index = index.map(|i| i + 1);
+          let value = self.exec_expression(node, sat);
+          if let Some(sat) = sat {
+            self.check_expression_assignable(node, AssignmentKind::Argument, value, sat);
+          }
         }
       }
     }
   }
+
+  /// The index of the parameter for the argument after a spread one. It is only known if the
+  /// spread value is a tuple of fixed length.
+  pub fn get_index_after_spread(&mut self, index: Option<usize>, spread: Ty<'a>) -> Option<usize> {
+    index.zip(self.get_fixed_tuple(spread)).map(|(i, tuple)| i + tuple.elements.len())
+  }
+
+  /// The tuple without optional or rest elements, e.g. `[number, string]`.
+  pub fn get_fixed_tuple(&mut self, ty: Ty<'a>) -> Option<&'a TupleType<'a>> {
+    match self.get_lowest_type(ty) {
+      Ty::Tuple(tuple) if tuple.elements.iter().all(|el| !el.spread && !el.optional) => Some(tuple),
+      _ => None,
+    }
+  }
 }
@@ -3,8 +3,8 @@ use oxc::ast::ast::Class;
 use crate::{analyzer::Analyzer, ty::Ty};
 
 impl<'a> Analyzer<'a> {
-  pub fn exec_class(&mut self, node: &'a Class<'a>, _sat: Option<Ty<'a>>) -> Ty<'a> {
-    todo!()
+  pub fn exec_class(&mut self, _node: &'a Class<'a>, _sat: Option<Ty<'a>>) -> Ty<'a> {
+    self.unsupported("classes")
   }
 
   pub fn declare_class(&mut self, node: &'a Class<'a>) {
@@ -12,6 +12,8 @@ impl<'a> Analyzer<'a> {
   }
 
   pub fn init_class(&mut self, node: &'a Class<'a>) -> Ty<'a> {
-    todo!()
+    let ty = self.unsupported("classes");
+    self.init_binding_identifier(node.id.as_ref().unwrap(), Some(ty));
+    ty
   }
 }
@@ -1,22 +1,34 @@
 use oxc::ast::ast::FormalParameters;
 
-use crate::{analyzer::Analyzer, ty::ctx::CtxTy};
+use crate::{
+  analyzer::Analyzer,
+  ty::{ctx::CtxTy, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_formal_parameters(
     &mut self,
     node: &'a FormalParameters<'a>,
+    contextual: Option<Vec<(bool, Ty<'a>)>>,
   ) -> (Option<CtxTy<'a>>, Vec<(bool, CtxTy<'a>)>, Option<CtxTy<'a>>) {
-    for param in &node.items {
-      self.declare_binding_pattern(&param.pattern, param.pattern.type_annotation.is_some());
+    // Parameters without annotations take their types from the contextual signature
+    let contextual = contextual.unwrap_or_default();
+    let contextual_param = |index: usize| match contextual.get(index) {
+      Some((false, ty)) => Some(*ty),
+      _ => None,
+    };
+
+    for (index, param) in node.items.iter().enumerate() {
+      let typed = param.pattern.type_annotation.is_some() || contextual_param(index).is_some();
+      self.declare_binding_pattern(&param.pattern, typed);
     }
     if let Some(rest) = &node.rest {
       self.declare_binding_rest_element(rest, false);
     }
 
     let mut params = vec![];
-    for param in &node.items {
-      let inferred = self.init_binding_pattern(&param.pattern, None);
+    for (index, param) in node.items.iter().enumerate() {
+      let inferred = self.init_binding_pattern(&param.pattern, contextual_param(index));
       params.push((
         param.pattern.optional || param.pattern.kind.is_assignment_pattern(),
         self.ctx_ty_from_annotation(&param.pattern.type_annotation, inferred),
@@ -46,13 +58,20 @@ impl<'a> Analyzer<'a> {
       ));
     }
 
-    let rest = if let Some(rest) = &node.rest {
-      Some(self.ctx_ty_from_annotation(&rest.argument.type_annotation, None))
-    } else {
-      None
-    };
+    let rest = node
+      .rest
+      .as_ref()
+      .map(|rest| self.ctx_ty_from_annotation(&rest.argument.type_annotation, None));
 
     // TODO: this type
     (None, params, rest)
   }
+
+  /// The names of the parameters, and then of the rest parameter, for printing. Destructured
+  /// parameters have no names.
+  pub fn get_parameter_names(&self, node: &'a FormalParameters<'a>) -> Vec<Option<&'a str>> {
+    let params = node.items.iter().map(|param| &param.pattern);
+    let rest = node.rest.iter().map(|rest| &rest.argument);
+    params.chain(rest).map(|pattern| pattern.get_identifier().map(|name| name.as_str())).collect()
+  }
 }
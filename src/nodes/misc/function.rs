@@ -1,4 +1,4 @@
-use oxc::ast::ast::Function;
+use oxc::{ast::ast::Function, semantic::ScopeId};
 
 use crate::{
   analyzer::Analyzer,
@@ -6,14 +6,16 @@ use crate::{
 };
 
 impl<'a> Analyzer<'a> {
-  pub fn exec_function(&mut self, node: &'a Function<'a>, _sat: Option<Ty<'a>>) -> Ty<'a> {
+  pub fn exec_function(&mut self, node: &'a Function<'a>, sat: Option<Ty<'a>>) -> Ty<'a> {
     let type_params = node
       .type_parameters
       .as_ref()
-      .map(|type_parameters| self.resolve_type_parameter_declaration(&type_parameters))
+      .map(|type_parameters| self.resolve_type_parameter_declaration(type_parameters))
       .unwrap_or_default();
 
-    let (this_param, params, rest_param) = self.exec_formal_parameters(&node.params);
+    let (contextual_params, contextual_ret) = self.get_contextual_signature(sat);
+    let (this_param, params, rest_param) =
+      self.exec_formal_parameters(&node.params, contextual_params);
 
     let annotated_ret = node.return_type.as_ref().map(|n| &n.type_annotation);
     let inferred_ret = if let Some(body) = &node.body {
@@ -24,13 +26,14 @@ impl<'a> Analyzer<'a> {
         node.generator,
         /*TODO:*/ None,
         resolved_annotated,
+        contextual_ret.filter(|_| !node.r#async && !node.generator),
       )
     } else {
       Ty::Error
     };
     let return_type = self.ctx_ty_from_annotation(&node.return_type, Some(inferred_ret));
 
-    Ty::Function(self.allocator.alloc(CallableType {
+    self.intern_function(CallableType {
       is_method: false,
       scope: self.type_scopes.top(),
       type_params,
@@ -38,14 +41,48 @@ impl<'a> Analyzer<'a> {
       params,
       rest_param,
       return_type,
-    }))
+      param_names: self.get_parameter_names(&node.params),
+    })
   }
 
   pub fn declare_function(&mut self, node: &'a Function<'a>) {
-    let symbol = node.id.as_ref().unwrap().symbol_id.get().unwrap();
+    let id = node.id.as_ref().unwrap();
     let value = self.exec_function(node, None);
 
+    // Overload signatures and `declare function` are not bound to symbols. Overloads share the
+    // symbol of their implementation.
+    let scopes = self.semantic.scopes();
+    let scope = scopes.get_parent_id(node.scope_id()).unwrap();
+    let Some(symbol) = id.symbol_id.get().or_else(|| scopes.get_binding(scope, &id.name)) else {
+      self.ambient_functions.entry((scope, id.name.as_str())).or_default().push(value);
+      return;
+    };
+
+    let value = if node.body.is_none() {
+      // Overload signature, or `declare function`
+      let overloads = self.function_overloads.entry(symbol).or_default();
+      overloads.push(value);
+      let overloads = overloads.clone();
+      self.into_overloaded(overloads)
+    } else if self.function_overloads.contains_key(&symbol) {
+      // The implementation signature is not visible from outside
+      return;
+    } else {
+      value
+    };
+
     self.declare_variable(symbol, true);
     self.init_variable(symbol, value);
   }
+
+  /// The signatures of a `declare function` visible from `scope`.
+  pub fn read_ambient_function(&mut self, scope: ScopeId, name: &str) -> Option<Ty<'a>> {
+    let scope = self
+      .semantic
+      .scopes()
+      .ancestors(scope)
+      .find(|scope| self.ambient_functions.contains_key(&(*scope, name)))?;
+    let signatures = self.ambient_functions[&(scope, name)].clone();
+    Some(self.into_overloaded(signatures))
+  }
 }
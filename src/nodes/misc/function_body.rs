@@ -6,7 +6,7 @@ use crate::{
     call::{CallScope, CallScopeReturnType},
     control::CfScopeKind,
   },
-  ty::Ty,
+  ty::{union::UnionReduction, Ty},
 };
 
 impl<'a> Analyzer<'a> {
@@ -17,6 +17,7 @@ impl<'a> Analyzer<'a> {
     is_generator: bool,
     this: Option<Ty<'a>>,
     annotated_ret: Option<Ty<'a>>,
+    contextual_ret: Option<Ty<'a>>,
   ) -> Ty<'a> {
     let body_scope = self.push_scope(CfScopeKind::Function);
     self.call_scopes.push(CallScope::new(
@@ -25,6 +26,7 @@ impl<'a> Analyzer<'a> {
       is_generator,
       this.unwrap_or(Ty::Any),
       annotated_ret,
+      contextual_ret,
     ));
 
     self.exec_statement_vec(&node.statements);
@@ -32,7 +34,21 @@ impl<'a> Analyzer<'a> {
     self.pop_scope();
     match self.call_scopes.pop().unwrap().ret {
       CallScopeReturnType::Annotated(ty) => ty,
-      CallScopeReturnType::Inferred(mut acc) => acc.to_ty().unwrap_or(Ty::Void),
+      CallScopeReturnType::Inferred(mut acc) => {
+        let ty = match acc.to_ty() {
+          Some(ty @ Ty::Union(_)) => {
+            self.into_union_with_reduction([ty], UnionReduction::Subtype).unwrap()
+          }
+          Some(ty) => ty,
+          None => Ty::Void,
+        };
+        // The inferred return type of generators is not wrapped yet
+        if is_async && !is_generator {
+          self.wrap_async_return_type(ty)
+        } else {
+          ty
+        }
+      }
     }
   }
 
@@ -48,6 +64,16 @@ impl<'a> Analyzer<'a> {
     self.push_scope(CfScopeKind::Function);
     let value = self.exec_expression(&expr.expression, annotated_ret);
     self.pop_scope();
-    value
+    if is_async {
+      self.wrap_async_return_type(value)
+    } else {
+      value
+    }
+  }
+
+  /// Async functions return `Promise<T>`, where `T` is the awaited returned value.
+  fn wrap_async_return_type(&mut self, returned: Ty<'a>) -> Ty<'a> {
+    let value = self.get_to_awaited(returned);
+    self.get_promise_type(value)
   }
 }
@@ -14,8 +14,9 @@ impl<'a> Analyzer<'a> {
     if let Some(symbol) = symbol {
       self.read_variable(symbol)
     } else {
+      let scope = self.semantic.nodes().get_node(reference.node_id()).scope_id();
       // TODO: globals and `arguments`
-      Ty::Unknown
+      self.read_ambient_function(scope, &node.name).unwrap_or(Ty::Unknown)
     }
   }
 
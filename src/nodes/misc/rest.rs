@@ -18,6 +18,6 @@ impl<'a> Analyzer<'a> {
       rest.remove_property(self, key);
     }
 
-    Ty::Record(self.allocator.alloc(rest.build()))
+    self.intern_record(rest.build())
   }
 }
@@ -1,6 +1,9 @@
 use oxc::ast::ast::VariableDeclarator;
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  ty::{assignable::AssignmentKind, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn declare_variable_declarator(&mut self, node: &'a VariableDeclarator) {
@@ -15,12 +18,16 @@ impl<'a> Analyzer<'a> {
   ) {
     let init = if let Some(type_annotation) = &node.id.type_annotation {
       let ty = self.resolve_type_annotation(type_annotation);
-      node.init.as_ref().map(|init| self.exec_expression(init, Some(ty)));
+      if let Some(init) = &node.init {
+        let value = self.exec_expression(init, Some(ty));
+        self.check_expression_assignable(init, AssignmentKind::Value, value, ty);
+      }
       Some(ty)
     } else if let Some(loop_init) = loop_init {
       Some(loop_init)
     } else if let Some(init) = &node.init {
-      if node.kind.is_const() && init.is_literal() {
+      // `const a = E.A` keeps the enum member type
+      if node.kind.is_const() && (init.is_literal() || init.is_member_expression()) {
         Some(self.exec_expression_with_as_const(init, None, true))
       } else {
         Some(self.exec_expression(init, None))
@@ -20,7 +20,16 @@ impl<'a> Analyzer<'a> {
       Declaration::TSInterfaceDeclaration(node) => {
         self.declare_ts_interface(node);
       }
-      _ => todo!(),
+      Declaration::TSEnumDeclaration(node) => {
+        self.declare_ts_enum(node);
+      }
+      Declaration::TSModuleDeclaration(node) => {
+        self.declare_ts_module(node);
+      }
+      Declaration::TSImportEqualsDeclaration(node) => {
+        // Declared anyway, so that references to it don't break
+        self.declare_binding_identifier(&node.id, true);
+      }
     }
   }
 
@@ -41,7 +50,16 @@ impl<'a> Analyzer<'a> {
       Declaration::TSInterfaceDeclaration(node) => {
         self.init_ts_interface(node);
       }
-      _ => todo!(),
+      Declaration::TSEnumDeclaration(node) => {
+        self.init_ts_enum(node);
+      }
+      Declaration::TSModuleDeclaration(node) => {
+        self.init_ts_module(node);
+      }
+      Declaration::TSImportEqualsDeclaration(node) => {
+        let ty = self.unsupported("`import =` declarations");
+        self.init_binding_identifier(&node.id, Some(ty));
+      }
     }
   }
 }
@@ -12,18 +12,33 @@ impl<'a> Analyzer<'a> {
       self.push_indeterminate_scope();
     }
 
+    self.narrow_by_condition(&node.test, true);
     self.exec_statement(&node.consequent);
 
     if let Some(alternate) = &node.alternate {
       let scope_1 = self.runtime_scopes.pop();
 
       self.push_exit_blocker_scope();
+      self.narrow_by_condition(&node.test, false);
       self.exec_statement(alternate);
       let scope_2 = self.runtime_scopes.pop();
 
       self.finalize_complementary_scopes(scope_1, scope_2);
+
+      // Only the other branch continues, e.g. `if (x) { return; } else { ... }`
+      match (self.is_scope_exited(scope_1), self.is_scope_exited(scope_2)) {
+        (true, false) => self.narrow_by_condition(&node.test, false),
+        (false, true) => self.narrow_by_condition(&node.test, true),
+        _ => {}
+      }
     } else {
+      let scope = *self.runtime_scopes.stack.last().unwrap();
       self.pop_scope();
+
+      // e.g. `if (x === undefined) return;`
+      if self.is_scope_exited(scope) {
+        self.narrow_by_condition(&node.test, false);
+      }
     }
   }
 }
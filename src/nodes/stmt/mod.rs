@@ -68,7 +68,9 @@ impl<'a> Analyzer<'a> {
       Statement::ThrowStatement(node) => self.exec_throw_statement(node),
       Statement::EmptyStatement(_) => {}
       Statement::DebuggerStatement(_node) => {}
-      Statement::WithStatement(_node) => unimplemented!("with statement"),
+      Statement::WithStatement(_node) => {
+        self.unsupported("with statement");
+      }
     }
     self.pop_span();
   }
@@ -1,37 +1,23 @@
-use oxc::ast::ast::{ExportDefaultDeclarationKind, ModuleDeclaration};
+use oxc::{
+  ast::ast::{
+    ExportDefaultDeclarationKind, ImportDeclaration, ImportDeclarationSpecifier, ModuleDeclaration,
+  },
+  span::GetSpan,
+};
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  diagnostic::{Diagnostic, DiagnosticCode},
+  ty::{namespace::NamespaceType, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn declare_module_declaration(&mut self, node: &'a ModuleDeclaration<'a>) {
     match node {
       ModuleDeclaration::ImportDeclaration(node) => {
         if let Some(specifiers) = &node.specifiers {
-          let name = node.source.value.as_str();
-          let known = self.resolve_module(name);
-
           for specifier in specifiers {
-            let value = if let Some(known) = known {
-              // match specifier {
-              //   ImportDeclarationSpecifier::ImportDefaultSpecifier(_node) => known.default,
-              //   ImportDeclarationSpecifier::ImportNamespaceSpecifier(_node) => known.namespace,
-              //   ImportDeclarationSpecifier::ImportSpecifier(node) => {
-              //     let key = Ty::StringLiteral(match &node.imported {
-              //       ModuleExportName::IdentifierName(identifier) => &identifier.name,
-              //       ModuleExportName::IdentifierReference(identifier) => &identifier.name,
-              //       ModuleExportName::StringLiteral(literal) => &literal.value,
-              //     });
-              //     self.get_property(known.namespace, key)
-              //   }
-              // }
-              todo!()
-            } else {
-              Ty::Unknown
-            };
-
-            let local = specifier.local();
-            self.declare_binding_identifier(local, true);
-            self.init_binding_identifier(local, Some(value));
+            self.declare_binding_identifier(specifier.local(), true);
           }
         }
       }
@@ -76,7 +62,7 @@ impl<'a> Analyzer<'a> {
 
   pub fn init_module_declaration(&mut self, node: &'a ModuleDeclaration<'a>) {
     match node {
-      ModuleDeclaration::ImportDeclaration(_node) => {}
+      ModuleDeclaration::ImportDeclaration(node) => self.init_import_declaration(node),
       ModuleDeclaration::ExportNamedDeclaration(node) => {
         if node.source.is_some() {
           // Re-exports. Nothing to do.
@@ -109,9 +95,64 @@ impl<'a> Analyzer<'a> {
       ModuleDeclaration::TSExportAssignment(node) => {
         self.exec_expression(&node.expression, None);
       }
-      ModuleDeclaration::TSNamespaceExportDeclaration(node) => {
-        todo!()
+      ModuleDeclaration::TSNamespaceExportDeclaration(_node) => {
+        // `export as namespace` only declares a UMD global
+      }
+    }
+  }
+
+  fn init_import_declaration(&mut self, node: &'a ImportDeclaration<'a>) {
+    let Some(specifiers) = &node.specifiers else {
+      return;
+    };
+    let name = node.source.value.as_str();
+    if let Some(namespace) = self.module_augmentations.get(name).copied() {
+      for specifier in specifiers {
+        self.init_import_from_module_declaration(namespace, specifier);
+      }
+      return;
+    }
+
+    let known = self.resolve_module(name);
+    for specifier in specifiers {
+      let value = if let Some(_known) = known {
+        self.unsupported("imports from resolved modules")
+      } else {
+        Ty::Unknown
+      };
+      self.init_binding_identifier(specifier.local(), Some(value));
+    }
+  }
+
+  /// Imports from a module declared with `declare module "m" {}`, which has both values and types.
+  fn init_import_from_module_declaration(
+    &mut self,
+    namespace: &'a NamespaceType<'a>,
+    specifier: &'a ImportDeclarationSpecifier<'a>,
+  ) {
+    let local = specifier.local();
+    let symbol = local.symbol_id();
+    let imported = match specifier {
+      ImportDeclarationSpecifier::ImportSpecifier(node) => node.imported.name(),
+      ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => "default".into(),
+      ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {
+        self.namespaces.insert(symbol, namespace);
+        self.init_binding_identifier(local, Some(Ty::Namespace(namespace)));
+        return;
       }
+    };
+    let value = namespace.values.borrow().get(imported.as_str()).copied();
+    let ty = namespace.get_type(&imported);
+    if value.is_none() && ty.is_none() {
+      self.report(Diagnostic::error(
+        DiagnosticCode::NoExportedModuleMember,
+        format!("Module '\"{}\"' has no exported member '{}'.", namespace.name, imported),
+        specifier.span(),
+      ));
+    }
+    if let Some(ty) = ty {
+      self.type_scopes.insert_on_top(symbol, ty);
     }
+    self.init_binding_identifier(local, Some(value.unwrap_or(Ty::Error)));
   }
 }
@@ -1,19 +1,35 @@
 use oxc::ast::ast::ReturnStatement;
 
-use crate::{analyzer::Analyzer, scope::call::CallScopeReturnType, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  scope::call::CallScopeReturnType,
+  ty::{assignable::AssignmentKind, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_return_statement(&mut self, node: &'a ReturnStatement) {
+    self.exec_return_argument(node);
+    self.exit_to_function();
+  }
+
+  fn exec_return_argument(&mut self, node: &'a ReturnStatement) {
     let call_scope = self.call_scopes.last().unwrap();
     match &call_scope.ret {
       CallScopeReturnType::Annotated(ty) => {
+        let expected = *ty;
+        // The annotated return type of async functions and generators is not the returned value
+        let checked = !call_scope.is_async && !call_scope.is_generator;
         if let Some(argument) = &node.argument {
-          self.exec_expression(argument, Some(*ty));
+          let value = self.exec_expression(argument, Some(expected));
+          if checked {
+            self.check_expression_assignable(argument, AssignmentKind::Value, value, expected);
+          }
         }
       }
       CallScopeReturnType::Inferred(_) => {
+        let sat = call_scope.contextual_ret;
         let ty = if let Some(argument) = &node.argument {
-          self.exec_expression(argument, None)
+          self.exec_expression(argument, sat)
         } else {
           Ty::Undefined
         };
@@ -1,9 +1,34 @@
 use oxc::ast::ast::SwitchStatement;
 
-use crate::analyzer::Analyzer;
+use crate::{analyzer::Analyzer, scope::control::CfScopeKind};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_switch_statement(&mut self, node: &'a SwitchStatement<'a>) {
-    todo!()
+    let discriminant = self.exec_expression(&node.discriminant, None);
+
+    self.push_scope(CfScopeKind::Switch);
+
+    // The cases share one block scope
+    for case in &node.cases {
+      for statement in &case.consequent {
+        self.declare_statement(statement);
+      }
+    }
+
+    for case in &node.cases {
+      if let Some(test) = &case.test {
+        // Literal cases are compared with the discriminant, so they are not widened
+        self.exec_expression(test, Some(discriminant));
+      }
+
+      // Any case may be skipped, or fall through to the next one
+      self.push_indeterminate_scope();
+      for statement in &case.consequent {
+        self.init_statement(statement);
+      }
+      self.pop_scope();
+    }
+
+    self.pop_scope();
   }
 }
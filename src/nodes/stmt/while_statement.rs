@@ -10,6 +10,7 @@ impl<'a> Analyzer<'a> {
     self.pop_scope();
 
     self.push_loop_scope();
+    self.narrow_by_condition(&node.test, true);
     self.exec_statement(&node.body);
     self.pop_scope();
   }
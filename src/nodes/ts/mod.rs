@@ -1,11 +1,15 @@
 mod ts_as_expression;
 mod ts_conditional_type;
+mod ts_enum_declaration;
 mod ts_function_type;
+mod ts_indexed_access_type;
 mod ts_infer_type;
 mod ts_instantiation_expression;
 mod ts_interface_declaration;
 mod ts_intersection_type;
 mod ts_literal;
+mod ts_mapped_type;
+mod ts_module_declaration;
 mod ts_non_null_expression;
 mod ts_operator_type;
 mod ts_satisfies_expression;
@@ -32,7 +36,7 @@ impl<'a> Analyzer<'a> {
       TSType::TSAnyKeyword(_) => Ty::Any,
       TSType::TSBigIntKeyword(_) => Ty::BigInt,
       TSType::TSBooleanKeyword(_) => Ty::Boolean,
-      TSType::TSIntrinsicKeyword(_) => todo!(),
+      TSType::TSIntrinsicKeyword(_) => self.unsupported("intrinsic keyword"),
       TSType::TSNeverKeyword(_) => Ty::Never,
       TSType::TSNullKeyword(_) => Ty::Null,
       TSType::TSNumberKeyword(_) => Ty::Number,
@@ -55,9 +59,17 @@ impl<'a> Analyzer<'a> {
       TSType::TSConditionalType(node) => self.resolve_conditional_type(node),
       TSType::TSTypeOperatorType(node) => self.resolve_operator_type(node),
       TSType::TSTupleType(node) => self.resolve_tuple_type(node, false),
+      TSType::TSIndexedAccessType(node) => self.resolve_indexed_access_type(node),
+      TSType::TSMappedType(node) => self.resolve_mapped_type(node),
       TSType::TSNamedTupleMember(_) => unreachable!("Handled in TSTupleElement"),
 
-      _ => todo!(),
+      TSType::TSArrayType(_) => self.unsupported("array types"),
+      TSType::TSConstructorType(_) => self.unsupported("constructor types"),
+      TSType::TSImportType(_) => self.unsupported("import types"),
+      TSType::TSTemplateLiteralType(_) => self.unsupported("template literal types"),
+      TSType::TSThisType(_) => self.unsupported("`this` types"),
+      TSType::TSTypePredicate(_) => self.unsupported("type predicates"),
+      _ => self.unsupported("JSDoc types"),
     }
   }
 }
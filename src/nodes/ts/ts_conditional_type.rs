@@ -1,19 +1,68 @@
 use crate::{
-  ty::{r#match::MatchResult, Ty},
+  ty::{conditional::ConditionalType, r#match::MatchResult, unresolved::UnresolvedType, Ty},
   Analyzer,
 };
-use oxc::ast::ast::TSConditionalType;
+use oxc::{
+  ast::ast::{TSConditionalType, TSType, TSTypeName},
+  semantic::{SymbolFlags, SymbolId},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn resolve_conditional_type(&mut self, node: &'a TSConditionalType<'a>) -> Ty<'a> {
-    let target = self.resolve_type(&node.check_type);
-    let pattern = self.resolve_type(&node.extends_type);
+    let check_type = self.resolve_type(&node.check_type);
+
+    // Distributive only when the check type is a naked type parameter. `[T] extends [U]` is not
+    let Some(symbol) = self.get_naked_type_parameter(&node.check_type) else {
+      return self.evaluate_conditional_type(node, check_type);
+    };
+    let check_type = match check_type {
+      Ty::Instance(instance) => self.unwrap_generic_instance(instance),
+      ty => ty,
+    };
+    let mut members = vec![];
+    let mut add_member = |ty| match ty {
+      Ty::Boolean => members.extend([Ty::BooleanLiteral(true), Ty::BooleanLiteral(false)]),
+      ty => members.push(ty),
+    };
+    match check_type {
+      Ty::Union(union) => union.for_each(&mut add_member),
+      Ty::Never => {}
+      ty => add_member(ty),
+    }
+
+    let mut results = Vec::new();
+    for member in members {
+      // The type parameter is narrowed to the member in the branches
+      self.type_scopes.push();
+      self.type_scopes.insert_on_top(symbol, member);
+      results.push(self.evaluate_conditional_type(node, member));
+      self.type_scopes.pop();
+    }
+    self.into_union(results).unwrap_or(Ty::Never)
+  }
+
+  fn evaluate_conditional_type(
+    &mut self,
+    node: &'a TSConditionalType<'a>,
+    check_type: Ty<'a>,
+  ) -> Ty<'a> {
+    let extends_type = self.resolve_type(&node.extends_type);
+    if is_generic_check_type(check_type) {
+      return self.defer_conditional_type(node, check_type, extends_type);
+    }
+
+    let matches = if let Ty::Union(_) = check_type {
+      // Not distributed, so the union is matched as a whole
+      vec![self.match_covariant_types(1, check_type, extends_type)]
+    } else {
+      self.match_types_with_dispatch(check_type, extends_type)
+    };
 
     let mut matched_no_infer = None;
     let mut unmatched = None;
     let mut results = Vec::new();
 
-    for m in self.match_types_with_dispatch(target, pattern) {
+    for m in matches {
       match m {
         MatchResult::Error => return Ty::Error,
         MatchResult::Matched => {
@@ -47,4 +96,58 @@ impl<'a> Analyzer<'a> {
 
     self.into_union(results).unwrap()
   }
+
+  /// The branches are resolved with the `infer` declarations referring to themselves, so that the
+  /// conditional can be printed.
+  fn defer_conditional_type(
+    &mut self,
+    node: &'a TSConditionalType<'a>,
+    check_type: Ty<'a>,
+    extends_type: Ty<'a>,
+  ) -> Ty<'a> {
+    self.type_scopes.push();
+    let infer_declarations = self.semantic.scopes().get_bindings(node.scope_id());
+    for symbol in infer_declarations.values() {
+      self
+        .type_scopes
+        .insert_on_top(*symbol, Ty::Unresolved(UnresolvedType::GenericParam(*symbol)));
+    }
+    let true_type = self.resolve_type(&node.true_type);
+    let false_type = self.resolve_type(&node.false_type);
+    self.type_scopes.pop();
+
+    Ty::Unresolved(UnresolvedType::Conditional(self.allocator.alloc(ConditionalType {
+      check_type,
+      extends_type,
+      true_type,
+      false_type,
+      node,
+    })))
+  }
+
+  fn get_naked_type_parameter(&self, node: &TSType<'a>) -> Option<SymbolId> {
+    let TSType::TSTypeReference(node) = node else {
+      return None;
+    };
+    let TSTypeName::IdentifierReference(id) = &node.type_name else {
+      return None;
+    };
+    if node.type_parameters.is_some() {
+      return None;
+    }
+    let symbols = self.semantic.symbols();
+    let symbol = symbols.get_reference(id.reference_id()).symbol_id()?;
+    symbols.get_flags(symbol).contains(SymbolFlags::TypeParameter).then_some(symbol)
+  }
+}
+
+/// Whether the check type can only be matched after the type parameters are instantiated.
+fn is_generic_check_type(ty: Ty) -> bool {
+  match ty {
+    Ty::Unresolved(
+      UnresolvedType::GenericParam(_) | UnresolvedType::Keyof(_) | UnresolvedType::Conditional(_),
+    ) => true,
+    Ty::Tuple(tuple) => tuple.elements.iter().any(|element| is_generic_check_type(element.ty)),
+    _ => false,
+  }
 }
@@ -0,0 +1,61 @@
+use oxc::ast::ast::{Expression, TSEnumDeclaration, TSEnumMemberName, UnaryOperator};
+
+use crate::{
+  ty::{property_key::PropertyKeyType, r#enum::EnumMemberType, record::RecordTypeBuilder, Ty},
+  Analyzer,
+};
+
+impl<'a> Analyzer<'a> {
+  pub fn declare_ts_enum(&mut self, node: &'a TSEnumDeclaration<'a>) {
+    let symbol_id = node.id.symbol_id();
+
+    let mut members = vec![];
+    let mut object = RecordTypeBuilder::default();
+    // The value of the next member without an initializer
+    let mut next = Some(0.0);
+    for member in &node.members {
+      let name = match &member.id {
+        TSEnumMemberName::Identifier(id) => &id.name,
+        TSEnumMemberName::String(s) => &s.value,
+      };
+      let value = match &member.initializer {
+        None => next.map_or(Ty::Number, |n: f64| Ty::NumericLiteral(n.into())),
+        Some(Expression::NumericLiteral(n)) => Ty::NumericLiteral(n.value.into()),
+        Some(Expression::UnaryExpression(unary)) => match (&unary.operator, &unary.argument) {
+          (UnaryOperator::UnaryNegation, Expression::NumericLiteral(n)) => {
+            Ty::NumericLiteral((-n.value).into())
+          }
+          // TODO: Constant expressions
+          _ => Ty::Number,
+        },
+        Some(Expression::StringLiteral(s)) => Ty::StringLiteral(&s.value),
+        // TODO: Constant expressions, e.g. references to other members
+        Some(_) => Ty::Number,
+      };
+      next = match value {
+        Ty::NumericLiteral(n) => Some(n.0 + 1.0),
+        _ => None,
+      };
+
+      let member = Ty::EnumMember(self.allocator.alloc(EnumMemberType {
+        enum_symbol: symbol_id,
+        name,
+        value,
+      }));
+      members.push(member);
+      object.init_property(self, PropertyKeyType::StringLiteral(name), member, false, true);
+    }
+
+    let ty = self.into_union(members).unwrap_or(Ty::Never);
+    self.type_scopes.insert_on_top(symbol_id, ty);
+    self.enums.insert(symbol_id, ty);
+
+    self.declare_binding_identifier(&node.id, true);
+    let object = self.intern_record(object.build());
+    self.init_binding_identifier(&node.id, Some(object));
+  }
+
+  pub fn init_ts_enum(&mut self, _node: &'a TSEnumDeclaration<'a>) {
+    // Do nothing
+  }
+}
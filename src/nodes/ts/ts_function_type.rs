@@ -17,7 +17,7 @@ impl<'a> Analyzer<'a> {
     let (_, params, rest_param) = self.resolve_formal_parameters(&node.params);
     let return_type = self.ctx_ty_from_ts_type(&node.return_type.type_annotation);
 
-    Ty::Function(self.allocator.alloc(CallableType {
+    self.intern_function(CallableType {
       is_method: false,
       scope: self.type_scopes.top(),
       type_params,
@@ -25,6 +25,7 @@ impl<'a> Analyzer<'a> {
       params,
       rest_param,
       return_type,
-    }))
+      param_names: self.get_parameter_names(&node.params),
+    })
   }
 }
@@ -0,0 +1,22 @@
+use oxc::ast::ast::TSIndexedAccessType;
+
+use crate::{ty::Ty, Analyzer};
+
+impl<'a> Analyzer<'a> {
+  pub fn resolve_indexed_access_type(&mut self, node: &'a TSIndexedAccessType<'a>) -> Ty<'a> {
+    let object = self.resolve_type(&node.object_type);
+    let index = self.resolve_type(&node.index_type);
+    self.get_indexed_access_type(object, index)
+  }
+
+  /// `T[K]`, which is distributed over the members of `K`.
+  fn get_indexed_access_type(&mut self, object: Ty<'a>, index: Ty<'a>) -> Ty<'a> {
+    if let Ty::Union(union) = index {
+      let mut types = vec![];
+      union.for_each(|index| types.push(self.get_indexed_access_type(object, index)));
+      return self.into_union(types).unwrap();
+    }
+    let key = self.to_property_key(index);
+    self.get_property_value(object, key).map_or(Ty::Error, |property| property.value)
+  }
+}
@@ -1,56 +1,39 @@
-use oxc::ast::ast::{Expression, TSInterfaceDeclaration};
+use oxc::ast::ast::TSInterfaceDeclaration;
 
 use crate::{
-  ty::{interface::InterfaceTypeInner, unresolved::UnresolvedType, Ty},
+  ty::{interface::InterfaceType, Ty},
   Analyzer,
 };
 
 impl<'a> Analyzer<'a> {
   pub fn declare_ts_interface(&mut self, node: &'a TSInterfaceDeclaration<'a>) {
     let symbol_id = node.id.symbol_id();
-    self
-      .type_scopes
-      .insert_on_top(symbol_id, Ty::Unresolved(UnresolvedType::UnInitType(symbol_id)));
+    let type_params = node
+      .type_parameters
+      .as_ref()
+      .map(|type_params| self.resolve_type_parameter_declaration(type_params))
+      .unwrap_or_default();
+    if let Some(ty @ Ty::Interface(interface)) = self.type_scopes.get_on_top(symbol_id) {
+      // Declaration merging
+      interface.merge_declaration(node, type_params);
+      self.accumulate_type(&node.id, ty);
+      return;
+    }
+    let interface =
+      self.allocator.alloc(InterfaceType::new(node, self.type_scopes.top(), type_params));
+    let ty = Ty::Interface(interface);
+    self.type_scopes.insert_on_top(symbol_id, ty);
+    self.accumulate_type(&node.id, ty);
   }
 
   pub fn init_ts_interface(&mut self, node: &'a TSInterfaceDeclaration<'a>) -> Ty<'a> {
-    let symbol_id = node.id.symbol_id();
-
-    let params =
-      node.type_parameters.as_ref().map(|params| self.resolve_type_parameter_declaration(params));
-
-    let ty = self.type_scopes.get_mut_on_top(symbol_id).unwrap();
-
-    let mut interface = match ty {
-      Ty::Interface(interface) => interface.0.borrow_mut(),
-      _ => unreachable!(),
-    };
-
-    let InterfaceTypeInner { callables, .. } = &mut *interface;
-    if let Some(new_record) = self.resolve_signature_vec(&node.body.body, callables) {
-      interface.record.extend(new_record);
-    }
-
-    if let Some(extends) = &node.extends {
-      for heritage in extends {
-        match &heritage.expression {
-          Expression::Identifier(id) => {
-            let base = self.resolve_type_identifier_reference(id);
-            let extends = if let Some(type_parameters) = &heritage.type_parameters {
-              let type_parameters = self.resolve_type_parameter_instantiation(type_parameters);
-              self.create_generic_instance(base, type_parameters)
-            } else {
-              base
-            };
-            interface.extend(extends);
-          }
-          _ => {
-            // TODO: Error: An interface can only extend an identifier/qualified-name with optional type arguments.
-          }
-        }
+    let ty = self.type_scopes.get_on_top(node.id.symbol_id()).unwrap();
+    // Resolved here at the latest, so that errors in the declaration are reported
+    if let Ty::Interface(interface) = ty {
+      if !interface.is_generic() {
+        self.get_interface_members(interface);
       }
     }
-
-    self.type_scopes.get_on_top(symbol_id).unwrap()
+    ty
   }
 }
@@ -10,9 +10,9 @@ impl<'a> Analyzer<'a> {
       TSLiteral::StringLiteral(node) => Ty::StringLiteral(&node.value),
       TSLiteral::BigIntLiteral(node) => Ty::BigIntLiteral(&node.raw),
       TSLiteral::NullLiteral(_) => Ty::Null,
-      TSLiteral::RegExpLiteral(node) => todo!(),
-      TSLiteral::UnaryExpression(node) => todo!(),
-      TSLiteral::TemplateLiteral(node) => todo!(),
+      TSLiteral::RegExpLiteral(_) => self.unsupported("RegExp literal types"),
+      TSLiteral::UnaryExpression(_) => self.unsupported("unary expressions in literal types"),
+      TSLiteral::TemplateLiteral(_) => self.unsupported("template literal types"),
     }
   }
 }
@@ -0,0 +1,71 @@
+use oxc::ast::ast::{TSMappedType, TSMappedTypeModifierOperator, TSType, TSTypeOperatorOperator};
+
+use crate::{
+  ty::{record::RecordTypeBuilder, Ty},
+  Analyzer,
+};
+
+impl<'a> Analyzer<'a> {
+  /// Only mapped types over known keys are supported.
+  pub fn resolve_mapped_type(&mut self, node: &'a TSMappedType<'a>) -> Ty<'a> {
+    if node.name_type.is_some() {
+      return self.unsupported("key remapping in mapped types");
+    }
+    let Some(constraint) = &node.type_parameter.constraint else {
+      return Ty::Error;
+    };
+    // Homomorphic mapped types, e.g. `{ [K in keyof T]: T[K] }`, keep the modifiers of `T`
+    let source = match constraint {
+      TSType::TSTypeOperatorType(operator)
+        if operator.operator == TSTypeOperatorOperator::Keyof =>
+      {
+        Some(self.resolve_type(&operator.type_annotation))
+      }
+      _ => None,
+    };
+    let keys = match source {
+      Some(source) => self.get_keyof_type(source),
+      None => self.resolve_type(constraint),
+    };
+    let mut members = vec![];
+    match keys {
+      Ty::Union(union) => union.for_each(|key| members.push(key)),
+      Ty::Never => {}
+      key => members.push(key),
+    }
+
+    let symbol = node.type_parameter.name.symbol_id();
+    let mut record = RecordTypeBuilder::default();
+    for key in members {
+      let property_key = match key {
+        Ty::String
+        | Ty::Number
+        | Ty::Symbol
+        | Ty::StringLiteral(_)
+        | Ty::NumericLiteral(_)
+        | Ty::UniqueSymbol(_)
+        | Ty::EnumMember(_) => self.to_property_key(key),
+        _ => return self.unsupported("generic mapped types"),
+      };
+      let inherited = source.and_then(|source| self.get_property_value(source, property_key));
+
+      self.type_scopes.push();
+      self.type_scopes.insert_on_top(symbol, key);
+      let value = node.type_annotation.as_ref().map_or(Ty::Any, |ty| self.resolve_type(ty));
+      self.type_scopes.pop();
+
+      let optional = apply_modifier(node.optional, inherited.as_ref().is_some_and(|p| p.optional));
+      let readonly = apply_modifier(node.readonly, inherited.as_ref().is_some_and(|p| p.readonly));
+      record.init_property(self, property_key, value, optional, readonly);
+    }
+    self.intern_record(record.build())
+  }
+}
+
+fn apply_modifier(modifier: TSMappedTypeModifierOperator, inherited: bool) -> bool {
+  match modifier {
+    TSMappedTypeModifierOperator::True | TSMappedTypeModifierOperator::Plus => true,
+    TSMappedTypeModifierOperator::Minus => false,
+    TSMappedTypeModifierOperator::None => inherited,
+  }
+}
@@ -0,0 +1,215 @@
+use std::ptr;
+
+use oxc::ast::ast::{
+  BindingIdentifier, Declaration, Statement, TSModuleDeclaration, TSModuleDeclarationBody,
+  TSModuleDeclarationKind, TSModuleDeclarationName,
+};
+use oxc_ecmascript::BoundNames;
+
+use crate::{
+  ty::{namespace::NamespaceType, unresolved::UnresolvedType, Ty},
+  Analyzer,
+};
+
+impl<'a> Analyzer<'a> {
+  pub fn declare_ts_module(&mut self, node: &'a TSModuleDeclaration<'a>) {
+    if node.kind == TSModuleDeclarationKind::Global {
+      self.declare_ts_global(node);
+      return;
+    }
+    let namespace = match &node.id {
+      TSModuleDeclarationName::Identifier(id) => {
+        let symbol = id.symbol_id();
+        // Merged with a function or an enum, which may have been declared already
+        if !self.variables.contains_key(&symbol) {
+          self.declare_variable(symbol, true);
+        }
+        *self
+          .namespaces
+          .entry(symbol)
+          .or_insert_with(|| &*self.allocator.alloc(NamespaceType::new(&id.name)))
+      }
+      // `declare module "m";` without a body
+      TSModuleDeclarationName::StringLiteral(_) if node.body.is_none() => return,
+      TSModuleDeclarationName::StringLiteral(specifier) => {
+        // Other modules are not resolved, so the declarations are all the exports of the module
+        *self
+          .module_augmentations
+          .entry(specifier.value.as_str())
+          .or_insert_with(|| &*self.allocator.alloc(NamespaceType::new(&specifier.value)))
+      }
+    };
+
+    match &node.body {
+      Some(TSModuleDeclarationBody::TSModuleBlock(block)) => {
+        for statement in &block.body {
+          self.declare_statement(statement);
+        }
+        self.collect_namespace_exports(namespace, &block.body, false);
+      }
+      Some(TSModuleDeclarationBody::TSModuleDeclaration(nested)) => {
+        // `namespace A.B {}`, where `B` is exported implicitly
+        self.declare_ts_module(nested);
+        self.collect_nested_namespace(namespace, nested);
+      }
+      None => {}
+    }
+  }
+
+  pub fn init_ts_module(&mut self, node: &'a TSModuleDeclaration<'a>) {
+    match &node.body {
+      Some(TSModuleDeclarationBody::TSModuleBlock(block)) => {
+        for statement in &block.body {
+          self.init_statement(statement);
+        }
+      }
+      Some(TSModuleDeclarationBody::TSModuleDeclaration(nested)) => {
+        self.init_ts_module(nested);
+      }
+      None => {}
+    }
+
+    if node.kind == TSModuleDeclarationKind::Global {
+      return;
+    }
+    let id = match &node.id {
+      TSModuleDeclarationName::Identifier(id) => id,
+      TSModuleDeclarationName::StringLiteral(specifier) => {
+        if let Some(TSModuleDeclarationBody::TSModuleBlock(block)) = &node.body {
+          let namespace = self.module_augmentations[specifier.value.as_str()];
+          self.collect_namespace_exports(namespace, &block.body, true);
+        }
+        return;
+      }
+    };
+    let namespace = self.namespaces[&id.symbol_id()];
+    if let Some(TSModuleDeclarationBody::TSModuleBlock(block)) = &node.body {
+      self.collect_namespace_exports(namespace, &block.body, true);
+    }
+
+    let ty = Ty::Namespace(namespace);
+    match self.read_variable(id.symbol_id()) {
+      Ty::Unresolved(UnresolvedType::UnInitVariable(_)) | Ty::Namespace(_) => {}
+      merged => namespace.merged.set(Some(merged)),
+    }
+    self.init_variable(id.symbol_id(), ty);
+    self.accumulate_type(id, ty);
+  }
+
+  /// Interfaces and type aliases in `declare global` are visible by name from everywhere. Global
+  /// interfaces with the same name are merged.
+  fn declare_ts_global(&mut self, node: &'a TSModuleDeclaration<'a>) {
+    let Some(TSModuleDeclarationBody::TSModuleBlock(block)) = &node.body else {
+      return;
+    };
+    for statement in &block.body {
+      self.declare_statement(statement);
+    }
+    for statement in &block.body {
+      let (id, is_interface) = match statement {
+        Statement::TSInterfaceDeclaration(node) => (&node.id, true),
+        Statement::TSTypeAliasDeclaration(node) => (&node.id, false),
+        _ => continue,
+      };
+      let ty = self.type_scopes.search(id.symbol_id());
+      let name = id.name.as_str();
+      match (self.global_types.get(name).copied(), ty) {
+        (Some(Ty::Interface(existing)), Ty::Interface(interface)) if is_interface => {
+          if !ptr::eq(existing, interface) {
+            existing.merge_interface(interface);
+            self.type_scopes.insert_on_top(id.symbol_id(), Ty::Interface(existing));
+          }
+        }
+        (Some(_), _) => {}
+        (None, _) => {
+          self.global_types.insert(name, ty);
+        }
+      }
+    }
+  }
+
+  /// Exported types are collected when declared, and exported values when initialized.
+  fn collect_namespace_exports(
+    &mut self,
+    namespace: &'a NamespaceType<'a>,
+    body: &'a oxc::allocator::Vec<'a, Statement<'a>>,
+    init: bool,
+  ) {
+    for statement in body {
+      let Statement::ExportNamedDeclaration(export) = statement else {
+        continue;
+      };
+      let Some(declaration) = &export.declaration else {
+        continue;
+      };
+      let mut ids = vec![];
+      let mut push_id = |id: &BindingIdentifier<'a>| ids.push((id.name.clone(), id.symbol_id()));
+      match declaration {
+        Declaration::VariableDeclaration(node) => {
+          for declarator in &node.declarations {
+            declarator.id.bound_names(&mut push_id);
+          }
+        }
+        Declaration::FunctionDeclaration(node) => match &node.id {
+          // Overload signatures and `declare function`, which have no symbols
+          Some(id) if id.symbol_id.get().is_none() => {
+            if init {
+              if let Some(value) = self.read_ambient_function(node.scope_id(), &id.name) {
+                namespace.values.borrow_mut().insert(id.name.as_str(), value);
+              }
+            }
+          }
+          id => id.iter().for_each(push_id),
+        },
+        Declaration::ClassDeclaration(node) => node.id.iter().for_each(push_id),
+        Declaration::TSEnumDeclaration(node) => {
+          let ty = self.type_scopes.search(node.id.symbol_id());
+          namespace.types.borrow_mut().insert(node.id.name.as_str(), ty);
+          push_id(&node.id);
+        }
+        Declaration::TSInterfaceDeclaration(node) => {
+          let symbol = node.id.symbol_id();
+          let ty = self.type_scopes.search(symbol);
+          let existing = namespace.types.borrow().get(node.id.name.as_str()).copied();
+          match (existing, ty) {
+            // Declared in another block of the same namespace or module
+            (Some(Ty::Interface(existing)), Ty::Interface(interface)) => {
+              if !ptr::eq(existing, interface) {
+                existing.merge_interface(interface);
+                self.type_scopes.insert_on_top(symbol, Ty::Interface(existing));
+              }
+            }
+            _ => {
+              namespace.types.borrow_mut().insert(node.id.name.as_str(), ty);
+            }
+          }
+        }
+        Declaration::TSTypeAliasDeclaration(node) => {
+          let ty = self.type_scopes.search(node.id.symbol_id());
+          namespace.types.borrow_mut().insert(node.id.name.as_str(), ty);
+        }
+        Declaration::TSModuleDeclaration(node) => self.collect_nested_namespace(namespace, node),
+        Declaration::TSImportEqualsDeclaration(_) => {}
+      }
+      if init {
+        for (name, symbol) in ids {
+          let value = self.read_variable(symbol);
+          namespace.values.borrow_mut().insert(name.as_str(), value);
+        }
+      }
+    }
+  }
+
+  /// Nested namespaces are needed by qualified names before being initialized.
+  fn collect_nested_namespace(
+    &mut self,
+    namespace: &'a NamespaceType<'a>,
+    nested: &'a TSModuleDeclaration<'a>,
+  ) {
+    if let TSModuleDeclarationName::Identifier(id) = &nested.id {
+      if let Some(nested) = self.namespaces.get(&id.symbol_id()) {
+        namespace.values.borrow_mut().insert(id.name.as_str(), Ty::Namespace(nested));
+      }
+    }
+  }
+}
@@ -1,24 +1,21 @@
 use oxc::ast::ast::{TSType, TSTypeOperator, TSTypeOperatorOperator};
 
-use crate::{
-  ty::{unresolved::UnresolvedType, Ty},
-  Analyzer,
-};
+use crate::{ty::Ty, Analyzer};
 
 impl<'a> Analyzer<'a> {
   pub fn resolve_operator_type(&mut self, node: &'a TSTypeOperator<'a>) -> Ty<'a> {
     match node.operator {
       TSTypeOperatorOperator::Keyof => {
         let ty = self.resolve_type(&node.type_annotation);
-        Ty::Unresolved(UnresolvedType::Keyof(self.allocator.alloc(ty)))
+        self.get_keyof_type(ty)
       }
       TSTypeOperatorOperator::Readonly => match &node.type_annotation {
         TSType::TSTupleType(node) => self.resolve_tuple_type(node, true),
-        TSType::TSArrayType(node) => todo!(),
+        TSType::TSArrayType(_) => self.unsupported("array types"),
         _ => self.resolve_type(&node.type_annotation),
       },
       TSTypeOperatorOperator::Unique => match &node.type_annotation {
-        TSType::TSSymbolKeyword(_) => todo!(),
+        TSType::TSSymbolKeyword(_) => self.unsupported("unique symbol types"),
         _ => self.resolve_type(&node.type_annotation),
       },
     }
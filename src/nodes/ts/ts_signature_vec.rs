@@ -1,9 +1,12 @@
-use oxc::{allocator, ast::ast::TSSignature};
+use oxc::{
+  allocator,
+  ast::ast::{TSSignature, TSTemplateLiteralType, TSType},
+};
 
 use crate::{
   ty::{
     callable::CallableType,
-    property_key::PropertyKeyType,
+    property_key::{PropertyKeyType, TemplateHole, TemplatePattern},
     record::{RecordType, RecordTypeBuilder},
     Ty,
   },
@@ -16,12 +19,34 @@ impl<'a> Analyzer<'a> {
     node: &'a allocator::Vec<'a, TSSignature<'a>>,
     callables: &mut Vec<Ty<'a>>,
   ) -> Option<RecordType<'a>> {
-    let mut record: Option<RecordTypeBuilder<'a>> = None;
+    let mut record = None;
+    self.resolve_signature_vec_into(node, &mut record, callables);
+    record.map(RecordTypeBuilder::build)
+  }
 
+  /// Adds the members to an existing record, e.g. for merged interface declarations.
+  pub fn resolve_signature_vec_into(
+    &mut self,
+    node: &'a allocator::Vec<'a, TSSignature<'a>>,
+    record: &mut Option<RecordTypeBuilder<'a>>,
+    callables: &mut Vec<Ty<'a>>,
+  ) {
     for member in node {
       match member {
         TSSignature::TSIndexSignature(node) => {
-          let key = self.resolve_type_annotation(&node.parameters[0].type_annotation);
+          let key_annotation = &node.parameters[0].type_annotation;
+          if let TSType::TSTemplateLiteralType(template) = &key_annotation.type_annotation {
+            let value = self.resolve_type_annotation(&node.type_annotation);
+            if let Some(pattern) = self.resolve_template_pattern(template) {
+              record.get_or_insert_with(Default::default).init_pattern_property(
+                pattern,
+                value,
+                node.readonly,
+              );
+            }
+            continue;
+          }
+          let key = self.resolve_type_annotation(key_annotation);
           let key = self.to_property_key(key);
           let value = self.resolve_type_annotation(&node.type_annotation);
           record.get_or_insert_with(Default::default).init_property(
@@ -58,7 +83,7 @@ impl<'a> Analyzer<'a> {
           let (_, params, rest_param) = self.resolve_formal_parameters(&node.params);
           let return_type = self.ctx_ty_from_annotation(&node.return_type, None);
 
-          callables.push(Ty::Function(self.allocator.alloc(CallableType {
+          callables.push(self.intern_function(CallableType {
             is_method: false,
             scope: self.type_scopes.top(),
             type_params,
@@ -66,9 +91,29 @@ impl<'a> Analyzer<'a> {
             params,
             rest_param,
             return_type,
-          })))
+            param_names: self.get_parameter_names(&node.params),
+          }))
+        }
+        TSSignature::TSConstructSignatureDeclaration(node) => {
+          let type_params = node
+            .type_parameters
+            .as_ref()
+            .map(|type_params| self.resolve_type_parameter_declaration(type_params))
+            .unwrap_or_default();
+          let (_, params, rest_param) = self.resolve_formal_parameters(&node.params);
+          let return_type = self.ctx_ty_from_annotation(&node.return_type, None);
+
+          callables.push(self.intern_constructor(CallableType {
+            is_method: false,
+            scope: self.type_scopes.top(),
+            type_params,
+            this_param: None,
+            params,
+            rest_param,
+            return_type,
+            param_names: self.get_parameter_names(&node.params),
+          }))
         }
-        TSSignature::TSConstructSignatureDeclaration(node) => todo!(),
         TSSignature::TSMethodSignature(node) => {
           let type_params = node
             .type_parameters
@@ -80,7 +125,7 @@ impl<'a> Analyzer<'a> {
           let (_, params, rest_param) = self.resolve_formal_parameters(&node.params);
           let return_type = self.ctx_ty_from_annotation(&node.return_type, None);
 
-          let function = Ty::Function(self.allocator.alloc(CallableType {
+          let function = self.intern_function(CallableType {
             is_method: true,
             scope: self.type_scopes.top(),
             type_params,
@@ -88,7 +133,8 @@ impl<'a> Analyzer<'a> {
             params,
             rest_param,
             return_type,
-          }));
+            param_names: self.get_parameter_names(&node.params),
+          });
 
           let key = self.exec_property_key(&node.key);
 
@@ -111,7 +157,29 @@ impl<'a> Analyzer<'a> {
         }
       }
     }
+  }
 
-    record.map(RecordTypeBuilder::build)
+  /// Only `${string}` and `${number}` holes are supported.
+  fn resolve_template_pattern(
+    &mut self,
+    node: &'a TSTemplateLiteralType<'a>,
+  ) -> Option<TemplatePattern<'a>> {
+    let mut holes = vec![];
+    for ty in &node.types {
+      holes.push(match ty {
+        TSType::TSStringKeyword(_) => TemplateHole::String,
+        TSType::TSNumberKeyword(_) => TemplateHole::Number,
+        _ => {
+          self.unsupported("template literal types");
+          return None;
+        }
+      });
+    }
+    let quasis = node
+      .quasis
+      .iter()
+      .map(|quasi| quasi.value.cooked.as_ref().unwrap_or(&quasi.value.raw).as_str())
+      .collect();
+    Some(TemplatePattern { quasis, holes })
   }
 }
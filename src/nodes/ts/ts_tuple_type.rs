@@ -7,9 +7,19 @@ use crate::{
 
 impl<'a> Analyzer<'a> {
   pub fn resolve_tuple_type(&mut self, node: &'a TSTupleType<'a>, readonly: bool) -> Ty<'a> {
-    Ty::Tuple(self.allocator.alloc(TupleType {
-      elements: node.element_types.iter().map(|el| self.resolve_tuple_element(el)).collect(),
-      readonly,
-    }))
+    let mut elements = vec![];
+    for element in &node.element_types {
+      let element = self.resolve_tuple_element(element);
+      let spread = match element.ty {
+        Ty::Instance(instance) if element.spread => self.unwrap_generic_instance(instance),
+        ty => ty,
+      };
+      match spread {
+        // Spread tuples are flattened, e.g. `[...[1, 2], 3]` is `[1, 2, 3]`
+        Ty::Tuple(tuple) if element.spread => elements.extend(tuple.elements.iter().copied()),
+        _ => elements.push(element),
+      }
+    }
+    self.intern_tuple(TupleType { elements, readonly })
   }
 }
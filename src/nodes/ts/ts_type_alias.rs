@@ -1,7 +1,8 @@
-use oxc::ast::ast::TSTypeAliasDeclaration;
+use oxc::{ast::ast::TSTypeAliasDeclaration, semantic::SymbolId};
 
 use crate::{
-  ty::{generic::GenericType, Ty},
+  diagnostic::{Diagnostic, DiagnosticCode},
+  ty::{generic::GenericType, unresolved::UnresolvedType, Ty},
   Analyzer,
 };
 
@@ -16,7 +17,18 @@ impl<'a> Analyzer<'a> {
         body: self.ctx_ty_from_ts_type(&node.type_annotation),
       }))
     } else {
-      self.resolve_type(&node.type_annotation)
+      // References to this alias in the type itself stay unresolved, and are resolved lazily
+      let ty = self.resolve_type(&node.type_annotation);
+      if self.is_circular_type_alias(symbol_id, ty) {
+        self.report(Diagnostic::error(
+          DiagnosticCode::CircularTypeAlias,
+          format!("Type alias '{}' circularly references itself.", node.id.name),
+          node.id.span,
+        ));
+        Ty::Error
+      } else {
+        ty
+      }
     };
     self.type_scopes.insert_on_top(symbol_id, ty);
     self.accumulate_type(&node.id, ty);
@@ -25,4 +37,20 @@ impl<'a> Analyzer<'a> {
   pub fn init_ts_type_alias(&mut self, _node: &'a TSTypeAliasDeclaration<'a>) {
     // Do nothing
   }
+
+  /// Whether the alias resolves to itself through other aliases, e.g. `type A = B; type B = A`.
+  fn is_circular_type_alias(&self, symbol_id: SymbolId, mut ty: Ty<'a>) -> bool {
+    while let Ty::Unresolved(UnresolvedType::UnInitType(symbol)) = ty {
+      if symbol == symbol_id {
+        return true;
+      }
+      let resolved = self.type_scopes.search(symbol);
+      if resolved == ty {
+        // Declared later
+        return false;
+      }
+      ty = resolved;
+    }
+    false
+  }
 }
@@ -14,16 +14,17 @@ impl<'a> Analyzer<'a> {
     let record = self.resolve_signature_vec(&node.members, &mut callables);
 
     if callables.is_empty() {
-      Ty::Record(self.allocator.alloc(record.unwrap_or_default()))
+      self.intern_record(record.unwrap_or_default())
     } else {
       if let Some(record) = record {
-        callables.push(Ty::Record(self.allocator.alloc(record)));
+        let record = self.intern_record(record);
+        callables.push(record);
       }
-      Ty::Intersection(self.allocator.alloc(IntersectionType {
+      self.intern_intersection(IntersectionType {
         kind: IntersectionBaseKind::NoBase,
         object_like: callables,
         unresolved: Vec::new(),
-      }))
+      })
     }
   }
 }
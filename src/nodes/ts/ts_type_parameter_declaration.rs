@@ -1,6 +1,9 @@
 use oxc::ast::ast::TSTypeParameterDeclaration;
 
-use crate::{ty::generic::GenericParam, Analyzer};
+use crate::{
+  ty::{generic::GenericParam, unresolved::UnresolvedType, Ty},
+  Analyzer,
+};
 
 impl<'a> Analyzer<'a> {
   pub fn resolve_type_parameter_declaration(
@@ -12,6 +15,10 @@ impl<'a> Analyzer<'a> {
       .iter()
       .map(|param| {
         let symbol_id = param.name.symbol_id();
+        // Refers to itself until instantiated
+        self
+          .type_scopes
+          .insert_on_top(symbol_id, Ty::Unresolved(UnresolvedType::GenericParam(symbol_id)));
         let constraint = param.constraint.as_ref().map(|c| self.ctx_ty_from_ts_type(c));
         if let Some(constraint) = constraint {
           self.generic_constraints.insert(symbol_id, constraint);
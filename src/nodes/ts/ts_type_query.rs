@@ -8,8 +8,8 @@ impl<'a> Analyzer<'a> {
       TSTypeQueryExprName::IdentifierReference(node) => {
         self.exec_identifier_reference_read(node, None)
       }
-      TSTypeQueryExprName::TSImportType(_node) => todo!(),
-      TSTypeQueryExprName::QualifiedName(_node) => todo!(),
+      TSTypeQueryExprName::TSImportType(_node) => self.unsupported("import types"),
+      TSTypeQueryExprName::QualifiedName(_node) => self.unsupported("qualified names"),
     };
 
     if let Some(type_parameters) = &node.type_parameters {
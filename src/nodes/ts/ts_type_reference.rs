@@ -1,12 +1,20 @@
-use oxc::ast::ast::{IdentifierReference, TSTypeName, TSTypeReference};
+use oxc::ast::ast::{IdentifierReference, TSQualifiedName, TSTypeName, TSTypeReference};
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  diagnostic::{Diagnostic, DiagnosticCode},
+  ty::{namespace::NamespaceType, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn resolve_type_reference(&mut self, node: &'a TSTypeReference<'a>) -> Ty<'a> {
+    if let Some(value) = self.resolve_builtin_promise_reference(node) {
+      return self.get_promise_type(value);
+    }
+
     let base = match &node.type_name {
       TSTypeName::IdentifierReference(node) => self.resolve_type_identifier_reference(node),
-      TSTypeName::QualifiedName(_node) => todo!(),
+      TSTypeName::QualifiedName(node) => self.resolve_qualified_name(node),
     };
 
     if let Some(type_parameters) = &node.type_parameters {
@@ -17,13 +25,79 @@ impl<'a> Analyzer<'a> {
     }
   }
 
+  /// The type argument of `Promise<T>`, unless `Promise` is declared by the program.
+  fn resolve_builtin_promise_reference(&mut self, node: &'a TSTypeReference<'a>) -> Option<Ty<'a>> {
+    let TSTypeName::IdentifierReference(id) = &node.type_name else {
+      return None;
+    };
+    // Inferred types are serialized without references, e.g. the return types of async functions
+    let symbol = id
+      .reference_id
+      .get()
+      .and_then(|reference| self.semantic.symbols().get_reference(reference).symbol_id());
+    if id.name != "Promise" || symbol.is_some() || self.global_types.contains_key("Promise") {
+      return None;
+    }
+    let arg = node.type_parameters.as_ref().and_then(|params| params.params.first());
+    Some(arg.map_or(Ty::Error, |arg| self.resolve_type(arg)))
+  }
+
+  /// Enum members, e.g. `E.A`, and types exported from namespaces, e.g. `N.T`.
+  fn resolve_qualified_name(&mut self, node: &'a TSQualifiedName<'a>) -> Ty<'a> {
+    if let TSTypeName::IdentifierReference(left) = &node.left {
+      let reference = self.semantic.symbols().get_reference(left.reference_id());
+      if let Some(member) =
+        reference.symbol_id().and_then(|symbol| self.get_enum_member(symbol, &node.right.name))
+      {
+        return member;
+      }
+    }
+    if let Some(namespace) = self.resolve_namespace_name(&node.left) {
+      if let Some(ty) = namespace.get_type(&node.right.name) {
+        return ty;
+      }
+      self.report(Diagnostic::error(
+        DiagnosticCode::NoExportedMember,
+        format!("Namespace '{}' has no exported member '{}'.", namespace.name, node.right.name),
+        node.right.span,
+      ));
+      return Ty::Error;
+    }
+    self.unsupported("qualified names")
+  }
+
+  fn resolve_namespace_name(&mut self, node: &'a TSTypeName<'a>) -> Option<&'a NamespaceType<'a>> {
+    match node {
+      TSTypeName::IdentifierReference(node) => {
+        let reference = self.semantic.symbols().get_reference(node.reference_id());
+        // References to namespaces in types are not resolved by the binder
+        let symbol = reference.symbol_id().or_else(|| {
+          let scope = self.semantic.nodes().get_node(reference.node_id()).scope_id();
+          let scopes = self.semantic.scopes();
+          scopes.ancestors(scope).find_map(|scope| scopes.get_binding(scope, &node.name))
+        })?;
+        self.namespaces.get(&symbol).copied()
+      }
+      TSTypeName::QualifiedName(node) => {
+        let parent = self.resolve_namespace_name(&node.left)?;
+        match parent.values.borrow().get(node.right.name.as_str()) {
+          Some(Ty::Namespace(namespace)) => Some(*namespace),
+          _ => None,
+        }
+      }
+    }
+  }
+
   pub fn resolve_type_identifier_reference(&mut self, node: &'a IdentifierReference<'a>) -> Ty<'a> {
     let reference = self.semantic.symbols().get_reference(node.reference_id());
     if let Some(symbol_id) = reference.symbol_id() {
       self.type_scopes.search(symbol_id)
+    } else if node.name == "true" {
+      // The parser reads the `true` literal type as a type reference
+      Ty::BooleanLiteral(true)
     } else {
-      // TODO: Global type
-      Ty::Unknown
+      // TODO: Built-in global types
+      self.global_types.get(node.name.as_str()).copied().unwrap_or(Ty::Unknown)
     }
   }
 }
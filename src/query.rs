@@ -0,0 +1,327 @@
+use oxc::{
+  ast::ast::Expression,
+  semantic::{ReferenceId, SymbolId},
+  span::{GetSpan, Span},
+};
+
+use crate::{
+  analyzer::Analyzer,
+  ty::{
+    callable::ExtractedCallable, facts::Facts, property_key::PropertyKeyType,
+    unresolved::UnresolvedType, Ty,
+  },
+};
+
+/// An opaque handle to a type of the analyzed program.
+///
+/// Predicates which need to look into the type (e.g. its properties) take the analyzer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TypeHandle<'a>(pub(crate) Ty<'a>);
+
+/// A call signature after overload resolution and type argument inference.
+#[derive(Debug, Clone)]
+pub struct SignatureHandle<'a> {
+  /// (optional, type)
+  pub(crate) params: Vec<(bool, Ty<'a>)>,
+  pub(crate) rest_param: Option<Ty<'a>>,
+  pub(crate) return_type: Ty<'a>,
+}
+
+impl<'a> TypeHandle<'a> {
+  pub fn is_any(self) -> bool {
+    matches!(self.0, Ty::Any)
+  }
+
+  /// The type is not known because of an error, or an unsupported construct.
+  pub fn is_error(self) -> bool {
+    matches!(self.0, Ty::Error)
+  }
+
+  pub fn is_unknown(self) -> bool {
+    matches!(self.0, Ty::Unknown)
+  }
+
+  pub fn is_never(self) -> bool {
+    matches!(self.0, Ty::Never)
+  }
+
+  pub fn is_void(self) -> bool {
+    matches!(self.0, Ty::Void)
+  }
+
+  pub fn is_undefined(self) -> bool {
+    matches!(self.0, Ty::Undefined)
+  }
+
+  pub fn is_null(self) -> bool {
+    matches!(self.0, Ty::Null)
+  }
+
+  pub fn is_literal(self) -> bool {
+    matches!(
+      self.0,
+      Ty::StringLiteral(_)
+        | Ty::NumericLiteral(_)
+        | Ty::BigIntLiteral(_)
+        | Ty::BooleanLiteral(_)
+        | Ty::UniqueSymbol(_)
+        | Ty::EnumMember(_)
+    )
+  }
+
+  pub fn is_enum_member(self) -> bool {
+    matches!(self.0, Ty::EnumMember(_))
+  }
+
+  /// The enum declaration of an enum member, or of an enum type like `E`.
+  pub fn enum_symbol(self, analyzer: &Analyzer<'a>) -> Option<SymbolId> {
+    match self.0 {
+      Ty::EnumMember(member) => Some(member.enum_symbol),
+      ty => analyzer.get_enum_symbol_of_union(ty),
+    }
+  }
+
+  /// The value of an enum member, e.g. `0` for `E.A`.
+  pub fn enum_member_value(self) -> Option<TypeHandle<'a>> {
+    match self.0 {
+      Ty::EnumMember(member) => Some(TypeHandle(member.value)),
+      _ => None,
+    }
+  }
+
+  pub fn is_union(self) -> bool {
+    matches!(self.0, Ty::Union(_))
+  }
+
+  /// A type parameter which is not instantiated, e.g. `T` inside a generic function.
+  pub fn is_type_parameter(self) -> bool {
+    matches!(self.0, Ty::Unresolved(UnresolvedType::GenericParam(_)))
+  }
+
+  /// The members of a union, or the type itself.
+  pub fn union_members(self) -> Vec<TypeHandle<'a>> {
+    match self.0 {
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|ty| members.push(TypeHandle(ty)));
+        members
+      }
+      ty => vec![TypeHandle(ty)],
+    }
+  }
+
+  /// May be `null` or `undefined`.
+  pub fn is_nullable(self, analyzer: &mut Analyzer<'a>) -> bool {
+    match self.0 {
+      Ty::Any | Ty::Error => false,
+      ty => !analyzer.get_facts(ty).contains(Facts::NOT_NULLISH),
+    }
+  }
+
+  /// `Some(true)` if the type is always truthy, `Some(false)` if it is always falsy.
+  pub fn test_truthy(self, analyzer: &mut Analyzer<'a>) -> Option<bool> {
+    analyzer.test_truthy(self.0)
+  }
+
+  /// `Some(true)` if the type is always `null` or `undefined`, `Some(false)` if it never is.
+  pub fn test_nullish(self, analyzer: &mut Analyzer<'a>) -> Option<bool> {
+    analyzer.test_nullish(self.0)
+  }
+
+  pub fn is_string_like(self, analyzer: &mut Analyzer<'a>) -> bool {
+    analyzer.get_facts(self.0).contains(Facts::T_EQ_STRING)
+  }
+
+  pub fn is_number_like(self, analyzer: &mut Analyzer<'a>) -> bool {
+    analyzer.get_facts(self.0).contains(Facts::T_EQ_NUMBER)
+  }
+
+  pub fn is_bigint_like(self, analyzer: &mut Analyzer<'a>) -> bool {
+    analyzer.get_facts(self.0).contains(Facts::T_EQ_BIGINT)
+  }
+
+  pub fn is_boolean_like(self, analyzer: &mut Analyzer<'a>) -> bool {
+    analyzer.get_facts(self.0).contains(Facts::T_EQ_BOOLEAN)
+  }
+
+  pub fn is_symbol_like(self, analyzer: &mut Analyzer<'a>) -> bool {
+    analyzer.get_facts(self.0).contains(Facts::T_EQ_SYMBOL)
+  }
+
+  /// An object or a function, which is always truthy.
+  pub fn is_object_like(self, analyzer: &mut Analyzer<'a>) -> bool {
+    let facts = analyzer.get_facts(self.0);
+    facts.contains(Facts::TRUTHY) && facts.intersects(Facts::T_EQ_OBJECT | Facts::T_EQ_FUNCTION)
+  }
+
+  /// The type without `null` and `undefined`, like `NonNullable<T>`. `None` if it contains
+  /// unresolved types, e.g. type parameters.
+  pub fn non_nullable(self, analyzer: &mut Analyzer<'a>) -> Option<TypeHandle<'a>> {
+    if self.union_members().iter().any(|member| matches!(member.0, Ty::Unresolved(_))) {
+      return None;
+    }
+    Some(TypeHandle(analyzer.non_nullable(self.0)))
+  }
+
+  /// The members of the union which are not members of any of the `covered` types. For
+  /// `"a" | "b" | boolean` and `["a", true]`, these are `"b"` and `false`.
+  pub fn remaining_union_members(
+    self,
+    analyzer: &mut Analyzer<'a>,
+    covered: &[TypeHandle<'a>],
+  ) -> Vec<TypeHandle<'a>> {
+    let covered = covered.iter().map(|ty| ty.0).collect::<Vec<_>>();
+    analyzer.get_remaining_members(self.0, &covered).into_iter().map(TypeHandle).collect()
+  }
+
+  /// Has a call signature.
+  pub fn is_callable(self, analyzer: &mut Analyzer<'a>) -> bool {
+    !matches!(self.0, Ty::Any | Ty::Error) && analyzer.extract_callable_function(self.0).is_some()
+  }
+
+  /// The return type of the only call signature.
+  pub fn call_return_type(self, analyzer: &mut Analyzer<'a>) -> Option<TypeHandle<'a>> {
+    match analyzer.extract_callable_function(self.0)? {
+      ExtractedCallable::Single(callable) => {
+        let scope = analyzer.type_scopes.empty_scope;
+        Some(TypeHandle(analyzer.resolve_ctx_ty(scope, callable.return_type)))
+      }
+      _ => None,
+    }
+  }
+
+  /// An object type whose `toString` is the one inherited from `Object.prototype`, which results in
+  /// `[object Object]`. For intersections, all of the members.
+  pub fn uses_object_to_string(self, analyzer: &mut Analyzer<'a>) -> bool {
+    match self.0 {
+      Ty::Object | Ty::Record(_) | Ty::Interface(_) => {
+        let key = analyzer.allocator.alloc(analyzer.ast_builder.atom("toString"));
+        let key = PropertyKeyType::StringLiteral(key);
+        let to_string = analyzer.get_property(self.0, key);
+        let inherited = analyzer.get_property(analyzer.builtins.object_prototype, key);
+        matches!(inherited, Ty::Function(_)) && to_string == inherited
+      }
+      Ty::Intersection(intersection) => {
+        let mut uses_object_to_string = true;
+        intersection
+          .for_each(|ty| uses_object_to_string &= TypeHandle(ty).uses_object_to_string(analyzer));
+        uses_object_to_string
+      }
+      Ty::Instance(_) | Ty::Unresolved(_) => {
+        let lowest = analyzer.get_lowest_type(self.0);
+        lowest != self.0 && TypeHandle(lowest).uses_object_to_string(analyzer)
+      }
+      _ => false,
+    }
+  }
+
+  /// Has a callable `then` property. For unions, any of the members.
+  pub fn is_promise_like(self, analyzer: &mut Analyzer<'a>) -> bool {
+    match self.0 {
+      Ty::Error
+      | Ty::Any
+      | Ty::Unknown
+      | Ty::Never
+      | Ty::Void
+      | Ty::Null
+      | Ty::Undefined
+      | Ty::Generic(_)
+      | Ty::Intrinsic(_)
+      | Ty::Namespace(_) => false,
+      Ty::Union(union) => {
+        let mut promise_like = false;
+        union.for_each(|ty| promise_like |= TypeHandle(ty).is_promise_like(analyzer));
+        promise_like
+      }
+      Ty::Intersection(intersection) => {
+        let mut promise_like = false;
+        intersection.for_each(|ty| promise_like |= TypeHandle(ty).is_promise_like(analyzer));
+        promise_like
+      }
+      Ty::Instance(_) | Ty::Unresolved(_) => {
+        let lowest = analyzer.get_lowest_type(self.0);
+        lowest != self.0 && TypeHandle(lowest).is_promise_like(analyzer)
+      }
+      ty => {
+        let then = analyzer.allocator.alloc(analyzer.ast_builder.atom("then"));
+        let then = analyzer.get_property(ty, PropertyKeyType::StringLiteral(then));
+        TypeHandle(then).is_callable(analyzer)
+      }
+    }
+  }
+
+  /// Prints the type as TypeScript source.
+  pub fn print(self, analyzer: &mut Analyzer<'a>) -> String {
+    analyzer.print_type(self.0)
+  }
+}
+
+impl<'a> SignatureHandle<'a> {
+  /// The number of parameters, excluding the rest parameter.
+  pub fn param_count(&self) -> usize {
+    self.params.len()
+  }
+
+  pub fn param(&self, index: usize) -> Option<TypeHandle<'a>> {
+    self.params.get(index).map(|(_, ty)| TypeHandle(*ty))
+  }
+
+  pub fn is_param_optional(&self, index: usize) -> bool {
+    self.params.get(index).is_some_and(|(optional, _)| *optional)
+  }
+
+  pub fn rest_param(&self) -> Option<TypeHandle<'a>> {
+    self.rest_param.map(TypeHandle)
+  }
+
+  pub fn return_type(&self) -> TypeHandle<'a> {
+    TypeHandle(self.return_type)
+  }
+}
+
+impl<'a> Analyzer<'a> {
+  /// The type of the expression. If the expression is executed more than once (e.g. in a loop),
+  /// this is the union of all the results.
+  pub fn type_of_expression(&mut self, node: &Expression<'a>) -> Option<TypeHandle<'a>> {
+    self.type_of_span(node.span())
+  }
+
+  /// The type of the node at the span. See `type_of_expression`.
+  pub fn type_of_span(&mut self, span: Span) -> Option<TypeHandle<'a>> {
+    self.get_type_by_span(span).map(|ty| TypeHandle(self.resolve_uninit_variable(ty)))
+  }
+
+  /// The declared type of a variable, or its initial type if it is not annotated.
+  pub fn declared_type_of_symbol(&mut self, symbol: SymbolId) -> Option<TypeHandle<'a>> {
+    if let Some(ty) = self.variables.get(&symbol) {
+      return Some(TypeHandle(self.resolve_uninit_variable(*ty)));
+    }
+    let span = self.semantic.symbols().get_span(symbol);
+    self.type_of_span(span)
+  }
+
+  /// The type of a member of an enum declaration, e.g. `E.A`.
+  pub fn type_of_enum_member(&self, enum_symbol: SymbolId, name: &str) -> Option<TypeHandle<'a>> {
+    self.get_enum_member(enum_symbol, name).map(TypeHandle)
+  }
+
+  /// The type read by a reference, i.e. the type of its identifier, narrowed by the conditions
+  /// guarding it. See `type_of_expression`.
+  pub fn type_of_reference(&mut self, reference: ReferenceId) -> Option<TypeHandle<'a>> {
+    let node = self.semantic.symbols().get_reference(reference).node_id();
+    let span = self.semantic.nodes().get_node(node).kind().span();
+    self.type_of_span(span)
+  }
+
+  /// The type expected by the context of the node at the span, e.g. the type annotation of the
+  /// variable it initializes, or the parameter type of the argument.
+  pub fn contextual_type_of_span(&mut self, span: Span) -> Option<TypeHandle<'a>> {
+    let ty = self.contextual_types.get_mut(&span).and_then(|acc| acc.to_ty())?;
+    Some(TypeHandle(self.resolve_uninit_variable(ty)))
+  }
+
+  /// The signature chosen for a call or `new` expression.
+  pub fn resolved_signature(&self, node: &impl GetSpan) -> Option<&SignatureHandle<'a>> {
+    self.call_signatures.get(&node.span())
+  }
+}
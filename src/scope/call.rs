@@ -15,6 +15,8 @@ pub struct CallScope<'a> {
 
   pub this: Ty<'a>,
   pub ret: CallScopeReturnType<'a>,
+  /// The return type expected by the context, used when the return type is inferred
+  pub contextual_ret: Option<Ty<'a>>,
 
   #[cfg(feature = "flame")]
   pub scope_guard: flame::SpanGuard,
@@ -27,6 +29,7 @@ impl<'a> CallScope<'a> {
     is_generator: bool,
     this: Ty<'a>,
     annotated_ret: Option<Ty<'a>>,
+    contextual_ret: Option<Ty<'a>>,
   ) -> Self {
     CallScope {
       body_scope,
@@ -40,6 +43,7 @@ impl<'a> CallScope<'a> {
       } else {
         CallScopeReturnType::Inferred(Default::default())
       },
+      contextual_ret,
 
       #[cfg(feature = "flame")]
       scope_guard: flame::start_guard(callee.debug_name.to_string()),
@@ -104,11 +104,26 @@ impl<'a> Analyzer<'a> {
   }
 
   pub fn exit_to(&mut self, target_depth: usize) {
-    self.exit_to_impl(target_depth, self.runtime_scopes.stack.len(), true);
+    self.exit_to_impl(self.runtime_scopes.stack.len(), target_depth, true);
   }
 
   pub fn exit_to_not_must(&mut self, target_depth: usize) {
-    self.exit_to_impl(target_depth, self.runtime_scopes.stack.len(), false);
+    self.exit_to_impl(self.runtime_scopes.stack.len(), target_depth, false);
+  }
+
+  /// Exits to the closest function, for `return` and `throw`.
+  pub fn exit_to_function(&mut self) {
+    let target_depth = self
+      .runtime_scopes
+      .iter_stack()
+      .rposition(|scope| matches!(scope.kind, CfScopeKind::Function | CfScopeKind::Module))
+      .unwrap();
+    self.exit_to(target_depth);
+  }
+
+  /// Whether the scope is always exited, e.g. the consequent of `if (x) return;`.
+  pub fn is_scope_exited(&self, scope: RuntimeScopeId) -> bool {
+    self.runtime_scopes.get(scope).exited == Some(true)
   }
 
   /// If the label is used, `true` is returned.
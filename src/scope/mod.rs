@@ -1,5 +1,6 @@
 pub mod call;
 pub mod control;
+pub mod narrow;
 pub mod runtime;
 pub mod r#type;
 pub mod variable;
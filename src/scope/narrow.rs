@@ -0,0 +1,116 @@
+use oxc::ast::ast::{
+  BinaryOperator, Expression, IdentifierReference, LogicalOperator, UnaryOperator,
+};
+
+use crate::{analyzer::Analyzer, ty::facts::Facts};
+
+impl<'a> Analyzer<'a> {
+  /// Narrows the variables in the condition in the current scope, assuming the condition is
+  /// `assumed`, e.g. `x` is not `undefined` in the consequent of `if (x !== undefined)`.
+  pub fn narrow_by_condition(&mut self, node: &'a Expression<'a>, assumed: bool) {
+    match node.without_parentheses() {
+      Expression::Identifier(node) => {
+        let excluded = if assumed { Facts::FALSY } else { Facts::TRUTHY };
+        self.narrow_reference_by_facts(node, excluded);
+      }
+      Expression::UnaryExpression(node) if node.operator == UnaryOperator::LogicalNot => {
+        self.narrow_by_condition(&node.argument, !assumed);
+      }
+      Expression::LogicalExpression(node) => match node.operator {
+        LogicalOperator::And if assumed => {
+          self.narrow_by_condition(&node.left, true);
+          self.narrow_by_condition(&node.right, true);
+        }
+        LogicalOperator::Or if !assumed => {
+          self.narrow_by_condition(&node.left, false);
+          self.narrow_by_condition(&node.right, false);
+        }
+        _ => {}
+      },
+      Expression::BinaryExpression(node) => {
+        let (strict, negated) = match node.operator {
+          BinaryOperator::StrictEquality => (true, false),
+          BinaryOperator::StrictInequality => (true, true),
+          BinaryOperator::Equality => (false, false),
+          BinaryOperator::Inequality => (false, true),
+          _ => return,
+        };
+        let equal = assumed != negated;
+        self.narrow_by_equality(&node.left, &node.right, strict, equal);
+        self.narrow_by_equality(&node.right, &node.left, strict, equal);
+      }
+      _ => {}
+    }
+  }
+
+  /// Narrows `target` in `target === other`, where `other` is `null`, `undefined`, or a string
+  /// compared with `typeof target`.
+  fn narrow_by_equality(
+    &mut self,
+    target: &'a Expression<'a>,
+    other: &'a Expression<'a>,
+    strict: bool,
+    equal: bool,
+  ) {
+    let other = other.without_parentheses();
+    match target.without_parentheses() {
+      Expression::Identifier(target) => {
+        let excluded = if !strict {
+          if !other.is_null() && !other.is_undefined() && !other.is_void_0() {
+            return;
+          }
+          // `x == null` is also true for `undefined`
+          if equal {
+            Facts::NOT_NULLISH
+          } else {
+            Facts::IS_NULLISH
+          }
+        } else if other.is_null() {
+          if equal {
+            Facts::NE_NULL
+          } else {
+            Facts::EQ_NULL
+          }
+        } else if other.is_undefined() || other.is_void_0() {
+          if equal {
+            Facts::NE_UNDEFINED
+          } else {
+            Facts::EQ_UNDEFINED
+          }
+        } else {
+          return;
+        };
+        self.narrow_reference_by_facts(target, excluded);
+      }
+      Expression::UnaryExpression(target) if target.operator == UnaryOperator::Typeof => {
+        let (Expression::Identifier(target), Expression::StringLiteral(name)) =
+          (target.argument.without_parentheses(), other)
+        else {
+          return;
+        };
+        let Some(symbol) = self.semantic.symbols().get_reference(target.reference_id()).symbol_id()
+        else {
+          return;
+        };
+        let ty = self.read_variable(symbol);
+        let narrowed = self.narrow_by_typeof(ty, &name.value, equal);
+        if narrowed != ty {
+          self.narrow_variable(symbol, narrowed);
+        }
+      }
+      _ => {}
+    }
+  }
+
+  fn narrow_reference_by_facts(&mut self, node: &'a IdentifierReference<'a>, excluded: Facts) {
+    let Some(symbol) = self.semantic.symbols().get_reference(node.reference_id()).symbol_id()
+    else {
+      return;
+    };
+    let ty = self.read_variable(symbol);
+    let narrowed = self.narrow_by_facts(ty, excluded);
+    if narrowed != ty {
+      self.narrow_variable(symbol, narrowed);
+    }
+  }
+}
@@ -35,7 +35,7 @@ impl<'a> RuntimeScopeTree<'a> {
   }
 
   pub fn get(&self, id: RuntimeScopeId) -> &RuntimeScope<'a> {
-    &self.nodes.get(id).unwrap()
+    self.nodes.get(id).unwrap()
   }
 
   pub fn get_mut(&mut self, id: RuntimeScopeId) -> &mut RuntimeScope<'a> {
@@ -19,7 +19,6 @@ struct TypeScope<'a> {
 #[derive(Debug)]
 pub struct TypeScopeTree<'a> {
   nodes: IndexVec<TypeScopeId, TypeScope<'a>>,
-  root: TypeScopeId,
   top: TypeScopeId,
   pub constraints_scope: TypeScopeId,
   pub empty_scope: TypeScopeId,
@@ -31,7 +30,7 @@ impl<'a> TypeScopeTree<'a> {
     let root = nodes.push(TypeScope::default());
     let constraints_scope = nodes.push(TypeScope::default());
     let empty_scope = nodes.push(TypeScope::default());
-    TypeScopeTree { nodes, root, top: root, constraints_scope, empty_scope }
+    TypeScopeTree { nodes, top: root, constraints_scope, empty_scope }
   }
 
   pub fn create_scope(&mut self) -> TypeScopeId {
@@ -114,3 +113,9 @@ impl<'a> TypeScopeTree<'a> {
     self.nodes[scope].parent = Some(parent);
   }
 }
+
+impl Default for TypeScopeTree<'_> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
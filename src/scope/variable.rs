@@ -3,7 +3,7 @@ use rustc_hash::FxHashMap;
 
 use crate::{
   analyzer::Analyzer,
-  ty::{unresolved::UnresolvedType, Ty},
+  ty::{assignable::AssignmentKind, unresolved::UnresolvedType, Ty},
 };
 
 use super::runtime::{RuntimeScopeId, RuntimeScopeTree};
@@ -53,42 +53,57 @@ impl<'a> Analyzer<'a> {
   }
 
   pub fn read_variable(&self, symbol: SymbolId) -> Ty<'a> {
-    if let Some(resolved) = self.variables.get(&symbol) {
-      *resolved
-    } else {
-      for scope in self.runtime_scopes.iter_stack().rev() {
-        if let Some(variable) = scope.variables.get(&symbol) {
-          return variable.value;
-        }
+    let declared = self.variables.get(&symbol).copied();
+    // Narrowings of typed variables don't apply to closures, unless the variable is constant
+    let in_closures = declared.is_none() || self.is_symbol_const(symbol);
+    for scope in self.runtime_scopes.iter_stack().rev() {
+      if let Some(variable) = scope.variables.get(&symbol) {
+        return variable.value;
       }
-      if self.is_symbol_var(symbol) {
-        // Var declaration like:
-        // ```ts
-        // read(a)
-        // while (a) { var a; }
-        // ```
-        Ty::Any
-      } else {
-        unreachable!("Variable not found: {:?}", self.semantic.symbols().get_name(symbol));
+      if scope.kind.is_function() && !in_closures {
+        break;
       }
     }
+    if let Some(declared) = declared {
+      declared
+    } else if self.is_symbol_var(symbol) {
+      // Var declaration like:
+      // ```ts
+      // read(a)
+      // while (a) { var a; }
+      // ```
+      Ty::Any
+    } else {
+      unreachable!("Variable not found: {:?}", self.semantic.symbols().get_name(symbol));
+    }
   }
 
   pub fn write_variable(&mut self, symbol: SymbolId, value: Ty<'a>) {
-    if let Some(_resolved) = self.variables.get_mut(&symbol) {
-      // Do nothing
-      // CHECKER: Should check type compatibility
+    if let Some(resolved) = self.variables.get(&symbol).copied() {
+      // The declared type is not narrowed by assignments
+      let span = self.current_span();
+      self.check_assignable(span, AssignmentKind::Value, value, resolved);
+      // Narrowings end at assignments
+      if self.runtime_scopes.iter_stack().any(|scope| scope.variables.contains_key(&symbol)) {
+        self.narrow_variable(symbol, resolved);
+      }
     } else {
-      self
-        .runtime_scopes
-        .get_current_mut()
-        .variables
-        .entry(symbol)
-        .and_modify(|variable| variable.value = value)
-        .or_insert(Variable::shadow(value));
+      self.narrow_variable(symbol, value);
     }
   }
 
+  /// Sets the type of the variable in the current scope, without checking it against the declared
+  /// type. The narrowed type is merged into the outer scope when the scope ends.
+  pub fn narrow_variable(&mut self, symbol: SymbolId, value: Ty<'a>) {
+    self
+      .runtime_scopes
+      .get_current_mut()
+      .variables
+      .entry(symbol)
+      .and_modify(|variable| variable.value = value)
+      .or_insert(Variable::shadow(value));
+  }
+
   pub fn apply_shadows<const N: usize>(
     &mut self,
     scopes: [RuntimeScopeId; N],
@@ -110,10 +125,14 @@ impl<'a> Analyzer<'a> {
         values.push(self.read_variable(symbol));
       }
       let value = self.into_union(values).unwrap();
-      self.write_variable(symbol, value);
+      self.narrow_variable(symbol, value);
     }
   }
 
+  fn is_symbol_const(&self, symbol: SymbolId) -> bool {
+    self.semantic.symbols().get_flags(symbol).is_const_variable()
+  }
+
   fn is_symbol_var(&self, symbol: SymbolId) -> bool {
     self.semantic.symbols().get_flags(symbol).is_function_scoped_declaration()
   }
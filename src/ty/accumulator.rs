@@ -17,6 +17,9 @@ impl<'a> TypeAccumulator<'a> {
   pub fn add(&mut self, ty: Ty<'a>, allocator: &'a Allocator) {
     match self {
       TypeAccumulator::None => *self = TypeAccumulator::Single(ty),
+      // `never` is absorbed by the other types, e.g. values of unreachable returns
+      TypeAccumulator::Single(_) | TypeAccumulator::Union(_) if ty == Ty::Never => {}
+      TypeAccumulator::Single(t) if *t == Ty::Never => *t = ty,
       TypeAccumulator::Single(t) => {
         if *t != ty {
           let union = allocator.alloc(UnionType::default());
@@ -31,31 +34,27 @@ impl<'a> TypeAccumulator<'a> {
   }
 
   pub fn is_empty(&self) -> bool {
-    match self {
-      TypeAccumulator::None => true,
-      _ => false,
-    }
+    matches!(self, TypeAccumulator::None)
   }
 
   pub fn frozen(&mut self) {
-    match self {
-      TypeAccumulator::Union(_) => match mem::take(self) {
+    if let TypeAccumulator::Union(_) = self {
+      match mem::take(self) {
         TypeAccumulator::Union(union) => {
           *self = TypeAccumulator::FrozenUnion(union);
         }
         _ => unreachable!(),
-      },
-      _ => {}
+      }
     }
   }
 
   pub fn to_ty(&mut self) -> Option<Ty<'a>> {
     self.frozen();
-    match &*self {
+    match self {
       TypeAccumulator::None => None,
       TypeAccumulator::Single(ty) => Some(*ty),
       TypeAccumulator::Union(_) => unreachable!(),
-      TypeAccumulator::FrozenUnion(union) => Some(Ty::Union(*union)),
+      TypeAccumulator::FrozenUnion(union) => Some(Ty::Union(union)),
     }
   }
 
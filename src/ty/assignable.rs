@@ -0,0 +1,81 @@
+use oxc::{
+  ast::ast::Expression,
+  span::{GetSpan, Span},
+};
+
+use super::{r#match::MatchResult, Ty};
+use crate::{
+  analyzer::Analyzer,
+  diagnostic::{Diagnostic, DiagnosticCode},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssignmentKind {
+  /// Variable declarators, assignments, returns and property initializers
+  Value,
+  /// Call arguments
+  Argument,
+}
+
+impl<'a> Analyzer<'a> {
+  /// Checks the value of an expression. Contextually typed object literals are skipped, because
+  /// their properties are checked one by one.
+  pub fn check_expression_assignable(
+    &mut self,
+    node: &Expression<'a>,
+    kind: AssignmentKind,
+    actual: Ty<'a>,
+    expected: Ty<'a>,
+  ) {
+    if !matches!(node.without_parentheses(), Expression::ObjectExpression(_)) {
+      self.check_assignable(node.span(), kind, actual, expected);
+    }
+  }
+
+  /// Reports if `actual` is not assignable to `expected`. Only enabled with
+  /// `Config::check_assignability`.
+  pub fn check_assignable(
+    &mut self,
+    span: Span,
+    kind: AssignmentKind,
+    actual: Ty<'a>,
+    expected: Ty<'a>,
+  ) {
+    if !self.config.check_assignability {
+      return;
+    }
+    if matches!(actual, Ty::Any | Ty::Error | Ty::Unresolved(_))
+      || matches!(expected, Ty::Unresolved(_))
+    {
+      return;
+    }
+
+    let mut unmatched = false;
+    for result in self.match_types_with_dispatch(actual, expected) {
+      match result {
+        // Unsupported by the matcher, so we can't tell
+        MatchResult::Error => return,
+        MatchResult::Unmatched => unmatched = true,
+        MatchResult::Matched | MatchResult::Inferred(_) => {}
+      }
+    }
+
+    if unmatched {
+      let actual = self.print_type(actual);
+      let expected = self.print_type(expected);
+      let (code, message) = match kind {
+        AssignmentKind::Value => (
+          DiagnosticCode::NotAssignable,
+          format!("Type '{actual}' is not assignable to type '{expected}'"),
+        ),
+        AssignmentKind::Argument => (
+          DiagnosticCode::ArgumentNotAssignable,
+          format!(
+            "Argument of type '{actual}' is not assignable to parameter of type '{expected}'"
+          ),
+        ),
+      };
+      self.report(Diagnostic::error(code, message, span));
+    }
+  }
+}
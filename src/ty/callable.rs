@@ -1,22 +1,27 @@
 use oxc::{
   allocator,
-  ast::{
-    ast::{Argument, FormalParameterKind, TSType, TSTypeParameterInstantiation},
-    NONE,
-  },
+  ast::ast::{Argument, Expression, FormalParameterKind, TSType, TSTypeParameterInstantiation},
   semantic::SymbolId,
   span::SPAN,
 };
 use rustc_hash::FxHashMap;
 
-use super::{ctx::CtxTy, generic::GenericParam, Ty};
+use super::{
+  ctx::CtxTy,
+  generic::GenericParam,
+  inference::{is_naked_in_union, InferenceCandidates, InferencePriority},
+  intersection::{IntersectionBaseKind, IntersectionType},
+  Ty,
+};
 use crate::{
   analyzer::Analyzer,
+  diagnostic::DiagnosticCode,
+  query::SignatureHandle,
   scope::r#type::TypeScopeId,
-  ty::{r#match::MatchResult, unresolved::UnresolvedType},
+  ty::{assignable::AssignmentKind, r#match::MatchResult, unresolved::UnresolvedType},
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CallableType<'a, const CTOR: bool> {
   pub is_method: bool,
   pub scope: TypeScopeId,
@@ -27,16 +32,43 @@ pub struct CallableType<'a, const CTOR: bool> {
   pub params: Vec<(bool, CtxTy<'a>)>,
   pub rest_param: Option<CtxTy<'a>>,
   pub return_type: CtxTy<'a>,
+  /// See `Analyzer::get_parameter_names`. Empty for built-in signatures.
+  pub param_names: Vec<Option<&'a str>>,
 }
 
 pub type FunctionType<'a> = CallableType<'a, false>;
 pub type ConstructorType<'a> = CallableType<'a, true>;
 
+/// (optional, type)
+type ParamTypes<'a> = Vec<(bool, Ty<'a>)>;
+
+/// The arguments of a call as `(spread, type)`, where the context sensitive ones are `None` until
+/// they are evaluated with the parameter types.
+struct CallArguments<'a> {
+  args: Vec<Option<(bool, Ty<'a>)>>,
+  /// The index of the parameter of each argument, if known
+  param_indexes: Vec<Option<usize>>,
+}
+
 impl<'a> Analyzer<'a> {
+  /// Combines the signatures into an overloaded callable. The order of signatures is preserved,
+  /// because overload resolution picks the first matching one.
+  pub fn into_overloaded(&mut self, signatures: Vec<Ty<'a>>) -> Ty<'a> {
+    if signatures.len() == 1 {
+      signatures[0]
+    } else {
+      self.intern_intersection(IntersectionType {
+        kind: IntersectionBaseKind::NoBase,
+        object_like: signatures,
+        unresolved: Vec::new(),
+      })
+    }
+  }
+
   pub fn instantiate_callable_type_parameters<const CTOR: bool>(
     &mut self,
     callable: &CallableType<'a, CTOR>,
-    type_args: &Vec<Ty<'a>>,
+    type_args: &[Ty<'a>],
   ) -> Option<&'a CallableType<'a, CTOR>> {
     if callable.type_params.len() != type_args.len() {
       return None;
@@ -57,6 +89,7 @@ impl<'a> Analyzer<'a> {
       params,
       rest_param,
       return_type,
+      param_names: callable.param_names.clone(),
     }))
   }
 
@@ -64,9 +97,26 @@ impl<'a> Analyzer<'a> {
     &mut self,
     callable: &CallableType<'a, CTOR>,
   ) -> TSType<'a> {
+    let type_params = (!callable.type_params.is_empty()).then(|| {
+      let mut params = self.ast_builder.vec();
+      for param in &callable.type_params {
+        let constraint = param.constraint.map(|ty| self.serialize_ctx_ty(ty));
+        let default = param.default.map(|ty| self.serialize_ctx_ty(ty));
+        params.push(self.ast_builder.ts_type_parameter(
+          SPAN,
+          self.serialize_binding_identifier(param.symbol_id),
+          constraint,
+          default,
+          param.r#in,
+          param.out,
+          param.r#const,
+        ));
+      }
+      self.ast_builder.alloc_ts_type_parameter_declaration(SPAN, params)
+    });
     self.ast_builder.ts_type_function_type(
       SPAN,
-      /* TODO: */ NONE,
+      type_params,
       callable.this_param.map(|ty| {
         self.ast_builder.ts_this_parameter(
           SPAN,
@@ -80,14 +130,15 @@ impl<'a> Analyzer<'a> {
         {
           let mut items = self.ast_builder.vec();
           for (i, (optional, param)) in callable.params.iter().enumerate() {
+            let name = match callable.param_names.get(i) {
+              Some(Some(name)) => *name,
+              _ => self.allocator.alloc(format!("a{i}")).as_str(),
+            };
             items.push(self.ast_builder.formal_parameter(
               SPAN,
               self.ast_builder.vec(),
               self.ast_builder.binding_pattern(
-                self.ast_builder.binding_pattern_kind_binding_identifier(
-                  SPAN,
-                  &*self.allocator.alloc(format!("a{i}")),
-                ),
+                self.ast_builder.binding_pattern_kind_binding_identifier(SPAN, name),
                 Some(self.ast_builder.ts_type_annotation(SPAN, self.serialize_ctx_ty(*param))),
                 *optional,
               ),
@@ -96,14 +147,17 @@ impl<'a> Analyzer<'a> {
               false,
             ))
           }
-          if let Some(rest) = callable.rest_param {}
           items
         },
         callable.rest_param.map(|ty| {
+          let name = match callable.param_names.get(callable.params.len()) {
+            Some(Some(name)) => *name,
+            _ => "rest",
+          };
           self.ast_builder.binding_rest_element(
             SPAN,
             self.ast_builder.binding_pattern(
-              self.ast_builder.binding_pattern_kind_binding_identifier(SPAN, "rest"),
+              self.ast_builder.binding_pattern_kind_binding_identifier(SPAN, name),
               Some(self.ast_builder.ts_type_annotation(SPAN, self.serialize_ctx_ty(ty))),
               false,
             ),
@@ -123,6 +177,16 @@ pub enum ExtractedCallable<'a, const CTOR: bool> {
   Union(Vec<ExtractedCallable<'a, CTOR>>),
 }
 
+impl<'a, const CTOR: bool> ExtractedCallable<'a, CTOR> {
+  fn from_overloads(overloads: Vec<Self>) -> Option<Self> {
+    match overloads.len() {
+      0 => None,
+      1 => overloads.into_iter().next(),
+      _ => Some(ExtractedCallable::Overloaded(overloads)),
+    }
+  }
+}
+
 macro_rules! impl_extract_callable {
   ($name: ident, $ctor: expr, $member: ident) => {
     impl<'a> Analyzer<'a> {
@@ -148,16 +212,27 @@ macro_rules! impl_extract_callable {
                 res.push(extracted);
               }
             });
-            match res.len() {
-              0 => None,
-              1 => Some(res.into_iter().next().unwrap()),
-              _ => Some(ExtractedCallable::Overloaded(res)),
+            ExtractedCallable::from_overloads(res)
+          }
+          Ty::Interface(i) => {
+            let callables = self.get_interface_members(i).callables.clone();
+            let mut res = vec![];
+            for ty in callables {
+              if let Some(extracted) = self.$name(ty) {
+                res.push(extracted);
+              }
             }
+            ExtractedCallable::from_overloads(res)
           }
           Ty::Instance(i) => {
             let unwrapped = self.unwrap_generic_instance(i);
             self.$name(unwrapped)
           }
+          Ty::Namespace(n) => n.merged.get().and_then(|merged| self.$name(merged)),
+          Ty::Unresolved(_) => {
+            let lowest = self.get_lowest_type(ty);
+            self.$name(lowest)
+          }
           _ => None,
         }
       }
@@ -199,9 +274,10 @@ impl<'a> Analyzer<'a> {
   fn get_transposed_callable_parameter_types<const CTOR: bool>(
     &mut self,
     scope: TypeScopeId,
-    callables: &Vec<ExtractedCallable<'a, CTOR>>,
+    callables: &[ExtractedCallable<'a, CTOR>],
   ) -> Vec<Vec<Ty<'a>>> {
-    let callables = callables.iter().map(|c| self.get_callable_parameter_types(scope, c));
+    let callables =
+      callables.iter().map(|c| self.get_callable_parameter_types(scope, c)).collect::<Vec<_>>();
     let mut res = Vec::new();
     for callable in callables {
       for _ in res.len()..callable.len() {
@@ -209,7 +285,7 @@ impl<'a> Analyzer<'a> {
       }
       for (i, (spread, item)) in callable.into_iter().enumerate() {
         if spread {
-          todo!()
+          self.unsupported("rest parameters of overloads or unions");
         } else {
           res[i].push(item);
         }
@@ -218,6 +294,37 @@ impl<'a> Analyzer<'a> {
     res
   }
 
+  /// Returns the parameter types and the return type of the signature that contextually types a
+  /// function expression. The contextual type must have exactly one non-generic call signature.
+  pub fn get_contextual_signature(
+    &mut self,
+    sat: Option<Ty<'a>>,
+  ) -> (Option<Vec<(bool, Ty<'a>)>>, Option<Ty<'a>>) {
+    let Some(callable) = sat.and_then(|sat| self.extract_contextual_signature(sat)) else {
+      return (None, None);
+    };
+    let scope = self.type_scopes.empty_scope;
+    let params = self.get_callable_parameter_types(scope, &ExtractedCallable::Single(callable));
+    let ret = self.resolve_ctx_ty(scope, callable.return_type);
+    (Some(params), Some(ret))
+  }
+
+  fn extract_contextual_signature(&mut self, sat: Ty<'a>) -> Option<&'a FunctionType<'a>> {
+    let callable = match sat {
+      Ty::Union(u) => {
+        // Members which are not callable are ignored
+        let mut signatures = vec![];
+        u.for_each(|ty| signatures.extend(self.extract_contextual_signature(ty)));
+        return (signatures.len() == 1).then(|| signatures[0]);
+      }
+      _ => match self.extract_callable_function(sat)? {
+        ExtractedCallable::Single(callable) => callable,
+        _ => return None,
+      },
+    };
+    callable.type_params.is_empty().then_some(callable)
+  }
+
   /// Returns `None` if the signature does not match. Otherwise, returns the return type.
   pub fn exec_call<const CTOR: bool>(
     &mut self,
@@ -231,20 +338,14 @@ impl<'a> Analyzer<'a> {
       match callable {
         ExtractedCallable::Any => {
           self.exec_arguments(arguments, None);
-          None
+          // Calling `any` results in `any`, which is not an error
+          Some(Ty::Any)
         }
         ExtractedCallable::Single(callable) => {
           self.exec_call_on_single(callable, type_args, this_arg, arguments, ret_sat)
         }
         ExtractedCallable::Overloaded(callables) => {
-          for callable in callables {
-            if let Some(ret) =
-              self.exec_call(Some(callable), type_args, this_arg, arguments, ret_sat)
-            {
-              return Some(ret);
-            }
-          }
-          None
+          self.exec_overloaded_call(callables, type_args, this_arg, arguments, ret_sat)
         }
         ExtractedCallable::Union(callables) => {
           let mut ret_types = Vec::new();
@@ -275,36 +376,193 @@ impl<'a> Analyzer<'a> {
         self.type_scopes.empty_scope,
         &ExtractedCallable::Single(callable),
       );
-      self.exec_arguments(arguments, Some(params));
-      Some(self.resolve_ctx_ty(self.type_scopes.empty_scope, callable.return_type))
+      self.exec_arguments(arguments, Some(params.clone()));
+      Some(self.record_call_signature(self.type_scopes.empty_scope, callable, params))
     } else if let Some(type_args) = type_args {
       // Generic, and type arguments are provided
       let type_args = self.resolve_type_parameter_instantiation(type_args);
       let scope = self.instantiate_generic_params(&callable.type_params, &type_args);
       let params = self.get_callable_parameter_types(scope, &ExtractedCallable::Single(callable));
-      self.exec_arguments(arguments, Some(params));
-      Some(self.resolve_ctx_ty(scope, callable.return_type))
+      self.exec_arguments(arguments, Some(params.clone()));
+      Some(self.record_call_signature(scope, callable, params))
     } else {
       // Generic, and need inference
       self.exec_call_with_inference(callable, this_arg, arguments, ret_sat)
     }
   }
 
-  fn exec_call_with_inference<const CTOR: bool>(
+  /// Overload resolution: the first signature that the arguments match is chosen. Context sensitive
+  /// arguments are only matched as functions, and are contextually typed by the chosen signature
+  /// afterwards. If none of them matches, the last signature is used, which is also what TypeScript
+  /// reports errors against.
+  fn exec_overloaded_call<const CTOR: bool>(
     &mut self,
-    callable: &'a CallableType<'a, CTOR>,
+    callables: Vec<ExtractedCallable<'a, CTOR>>,
+    type_args: &'a Option<allocator::Box<'a, TSTypeParameterInstantiation<'a>>>,
     this_arg: Ty<'a>,
     arguments: &'a allocator::Vec<'a, Argument<'a>>,
     ret_sat: Option<Ty<'a>>,
   ) -> Option<Ty<'a>> {
-    // # Inference
-    // See https://gitnation.com/contents/lets-make-a-generic-inference-algorithm
-    //
-    // - Non-context-aware arguments first (TODO)
-    // - Type inferred from input type is the upper-bound (specificity < 0)
-    // - Type inferred from output type is the lower-bound (specificity > 0)
-    // - Choose the widest type *FROM* output type inferred from output type
+    let type_args =
+      type_args.as_ref().map(|type_args| self.resolve_type_parameter_instantiation(type_args));
+    let mut args = self.exec_arguments_with_deferral(arguments, None);
+
+    let mut selected = None;
+    for callable in &callables {
+      if self.match_overload(callable, type_args.as_ref(), this_arg, &args.args) {
+        selected = Some(callable);
+        break;
+      }
+    }
+    if selected.is_none() && self.config.check_assignability {
+      self.add_diagnostic(DiagnosticCode::NoMatchingOverload, "No overload matches this call.");
+    }
+
+    match selected.or(callables.last()) {
+      Some(ExtractedCallable::Single(callable)) => {
+        let scope = self.instantiate_overload(
+          callable,
+          type_args.as_ref(),
+          this_arg,
+          arguments,
+          &mut args,
+          ret_sat,
+        );
+        let params = self.get_callable_parameter_types(scope, &ExtractedCallable::Single(callable));
+        self.exec_deferred_arguments(arguments, &mut args, &params);
+        // Otherwise, only the missing overload is reported
+        if selected.is_some() {
+          self.check_arguments(arguments, &args, &params);
+        }
+        Some(self.record_call_signature(scope, callable, params))
+      }
+      // Nested compound callables are not resolved
+      _ => {
+        self.exec_deferred_arguments(arguments, &mut args, &[]);
+        None
+      }
+    }
+  }
+
+  fn match_overload<const CTOR: bool>(
+    &mut self,
+    callable: &ExtractedCallable<'a, CTOR>,
+    type_args: Option<&Vec<Ty<'a>>>,
+    this_arg: Ty<'a>,
+    args: &[Option<(bool, Ty<'a>)>],
+  ) -> bool {
+    let callable = match callable {
+      ExtractedCallable::Any => return true,
+      ExtractedCallable::Single(callable) => *callable,
+      _ => return false,
+    };
+
+    let has_spread = args.iter().flatten().any(|(spread, _)| *spread);
+    if !has_spread {
+      let required = callable.params.iter().take_while(|(optional, _)| !*optional).count();
+      if args.len() < required
+        || (args.len() > callable.params.len() && callable.rest_param.is_none())
+      {
+        return false;
+      }
+    }
+
+    let scope = if callable.type_params.is_empty() {
+      self.type_scopes.empty_scope
+    } else if let Some(type_args) = type_args {
+      if type_args.len() > callable.type_params.len() {
+        return false;
+      }
+      self.instantiate_generic_params(&callable.type_params, type_args)
+    } else {
+      self.create_inference_scope(callable)
+    };
+    let params = self.get_callable_parameter_types(scope, &ExtractedCallable::Single(callable));
+
+    if let Some(this_param) = callable.this_param {
+      let this_param = self.resolve_ctx_ty(scope, this_param);
+      if !matches!(this_arg, Ty::Any | Ty::Error)
+        && !self.match_covariant_types(1, this_arg, this_param).matched()
+      {
+        return false;
+      }
+    }
+
+    for (arg, (_, param)) in args.iter().zip(params.iter()) {
+      // Context sensitive arguments are not evaluated yet, but they are functions
+      let (spread, arg) = arg.unwrap_or_else(|| (false, self.get_any_function_type()));
+      if spread {
+        // TODO: Match spread arguments
+        break;
+      }
+      if matches!(arg, Ty::Any | Ty::Error) {
+        continue;
+      }
+      if !self.match_types_with_dispatch(arg, *param).into_iter().all(MatchResult::matched) {
+        return false;
+      }
+    }
+    true
+  }
+
+  /// `() => any`, which is assignable to every function type.
+  fn get_any_function_type(&mut self) -> Ty<'a> {
+    self.intern_function(CallableType {
+      is_method: false,
+      scope: self.type_scopes.empty_scope,
+      type_params: vec![],
+      this_param: None,
+      params: vec![],
+      rest_param: None,
+      return_type: CtxTy::Static(Ty::Any),
+      param_names: vec![],
+    })
+  }
+
+  /// The scope where the type parameters of the chosen overload are instantiated. Without type
+  /// arguments, they are inferred, and the deferred arguments are evaluated in between.
+  fn instantiate_overload<const CTOR: bool>(
+    &mut self,
+    callable: &'a CallableType<'a, CTOR>,
+    type_args: Option<&Vec<Ty<'a>>>,
+    this_arg: Ty<'a>,
+    arguments: &'a allocator::Vec<'a, Argument<'a>>,
+    args: &mut CallArguments<'a>,
+    ret_sat: Option<Ty<'a>>,
+  ) -> TypeScopeId {
+    if callable.type_params.is_empty() {
+      self.type_scopes.empty_scope
+    } else if let Some(type_args) = type_args {
+      self.instantiate_generic_params(&callable.type_params, type_args)
+    } else {
+      let scope = self.create_inference_scope(callable);
+      self.infer_call_type_arguments(callable, scope, this_arg, arguments, args, ret_sat);
+      scope
+    }
+  }
+
+  /// Records the signature chosen for the call at the current span, with the parameter types that
+  /// the arguments are checked against. Returns its return type, instantiated in `scope`.
+  fn record_call_signature<const CTOR: bool>(
+    &mut self,
+    scope: TypeScopeId,
+    callable: &CallableType<'a, CTOR>,
+    params: Vec<(bool, Ty<'a>)>,
+  ) -> Ty<'a> {
+    let params =
+      callable.params.iter().zip(params).map(|((optional, _), (_, ty))| (*optional, ty)).collect();
+    let rest_param = callable.rest_param.map(|ty| self.resolve_ctx_ty(scope, ty));
+    let return_type = self.resolve_ctx_ty(scope, callable.return_type);
+    let span = self.current_span();
+    self.call_signatures.insert(span, SignatureHandle { params, rest_param, return_type });
+    return_type
+  }
 
+  /// Creates a scope where every type parameter of the callable is an inference target.
+  fn create_inference_scope<const CTOR: bool>(
+    &mut self,
+    callable: &CallableType<'a, CTOR>,
+  ) -> TypeScopeId {
     let scope = self.type_scopes.create_scope();
     for param in &callable.type_params {
       self.type_scopes.insert_on_scope(
@@ -313,85 +571,270 @@ impl<'a> Analyzer<'a> {
         Ty::Unresolved(UnresolvedType::InferType(param.symbol_id)),
       );
     }
+    scope
+  }
+
+  fn exec_call_with_inference<const CTOR: bool>(
+    &mut self,
+    callable: &'a CallableType<'a, CTOR>,
+    this_arg: Ty<'a>,
+    arguments: &'a allocator::Vec<'a, Argument<'a>>,
+    ret_sat: Option<Ty<'a>>,
+  ) -> Option<Ty<'a>> {
+    let scope = self.create_inference_scope(callable);
+    let params = self.get_callable_parameter_types(scope, &ExtractedCallable::Single(callable));
+
+    // The arguments which are not context sensitive are contextually typed by the parameters, so
+    // literals are kept until the type arguments are inferred
+    let mut args = self.exec_arguments_with_deferral(arguments, Some(&params));
+    self.infer_call_type_arguments(callable, scope, this_arg, arguments, &mut args, ret_sat);
+
+    // The parameters are resolved again now that the type arguments are inferred
+    let params = self.get_callable_parameter_types(scope, &ExtractedCallable::Single(callable));
+    self.check_arguments(arguments, &args, &params);
+    Some(self.record_call_signature(scope, callable, params))
+  }
+
+  /// Evaluates the arguments, except the context sensitive ones, which are deferred. The others are
+  /// contextually typed by `params` if given.
+  fn exec_arguments_with_deferral(
+    &mut self,
+    arguments: &'a allocator::Vec<'a, Argument<'a>>,
+    params: Option<&[(bool, Ty<'a>)]>,
+  ) -> CallArguments<'a> {
+    let mut args = vec![];
+    let mut param_indexes = vec![];
+    let mut index = Some(0);
+    for arg in arguments {
+      param_indexes.push(index);
+      args.push(match arg {
+        Argument::SpreadElement(node) => {
+          let value = self.exec_expression(&node.argument, None);
+          index = self.get_index_after_spread(index, value);
+          Some((true, value))
+        }
+        node if is_context_sensitive(node.to_expression()) => {
+          index = index.map(|i| i + 1);
+          None
+        }
+        node => {
+          let sat = match params.zip(index).and_then(|(params, i)| params.get(i)) {
+            Some((false, ty)) => Some(*ty),
+            _ => None,
+          };
+          index = index.map(|i| i + 1);
+          Some((false, self.exec_expression(node.to_expression(), sat)))
+        }
+      });
+    }
+    CallArguments { args, param_indexes }
+  }
+
+  /// Evaluates the deferred arguments, contextually typed by `params`.
+  fn exec_deferred_arguments(
+    &mut self,
+    arguments: &'a allocator::Vec<'a, Argument<'a>>,
+    args: &mut CallArguments<'a>,
+    params: &[(bool, Ty<'a>)],
+  ) {
+    for (index, arg) in arguments.iter().enumerate() {
+      if args.args[index].is_none() {
+        let sat = match args.param_indexes[index].and_then(|i| params.get(i)) {
+          Some((false, ty)) => Some(*ty),
+          _ => None,
+        };
+        args.args[index] = Some((false, self.exec_expression(arg.to_expression(), sat)));
+      }
+    }
+  }
+
+  /// Infers the type arguments into `scope`. The deferred arguments are evaluated in between, with
+  /// the parameters partially inferred from the other arguments.
+  fn infer_call_type_arguments<const CTOR: bool>(
+    &mut self,
+    callable: &'a CallableType<'a, CTOR>,
+    scope: TypeScopeId,
+    this_arg: Ty<'a>,
+    arguments: &'a allocator::Vec<'a, Argument<'a>>,
+    args: &mut CallArguments<'a>,
+    ret_sat: Option<Ty<'a>>,
+  ) {
     let params = self.get_callable_parameter_types(scope, &ExtractedCallable::Single(callable));
 
-    #[derive(Default)]
-    struct InferenceState<'a> {
-      upmost_output: Option<Ty<'a>>,
-      lowest_input: Option<Ty<'a>>,
+    if args.args.iter().any(Option::is_none) {
+      // The first round of inference, with the other arguments only
+      let (known_params, known_args) = self.pair_arguments(&params, &args.args);
+      let partial_scope = self.create_inference_scope(callable);
+      self.infer_type_arguments(
+        callable,
+        partial_scope,
+        this_arg,
+        &known_params,
+        &known_args,
+        ret_sat,
+      );
+
+      // Then the deferred arguments are contextually typed by the partially inferred parameters
+      let contextual =
+        self.get_callable_parameter_types(partial_scope, &ExtractedCallable::Single(callable));
+      self.exec_deferred_arguments(arguments, args, &contextual);
     }
 
-    impl<'a> InferenceState<'a> {
-      pub fn update(&mut self, analyzer: &mut Analyzer<'a>, specificity: i32, ty: Ty<'a>) {
-        if specificity > 0 {
-          if let Some(upmost) = &mut self.upmost_output {
-            if analyzer.match_covariant_types(1, *upmost, ty).matched() {
-              *upmost = ty;
+    let (params, args) = self.pair_arguments(&params, &args.args);
+    self.infer_type_arguments(callable, scope, this_arg, &params, &args, ret_sat);
+  }
+
+  /// Checks each argument against its parameter. Arguments after a spread one of unknown length
+  /// are not checked.
+  fn check_arguments(
+    &mut self,
+    arguments: &'a allocator::Vec<'a, Argument<'a>>,
+    args: &CallArguments<'a>,
+    params: &[(bool, Ty<'a>)],
+  ) {
+    for (index, arg) in arguments.iter().enumerate() {
+      let param = args.param_indexes[index].and_then(|i| params.get(i));
+      if let (Some((false, value)), Some((false, param))) = (args.args[index], param) {
+        self.check_expression_assignable(
+          arg.to_expression(),
+          AssignmentKind::Argument,
+          value,
+          *param,
+        );
+      }
+    }
+  }
+
+  /// Pairs the arguments with the parameters by index, with spread tuples of fixed length expanded.
+  /// Missing arguments are skipped. Other spread arguments are kept, and end the pairing.
+  fn pair_arguments(
+    &mut self,
+    params: &[(bool, Ty<'a>)],
+    args: &[Option<(bool, Ty<'a>)>],
+  ) -> (ParamTypes<'a>, ParamTypes<'a>) {
+    let mut pairs = (vec![], vec![]);
+    let mut index = 0;
+    for arg in args {
+      match *arg {
+        Some((true, ty)) => {
+          let Some(tuple) = self.get_fixed_tuple(ty) else {
+            if let Some(param) = params.get(index) {
+              pairs.0.push(*param);
+              pairs.1.push((true, ty));
             }
-          } else {
-            self.upmost_output = Some(ty);
-          }
-        } else {
-          if let Some(lowest) = &mut self.lowest_input {
-            if analyzer.match_covariant_types(1, ty, *lowest).matched() {
-              *lowest = ty;
+            break;
+          };
+          for element in &tuple.elements {
+            if let Some(param) = params.get(index) {
+              pairs.0.push(*param);
+              pairs.1.push((false, element.ty));
             }
-          } else {
-            self.lowest_input = Some(ty);
+            index += 1;
           }
         }
+        Some(arg) => {
+          if let Some(param) = params.get(index) {
+            pairs.0.push(*param);
+            pairs.1.push(arg);
+          }
+          index += 1;
+        }
+        None => index += 1,
       }
     }
+    pairs
+  }
 
-    let mut inferred = FxHashMap::<SymbolId, InferenceState<'a>>::default();
-
-    fn handle_match_result<'a>(
-      analyzer: &mut Analyzer<'a>,
-      inferred: &mut FxHashMap<SymbolId, InferenceState<'a>>,
-      result: MatchResult<'a>,
-    ) -> Option<()> {
-      match result {
-        MatchResult::Error | MatchResult::Unmatched => return None,
-        MatchResult::Matched => {}
-        MatchResult::Inferred(i) => {
-          for (symbol, (specificity, ty)) in i {
-            let state = inferred.entry(symbol).or_default();
-            state.update(analyzer, specificity, ty);
-          }
+  /// Infers the type arguments from the argument types, and writes them into `scope`.
+  fn infer_type_arguments<const CTOR: bool>(
+    &mut self,
+    callable: &'a CallableType<'a, CTOR>,
+    scope: TypeScopeId,
+    this_arg: Ty<'a>,
+    params: &[(bool, Ty<'a>)],
+    args: &[(bool, Ty<'a>)],
+    ret_sat: Option<Ty<'a>>,
+  ) {
+    // # Inference
+    // See https://gitnation.com/contents/lets-make-a-generic-inference-algorithm
+    //
+    // - Non-context-aware arguments first (see `exec_call_with_inference`)
+    // - Candidates are collected from every argument, then from the contextual return type. Only
+    //   the ones with the best priority are kept (see `InferencePriority`)
+    // - Candidates from input positions (specificity > 0) are preferred over the ones from output
+    //   positions (specificity < 0)
+    // - The type parameters are chosen in order, so constraints and defaults may refer to the
+    //   previous ones. Without candidates, the default is used
+
+    let mut candidates = FxHashMap::<SymbolId, InferenceCandidates<'a>>::default();
+
+    if let Some(this_param) = callable.this_param {
+      let this_param = self.resolve_ctx_ty(scope, this_param);
+      if let MatchResult::Inferred(inferred) = self.match_covariant_types(1, this_arg, this_param) {
+        for (symbol, (specificity, ty)) in inferred {
+          let priority = InferencePriority::Direct;
+          candidates.entry(symbol).or_default().add(priority, specificity, ty);
         }
       }
-      Some(())
     }
 
-    for (arg, (_, param)) in arguments.iter().zip(params.iter()) {
-      match arg {
-        Argument::SpreadElement(node) => {
-          todo!()
-        }
-        node => {
-          let arg = self.exec_expression(node.to_expression(), None);
-          let result = self.match_covariant_types(1, arg, *param);
-          handle_match_result(self, &mut inferred, result);
+    for ((spread, arg), (_, param)) in args.iter().zip(params.iter()) {
+      if *spread {
+        // TODO: Infer from spread arguments
+        break;
+      }
+      if let MatchResult::Inferred(inferred) = self.match_covariant_types(1, *arg, *param) {
+        for (symbol, (specificity, ty)) in inferred {
+          let priority = if specificity.abs() == 1 && is_naked_in_union(*param, symbol) {
+            InferencePriority::NakedTypeVariable
+          } else {
+            InferencePriority::Direct
+          };
+          candidates.entry(symbol).or_default().add(priority, specificity, ty);
         }
       }
     }
 
     if let Some(ret_sat) = ret_sat {
       let actual_ret = self.resolve_ctx_ty(scope, callable.return_type);
-      let result = self.match_covariant_types(1, actual_ret, ret_sat);
-      handle_match_result(self, &mut inferred, result);
+      if let MatchResult::Inferred(inferred) = self.match_covariant_types(1, actual_ret, ret_sat) {
+        for (symbol, (specificity, ty)) in inferred {
+          let priority = InferencePriority::ReturnType;
+          candidates.entry(symbol).or_default().add(priority, specificity, ty);
+        }
+      }
     }
 
     for param in &callable.type_params {
-      let ty = if let Some(inferred) = inferred.get(&param.symbol_id) {
-        inferred.upmost_output.or(inferred.lowest_input).unwrap()
-      } else {
-        // Or constraint? idk
-        Ty::Unknown
+      let inferred = match candidates.remove(&param.symbol_id) {
+        Some(candidates) => Some(self.get_inferred_type(callable, scope, param, candidates)),
+        None => param.default.map(|default| self.resolve_ctx_ty(scope, default)),
       };
+      let mut ty = inferred.unwrap_or(Ty::Unknown);
+      // The constraint is used instead if not satisfied, e.g. when nothing is inferred
+      if let Some(constraint) = param.constraint {
+        let constraint = self.resolve_ctx_ty(scope, constraint);
+        let comparable =
+          !matches!(ty, Ty::Unresolved(_)) && !matches!(constraint, Ty::Unresolved(_));
+        if comparable && !self.match_covariant_types(1, ty, constraint).matched() {
+          ty = constraint;
+        }
+      }
       self.type_scopes.insert_on_scope(scope, param.symbol_id, ty);
     }
+  }
+}
 
-    Some(self.resolve_ctx_ty(scope, callable.return_type))
+/// Function expressions with unannotated parameters depend on the contextual type.
+fn is_context_sensitive(node: &Expression) -> bool {
+  match node {
+    Expression::ArrowFunctionExpression(node) => {
+      node.params.items.iter().any(|param| param.pattern.type_annotation.is_none())
+    }
+    Expression::FunctionExpression(node) => {
+      node.params.items.iter().any(|param| param.pattern.type_annotation.is_none())
+    }
+    Expression::ParenthesizedExpression(node) => is_context_sensitive(&node.expression),
+    _ => false,
   }
 }
@@ -0,0 +1,62 @@
+use std::{
+  hash::{Hash, Hasher},
+  ptr,
+};
+
+use oxc::{
+  ast::ast::{TSConditionalType, TSType},
+  span::SPAN,
+};
+
+use super::Ty;
+use crate::analyzer::Analyzer;
+
+/// A conditional type whose check type depends on a type parameter which is not instantiated yet.
+/// The original node is resolved again when the type parameter is instantiated.
+#[derive(Debug)]
+pub struct ConditionalType<'a> {
+  pub check_type: Ty<'a>,
+  pub extends_type: Ty<'a>,
+  /// `infer` declarations are referred to as type parameters in the branches
+  pub true_type: Ty<'a>,
+  pub false_type: Ty<'a>,
+  pub node: &'a TSConditionalType<'a>,
+}
+
+impl<'a> PartialEq for ConditionalType<'a> {
+  fn eq(&self, other: &Self) -> bool {
+    ptr::eq(self.node, other.node)
+      && self.check_type == other.check_type
+      && self.extends_type == other.extends_type
+      && self.true_type == other.true_type
+      && self.false_type == other.false_type
+  }
+}
+
+impl<'a> Eq for ConditionalType<'a> {}
+
+impl<'a> Hash for ConditionalType<'a> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    ptr::hash(self.node, state);
+    self.check_type.hash(state);
+    self.extends_type.hash(state);
+  }
+}
+
+impl<'a> Analyzer<'a> {
+  pub fn serialize_conditional_type(&mut self, conditional: &ConditionalType<'a>) -> TSType<'a> {
+    let ty = self.ast_builder.ts_type_conditional_type(
+      SPAN,
+      self.serialize_type(conditional.check_type),
+      self.serialize_type(conditional.extends_type),
+      self.serialize_type(conditional.true_type),
+      self.serialize_type(conditional.false_type),
+    );
+    // The `infer` declarations are looked up in the scope of the original node, when the
+    // serialized type is resolved on instantiation
+    if let TSType::TSConditionalType(node) = &ty {
+      node.scope_id.set(conditional.node.scope_id.get());
+    }
+    ty
+  }
+}
@@ -1,3 +1,8 @@
+use std::{
+  hash::{Hash, Hasher},
+  mem, ptr,
+};
+
 use oxc::{
   allocator::{self, CloneIn},
   ast::ast::{TSType, TSTypeAnnotation},
@@ -17,14 +22,27 @@ impl<'a> PartialEq for CtxTy<'a> {
   fn eq(&self, other: &Self) -> bool {
     match (self, other) {
       (CtxTy::Static(a), CtxTy::Static(b)) => a == b,
-      (CtxTy::WithCtx(a, an), CtxTy::WithCtx(b, bn)) => {
-        a == b && (an as *const _) == (bn as *const _)
-      }
+      (CtxTy::WithCtx(a, an), CtxTy::WithCtx(b, bn)) => a == b && ptr::eq(*an, *bn),
       _ => false,
     }
   }
 }
 
+impl<'a> Eq for CtxTy<'a> {}
+
+impl<'a> Hash for CtxTy<'a> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    mem::discriminant(self).hash(state);
+    match self {
+      CtxTy::Static(ty) => ty.hash(state),
+      CtxTy::WithCtx(scope, node) => {
+        scope.hash(state);
+        ptr::hash(*node, state);
+      }
+    }
+  }
+}
+
 impl<'a> CtxTy<'a> {
   pub fn with_scope(self, scope: TypeScopeId) -> CtxTy<'a> {
     match self {
@@ -0,0 +1,87 @@
+use std::{
+  hash::{Hash, Hasher},
+  ptr,
+};
+
+use oxc::{
+  ast::{
+    ast::{TSType, TSTypeName},
+    NONE,
+  },
+  semantic::SymbolId,
+  span::{Atom, SPAN},
+};
+
+use super::Ty;
+use crate::analyzer::Analyzer;
+
+/// The type of an enum member, e.g. `E.A`. Each member is allocated once, so that its identity is
+/// kept through unions and widening.
+#[derive(Debug)]
+pub struct EnumMemberType<'a> {
+  /// The symbol of the enum declaration
+  pub enum_symbol: SymbolId,
+  pub name: &'a Atom<'a>,
+  /// A string or numeric literal, or `number` for computed members
+  pub value: Ty<'a>,
+}
+
+impl PartialEq for EnumMemberType<'_> {
+  fn eq(&self, other: &Self) -> bool {
+    ptr::eq(self, other)
+  }
+}
+
+impl Eq for EnumMemberType<'_> {}
+
+impl Hash for EnumMemberType<'_> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    ptr::hash(self, state)
+  }
+}
+
+impl<'a> Analyzer<'a> {
+  /// The type `E` of the enum, which is the union of its members.
+  pub fn get_enum_type(&self, member: &EnumMemberType<'a>) -> Ty<'a> {
+    self.enums.get(&member.enum_symbol).copied().unwrap_or(Ty::Error)
+  }
+
+  /// The member `name` of the enum declared by `symbol`.
+  pub fn get_enum_member(&self, symbol: SymbolId, name: &str) -> Option<Ty<'a>> {
+    let mut found = None;
+    let mut visit = |ty: Ty<'a>| match ty {
+      Ty::EnumMember(member) if member.name.as_str() == name => found = Some(ty),
+      _ => {}
+    };
+    match *self.enums.get(&symbol)? {
+      Ty::Union(union) => union.for_each(visit),
+      ty => visit(ty),
+    }
+    found
+  }
+
+  /// If `ty` is the type of an enum, its symbol.
+  pub fn get_enum_symbol_of_union(&self, ty: Ty<'a>) -> Option<SymbolId> {
+    let Ty::Union(union) = ty else {
+      return None;
+    };
+    let symbol = union.complex.iter().find_map(|ty| match ty {
+      Ty::EnumMember(member) => Some(member.enum_symbol),
+      _ => None,
+    })?;
+    (self.enums.get(&symbol) == Some(&ty)).then_some(symbol)
+  }
+
+  /// `E`, for the type of the enum or as the left side of `E.A`.
+  pub fn serialize_enum_reference(&mut self, symbol: SymbolId) -> TSTypeName<'a> {
+    let node = self.serialize_identifier_reference(symbol);
+    TSTypeName::IdentifierReference(self.ast_builder.alloc(node))
+  }
+
+  pub fn serialize_enum_member_type(&mut self, member: &EnumMemberType<'a>) -> TSType<'a> {
+    let left = self.serialize_enum_reference(member.enum_symbol);
+    let right = self.ast_builder.identifier_name(SPAN, member.name.as_str());
+    let name = self.ast_builder.ts_type_name_qualified_name(SPAN, left, right);
+    self.ast_builder.ts_type_type_reference(SPAN, name, NONE)
+  }
+}
@@ -69,24 +69,24 @@ impl<'a> Analyzer<'a> {
       Ty::Never => Facts::T_NE_ALL,
       Ty::Void => Facts::FALSY | Facts::T_NE_ALL,
 
-      Ty::BigInt => Facts::T_EQ_BIGINT | Facts::T_NE_ALL & !Facts::T_EQ_BIGINT,
-      Ty::Boolean => Facts::T_EQ_BOOLEAN | Facts::T_NE_ALL & !Facts::T_EQ_BOOLEAN,
+      Ty::BigInt => Facts::T_EQ_BIGINT | Facts::T_NE_ALL & !Facts::T_NE_BIGINT,
+      Ty::Boolean => Facts::T_EQ_BOOLEAN | Facts::T_NE_ALL & !Facts::T_NE_BOOLEAN,
       Ty::Null => {
         Facts::EQ_NULL
           | Facts::IS_NULLISH
           | Facts::FALSY
           | Facts::T_EQ_OBJECT
-          | Facts::T_NE_ALL & !Facts::NE_NULL & !Facts::T_NE_OBJECT
+          | Facts::T_NE_ALL & !Facts::NE_NULL & !Facts::T_NE_OBJECT & !Facts::NOT_NULLISH
       }
-      Ty::Number => Facts::T_EQ_NUMBER | Facts::T_NE_ALL & !Facts::T_EQ_NUMBER,
-      Ty::Object => Facts::T_EQ_OBJECT | Facts::TRUTHY | Facts::T_NE_ALL & !Facts::T_EQ_OBJECT,
-      Ty::String => Facts::T_EQ_STRING | Facts::T_NE_ALL & !Facts::T_EQ_STRING,
-      Ty::Symbol => Facts::T_EQ_SYMBOL | Facts::TRUTHY | Facts::T_NE_ALL & !Facts::T_EQ_SYMBOL,
+      Ty::Number => Facts::T_EQ_NUMBER | Facts::T_NE_ALL & !Facts::T_NE_NUMBER,
+      Ty::Object => Facts::T_EQ_OBJECT | Facts::TRUTHY | Facts::T_NE_ALL & !Facts::T_NE_OBJECT,
+      Ty::String => Facts::T_EQ_STRING | Facts::T_NE_ALL & !Facts::T_NE_STRING,
+      Ty::Symbol => Facts::T_EQ_SYMBOL | Facts::TRUTHY | Facts::T_NE_ALL & !Facts::T_NE_SYMBOL,
       Ty::Undefined => {
         Facts::EQ_UNDEFINED
           | Facts::IS_NULLISH
           | Facts::FALSY
-          | Facts::T_NE_ALL & !Facts::NE_UNDEFINED
+          | Facts::T_NE_ALL & !Facts::NE_UNDEFINED & !Facts::NOT_NULLISH
       }
 
       Ty::StringLiteral(s) => self.get_facts(Ty::String) | Facts::truthy(s.len() > 0),
@@ -94,10 +94,11 @@ impl<'a> Analyzer<'a> {
       Ty::BigIntLiteral(_) => self.get_facts(Ty::BigInt),
       Ty::BooleanLiteral(b) => self.get_facts(Ty::Boolean) | Facts::truthy(b),
       Ty::UniqueSymbol(_) => self.get_facts(Ty::Symbol),
+      Ty::EnumMember(member) => self.get_facts(member.value),
 
       Ty::Record(_) | Ty::Interface(_) | Ty::Tuple(_) => self.get_facts(Ty::Object),
       Ty::Function(_) | Ty::Constructor(_) => {
-        Facts::T_EQ_FUNCTION | Facts::TRUTHY | Facts::T_NE_ALL & !Facts::T_EQ_FUNCTION
+        Facts::T_EQ_FUNCTION | Facts::TRUTHY | Facts::T_NE_ALL & !Facts::T_NE_FUNCTION
       }
 
       Ty::Union(union) => {
@@ -111,11 +112,13 @@ impl<'a> Analyzer<'a> {
         facts
       }
 
-      Ty::Generic(_) | Ty::Intrinsic(_) | Ty::Namespace(_) => Facts::NONE,
+      Ty::Namespace(_) => Facts::NONE,
 
-      Ty::Instance(_) | Ty::Unresolved(_) => {
-        let lowest = self.get_lowest_type(ty);
-        self.get_facts(lowest)
+      Ty::Generic(_) | Ty::Intrinsic(_) | Ty::Instance(_) | Ty::Unresolved(_) => {
+        match self.get_lowest_type(ty) {
+          lowest if lowest == ty => Facts::NONE,
+          lowest => self.get_facts(lowest),
+        }
       }
     }
   }
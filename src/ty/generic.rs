@@ -1,11 +1,19 @@
 use std::cell::RefCell;
 
-use oxc::{ast::ast::TSType, semantic::SymbolId, span::Atom};
+use oxc::{
+  ast::ast::TSType,
+  semantic::SymbolId,
+  span::{Atom, SPAN},
+};
 
 use super::{ctx::CtxTy, intersection::IntersectionType, union::UnionType, Ty};
-use crate::{analyzer::Analyzer, scope::r#type::TypeScopeId};
+use crate::{
+  analyzer::Analyzer,
+  diagnostic::{Diagnostic, DiagnosticCode},
+  scope::r#type::TypeScopeId,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GenericParam<'a> {
   pub symbol_id: SymbolId,
   pub constraint: Option<CtxTy<'a>>,
@@ -33,8 +41,8 @@ pub struct GenericInstanceType<'a> {
 impl<'a> Analyzer<'a> {
   pub fn instantiate_generic_params(
     &mut self,
-    params: &Vec<GenericParam<'a>>,
-    args: &Vec<Ty<'a>>,
+    params: &[GenericParam<'a>],
+    args: &[Ty<'a>],
   ) -> TypeScopeId {
     let scope = self.type_scopes.create_scope();
 
@@ -51,27 +59,27 @@ impl<'a> Analyzer<'a> {
   }
 
   pub fn create_generic_instance(&mut self, generic: Ty<'a>, mut args: Vec<Ty<'a>>) -> Ty<'a> {
-    match generic {
-      Ty::Generic(generic) => {
-        if generic.params.len() > args.len() {
-          // Should resolve all the defaults
-          let scope = self.type_scopes.create_scope();
-          for (param, arg) in generic.params.iter().zip(args.iter()) {
-            self.type_scopes.insert_on_scope(scope, param.symbol_id, *arg);
-          }
-          for param in generic.params.iter().skip(args.len()) {
-            let arg = if let Some(default) = param.default {
-              self.resolve_ctx_ty(scope, default)
-            } else {
-              Ty::Error
-            };
-            args.push(arg);
-            self.type_scopes.insert_on_scope(scope, param.symbol_id, arg);
-          }
-        }
-      }
-      Ty::Intrinsic(_) => {}
+    let params = match generic {
+      Ty::Generic(generic) => generic.params.as_slice(),
+      Ty::Interface(interface) if interface.is_generic() => interface.type_params.as_slice(),
+      Ty::Intrinsic(_) => &[],
       _ => return Ty::Error,
+    };
+    if params.len() > args.len() {
+      // Should resolve all the defaults
+      let scope = self.type_scopes.create_scope();
+      for (param, arg) in params.iter().zip(args.iter()) {
+        self.type_scopes.insert_on_scope(scope, param.symbol_id, *arg);
+      }
+      for param in params.iter().skip(args.len()) {
+        let arg = if let Some(default) = param.default {
+          self.resolve_ctx_ty(scope, default)
+        } else {
+          Ty::Error
+        };
+        args.push(arg);
+        self.type_scopes.insert_on_scope(scope, param.symbol_id, arg);
+      }
     }
     Ty::Instance(self.allocator.alloc(GenericInstanceType {
       generic,
@@ -80,24 +88,53 @@ impl<'a> Analyzer<'a> {
     }))
   }
 
+  /// Instances which unwrap to other instances, e.g. recursive conditional types, are unwrapped
+  /// until they are not, within the limits of `Config::max_instantiation_depth` and
+  /// `Config::max_instantiation_count`.
   pub fn unwrap_generic_instance(&mut self, instance: &GenericInstanceType<'a>) -> Ty<'a> {
-    *instance.unwrapped.borrow_mut().get_or_insert_with(|| {
-      match instance.generic {
-        Ty::Unresolved(_) => {
-          unreachable!("Generic itself should always be resolved when analyzing declaration")
-        }
+    if let Some(unwrapped) = *instance.unwrapped.borrow() {
+      return unwrapped;
+    }
 
-        // instance.generic is a generic type
-        Ty::Generic(generic) => {
-          let scope = self.instantiate_generic_params(&generic.params, &instance.args);
-          self.resolve_ctx_ty(scope, generic.body)
-        }
-        Ty::Intrinsic(_) => todo!(),
+    if self.instantiation_depth >= self.config.max_instantiation_depth
+      || self.instantiation_count >= self.config.max_instantiation_count
+    {
+      let span = self.span_stack.last().copied().unwrap_or(SPAN);
+      self.report(Diagnostic::error(
+        DiagnosticCode::ExcessivelyDeepInstantiation,
+        "Type instantiation is excessively deep and possibly infinite.",
+        span,
+      ));
+      *instance.unwrapped.borrow_mut() = Some(Ty::Error);
+      return Ty::Error;
+    }
+    self.instantiation_depth += 1;
+    self.instantiation_count += 1;
 
-        // instance.generic is a generic value (function or constructor or compound of them)
-        _ => self.instantiate_generic_value(instance.generic, &instance.args),
+    let unwrapped = match instance.generic {
+      Ty::Unresolved(_) => {
+        unreachable!("Generic itself should always be resolved when analyzing declaration")
+      }
+
+      // instance.generic is a generic type
+      Ty::Generic(generic) => {
+        let scope = self.instantiate_generic_params(&generic.params, &instance.args);
+        self.resolve_ctx_ty(scope, generic.body)
       }
-    })
+      Ty::Intrinsic(_) => self.unsupported("intrinsic types"),
+      Ty::Interface(interface) => self.instantiate_interface(interface, &instance.args),
+
+      // instance.generic is a generic value (function or constructor or compound of them)
+      _ => self.instantiate_generic_value(instance.generic, &instance.args),
+    };
+    let unwrapped = match unwrapped {
+      Ty::Instance(inner) => self.unwrap_generic_instance(inner),
+      ty => ty,
+    };
+
+    self.instantiation_depth -= 1;
+    *instance.unwrapped.borrow_mut() = Some(unwrapped);
+    unwrapped
   }
 
   // pub fn instantiate_generic_type(&mut self, instance: &GenericInstanceType<'a>) -> Ty<'a> {
@@ -127,7 +164,7 @@ impl<'a> Analyzer<'a> {
       Ty::Union(union) => {
         let complex =
           union.complex.iter().map(|ty| self.instantiate_generic_value(*ty, args)).collect();
-        Some(Ty::Union(self.allocator.alloc(Box::new(UnionType { complex, ..union.clone() }))))
+        Some(self.intern_union(UnionType { complex, ..union.clone() }))
       }
       Ty::Intersection(intersection) => {
         let kind = intersection.kind;
@@ -139,11 +176,11 @@ impl<'a> Analyzer<'a> {
         if object_like.is_empty() {
           intersection.kind_to_ty()
         } else {
-          Some(Ty::Intersection(self.allocator.alloc(Box::new(IntersectionType {
+          Some(self.intern_intersection(IntersectionType {
             kind,
             object_like,
             unresolved: intersection.unresolved.clone(),
-          }))))
+          }))
         }
       }
 
@@ -161,7 +198,7 @@ impl<'a> Analyzer<'a> {
   pub fn instantiate_generic_value(&mut self, ty: Ty<'a>, args: &Vec<Ty<'a>>) -> Ty<'a> {
     self
       .try_instantiate_generic_value(ty, args)
-      .unwrap_or_else(|| Ty::Record(self.allocator.alloc(Default::default())))
+      .unwrap_or_else(|| self.intern_record(Default::default()))
   }
 
   pub fn serialize_instance_type(&mut self, instance: &GenericInstanceType<'a>) -> TSType<'a> {
@@ -169,7 +206,8 @@ impl<'a> Analyzer<'a> {
     self.serialize_type(unwrapped)
   }
 
-  pub fn serialize_generic_type(&mut self, generic: &GenericType<'a>) -> TSType<'a> {
-    todo!()
+  pub fn serialize_generic_type(&mut self, _generic: &GenericType<'a>) -> TSType<'a> {
+    let ty = self.unsupported("printing generic types");
+    self.serialize_type(ty)
   }
 }
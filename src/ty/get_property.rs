@@ -1,4 +1,8 @@
-use super::{property_key::PropertyKeyType, Ty};
+use super::{
+  property_key::PropertyKeyType,
+  record::{RecordPropertyValue, RecordType},
+  Ty,
+};
 use crate::Analyzer;
 
 impl<'a> Analyzer<'a> {
@@ -16,16 +20,24 @@ impl<'a> Analyzer<'a> {
       Ty::Object => self.get_property(self.builtins.object_prototype, key),
       Ty::String | Ty::StringLiteral(_) => self.get_property(self.builtins.string_prototype, key),
       Ty::Symbol | Ty::UniqueSymbol(_) => self.get_property(self.builtins.symbol_prototype, key),
+      Ty::EnumMember(member) => self.get_property(member.value, key),
       Ty::Function(_) | Ty::Constructor(_) => {
         self.get_property(self.builtins.function_prototype, key)
       }
 
-      Ty::Record(r) => r.get_property(key),
-      Ty::Interface(i) => i.get_property(key),
+      Ty::Record(r) => {
+        let own = self.get_record_property(r, key);
+        self.inherit_object_prototype(own, key)
+      }
+      Ty::Interface(i) => {
+        let members = self.get_interface_members(i);
+        let own = self.get_record_property(&members.record, key);
+        self.inherit_object_prototype(own, key)
+      }
       Ty::Tuple(t) => t.get_property(key, self),
 
       Ty::Union(u) => self.get_union_property(u, key),
-      Ty::Intersection(_) => todo!(),
+      Ty::Intersection(i) => self.get_intersection_property(i, key),
 
       Ty::Instance(i) => {
         let unwrapped = self.unwrap_generic_instance(i);
@@ -33,7 +45,7 @@ impl<'a> Analyzer<'a> {
       }
 
       Ty::Generic(_) | Ty::Intrinsic(_) => Ty::Error,
-      Ty::Namespace(_) => todo!(),
+      Ty::Namespace(n) => self.get_namespace_property(n, key),
 
       Ty::Unresolved(_) => {
         let lowest = self.get_lowest_type(target);
@@ -41,4 +53,58 @@ impl<'a> Analyzer<'a> {
       }
     }
   }
+
+  /// Properties read via index signatures may be `undefined` with
+  /// `Config::no_unchecked_indexed_access`.
+  fn get_record_property(&mut self, record: &RecordType<'a>, key: PropertyKeyType<'a>) -> Ty<'a> {
+    if let Some(property) = record.get_keyed_property(key) {
+      property.value
+    } else if let Some(property) = record.get_index_signature(key) {
+      if self.config.no_unchecked_indexed_access {
+        self.into_union([property.value, Ty::Undefined]).unwrap()
+      } else {
+        property.value
+      }
+    } else {
+      Ty::Error
+    }
+  }
+
+  /// Like `get_property`, but with the optional and readonly flags. Returns `None` if the property
+  /// does not exist.
+  pub fn get_property_value(
+    &mut self,
+    target: Ty<'a>,
+    key: PropertyKeyType<'a>,
+  ) -> Option<RecordPropertyValue<'a>> {
+    let own = match target {
+      Ty::Record(r) => r.get_property_value(key),
+      Ty::Interface(i) => self.get_interface_members(i).record.get_property_value(key),
+      Ty::Intersection(i) => return self.get_intersection_property_value(i, key),
+      _ => {
+        let value = self.get_property(target, key);
+        return (value != Ty::Error).then_some(RecordPropertyValue {
+          value,
+          optional: false,
+          readonly: false,
+        });
+      }
+    };
+    own.or_else(|| {
+      let value = self.inherit_object_prototype(Ty::Error, key);
+      (value != Ty::Error).then_some(RecordPropertyValue {
+        value,
+        optional: false,
+        readonly: false,
+      })
+    })
+  }
+
+  /// Members of `Object.prototype` which are not declared by the object type itself.
+  fn inherit_object_prototype(&self, own: Ty<'a>, key: PropertyKeyType<'a>) -> Ty<'a> {
+    match (own, self.builtins.object_prototype) {
+      (Ty::Error, Ty::Record(prototype)) => prototype.get_property(key),
+      _ => own,
+    }
+  }
 }
@@ -0,0 +1,215 @@
+use oxc::semantic::SymbolId;
+
+use super::{callable::CallableType, generic::GenericParam, unresolved::UnresolvedType, Ty};
+use crate::{analyzer::Analyzer, scope::r#type::TypeScopeId};
+
+/// Where an inference candidate comes from. Lower is preferred, like in TypeScript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InferencePriority {
+  /// Inferred from an argument, e.g. `T` in `(x: T)` or in `(x: Box<T>)`
+  Direct,
+  /// Inferred to a naked type parameter in a union, e.g. `T` in `(x: T | undefined)`
+  NakedTypeVariable,
+  /// Inferred from the contextual type of the call
+  ReturnType,
+}
+
+/// The candidates of one type parameter. Only the ones with the best priority are kept.
+#[derive(Debug, Default)]
+pub struct InferenceCandidates<'a> {
+  priority: Option<InferencePriority>,
+  /// Inferred from input positions (specificity > 0), e.g. arguments
+  covariant: Vec<Ty<'a>>,
+  /// Inferred from output positions (specificity < 0), e.g. the parameters of callbacks
+  contravariant: Vec<Ty<'a>>,
+  /// Whether any covariant candidate of an argument is inferred from a nested position, e.g. `T`
+  /// in `Box<T>`
+  nested: bool,
+}
+
+impl<'a> InferenceCandidates<'a> {
+  pub fn add(&mut self, priority: InferencePriority, specificity: i32, ty: Ty<'a>) {
+    if priority != InferencePriority::ReturnType && specificity > 1 {
+      self.nested = true;
+    }
+    match self.priority {
+      Some(p) if p < priority => return,
+      Some(p) if p == priority => {}
+      _ => {
+        self.priority = Some(priority);
+        self.covariant.clear();
+        self.contravariant.clear();
+      }
+    }
+    if specificity > 0 {
+      self.covariant.push(ty);
+    } else {
+      self.contravariant.push(ty);
+    }
+  }
+}
+
+/// Whether the type parameter is a member of the union, e.g. `T | undefined`.
+pub fn is_naked_in_union(ty: Ty, symbol: SymbolId) -> bool {
+  match ty {
+    Ty::Union(union) => union.unresolved.contains(&UnresolvedType::InferType(symbol)),
+    _ => false,
+  }
+}
+
+impl<'a> Analyzer<'a> {
+  /// Chooses the type argument from the candidates, like `getInferredType` in TypeScript. The
+  /// type parameters before `param` should have been written into `scope`.
+  pub fn get_inferred_type<const CTOR: bool>(
+    &mut self,
+    callable: &'a CallableType<'a, CTOR>,
+    scope: TypeScopeId,
+    param: &GenericParam<'a>,
+    candidates: InferenceCandidates<'a>,
+  ) -> Ty<'a> {
+    if candidates.covariant.is_empty() {
+      return self.get_common_subtype(candidates.contravariant);
+    }
+
+    let supertype = self.get_common_supertype(candidates.covariant);
+    if param.r#const || matches!(supertype, Ty::Unresolved(_)) {
+      return supertype;
+    }
+    let widened = self.get_widened_type(supertype);
+    if widened == supertype {
+      return supertype;
+    }
+
+    // Literals are kept if the constraint expects primitives, or if the type parameter is returned
+    // as is, e.g. `identity(1)` is `1` but `box(1)` is `{ value: number }`
+    let primitive_constraint = match param.constraint {
+      Some(constraint) => {
+        let constraint = self.resolve_ctx_ty(scope, constraint);
+        self.has_primitive_type(constraint)
+      }
+      None => false,
+    };
+    if primitive_constraint
+      || (!candidates.nested && self.is_returned_at_top_level(callable, scope, param.symbol_id))
+    {
+      supertype
+    } else {
+      widened
+    }
+  }
+
+  /// The first candidate which the others are assignable to, or the union of literals of the same
+  /// primitive. `null` and `undefined` are taken out first, and added back to the result.
+  fn get_common_supertype(&mut self, candidates: Vec<Ty<'a>>) -> Ty<'a> {
+    let mut nullable = vec![];
+    let mut primary = vec![];
+    for ty in candidates {
+      match ty {
+        Ty::Null | Ty::Undefined => nullable.push(ty),
+        Ty::Union(union) if union.null || union.undefined => {
+          if union.null {
+            nullable.push(Ty::Null);
+          }
+          if union.undefined {
+            nullable.push(Ty::Undefined);
+          }
+        }
+        _ => {
+          primary.push(ty);
+          continue;
+        }
+      }
+      primary.push(self.non_nullable(ty));
+    }
+
+    let supertype = if self.is_literals_of_same_primitive(&primary) {
+      self.into_union(primary).unwrap()
+    } else {
+      primary
+        .into_iter()
+        .reduce(|s, t| if self.match_covariant_types(1, s, t).matched() { t } else { s })
+        .unwrap()
+    };
+    if nullable.is_empty() {
+      supertype
+    } else {
+      nullable.push(supertype);
+      self.into_union(nullable).unwrap()
+    }
+  }
+
+  /// The first candidate which is assignable to the others.
+  fn get_common_subtype(&mut self, candidates: Vec<Ty<'a>>) -> Ty<'a> {
+    candidates
+      .into_iter()
+      .reduce(|s, t| if self.match_covariant_types(1, t, s).matched() { t } else { s })
+      .unwrap()
+  }
+
+  fn is_literals_of_same_primitive(&mut self, types: &[Ty<'a>]) -> bool {
+    let mut base = None;
+    for ty in types {
+      if !matches!(
+        ty,
+        Ty::StringLiteral(_)
+          | Ty::NumericLiteral(_)
+          | Ty::BigIntLiteral(_)
+          | Ty::BooleanLiteral(_)
+          | Ty::EnumMember(_)
+      ) {
+        return false;
+      }
+      let widened = self.get_widened_type(*ty);
+      if base.replace(widened).is_some_and(|base| base != widened) {
+        return false;
+      }
+    }
+    true
+  }
+
+  /// Whether the type may be a primitive, e.g. `string` or `keyof T`.
+  fn has_primitive_type(&mut self, ty: Ty<'a>) -> bool {
+    match ty {
+      Ty::String
+      | Ty::Number
+      | Ty::BigInt
+      | Ty::Boolean
+      | Ty::Symbol
+      | Ty::StringLiteral(_)
+      | Ty::NumericLiteral(_)
+      | Ty::BigIntLiteral(_)
+      | Ty::BooleanLiteral(_)
+      | Ty::UniqueSymbol(_)
+      | Ty::EnumMember(_) => true,
+      Ty::Union(union) => {
+        let mut found = false;
+        union.for_each(|ty| found |= self.has_primitive_type(ty));
+        found
+      }
+      Ty::Intersection(intersection) => {
+        let mut found = false;
+        intersection.for_each(|ty| found |= self.has_primitive_type(ty));
+        found
+      }
+      Ty::Instance(_) | Ty::Unresolved(_) => {
+        let lowest = self.get_lowest_type(ty);
+        self.has_primitive_type(lowest)
+      }
+      _ => false,
+    }
+  }
+
+  /// Whether the return type is the type parameter itself, or a union of it.
+  fn is_returned_at_top_level<const CTOR: bool>(
+    &mut self,
+    callable: &'a CallableType<'a, CTOR>,
+    scope: TypeScopeId,
+    symbol: SymbolId,
+  ) -> bool {
+    let target = Ty::Unresolved(UnresolvedType::InferType(symbol));
+    match self.resolve_ctx_ty(scope, callable.return_type) {
+      Ty::Union(union) => union.unresolved.contains(&UnresolvedType::InferType(symbol)),
+      ty => ty == target,
+    }
+  }
+}
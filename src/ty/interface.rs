@@ -1,62 +1,472 @@
-use std::cell::RefCell;
+use std::{
+  cell::{Cell, Ref, RefCell},
+  fmt,
+};
 
-use oxc::ast::ast::TSType;
+use oxc::{
+  ast::{
+    ast::{Expression, TSInterfaceDeclaration, TSInterfaceHeritage, TSType, TSTypeName},
+    NONE,
+  },
+  span::{Atom, Span, SPAN},
+};
+use rustc_hash::FxHashMap;
 
-use crate::Analyzer;
-
-use super::{property_key::PropertyKeyType, record::RecordType, unresolved::UnresolvedType, Ty};
+use super::{
+  generic::GenericParam,
+  intersection::IntersectionBaseKind,
+  namespace::NamespaceType,
+  r#match::MatchResult,
+  record::{RecordType, RecordTypeBuilder},
+  Ty,
+};
+use crate::{
+  analyzer::Analyzer,
+  diagnostic::{Diagnostic, DiagnosticCode},
+  scope::r#type::TypeScopeId,
+};
 
 #[derive(Debug, Default)]
 pub struct InterfaceTypeInner<'a> {
+  /// Own and inherited properties
   pub record: RecordType<'a>,
+  /// Own call and construct signatures, followed by the inherited ones
   pub callables: Vec<Ty<'a>>,
-  pub unresolved_extends: Vec<UnresolvedType<'a>>,
 }
 
-#[derive(Debug, Default)]
-pub struct InterfaceType<'a>(pub RefCell<InterfaceTypeInner<'a>>);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InterfaceState {
+  Unresolved,
+  Resolving,
+  Resolved,
+}
 
-impl<'a> InterfaceTypeInner<'a> {
-  pub fn extend(&mut self, ty: Ty<'a>) {
-    match ty {
-      Ty::Record(r) => {}
-      Ty::Constructor(_) | Ty::Function(_) => self.callables.push(ty.clone()),
-      Ty::Interface(i) => {
-        let i = i.0.borrow();
-        self.record.extend(i.record.clone());
-        self.callables.extend(i.callables.iter().cloned());
-      }
-      Ty::Namespace(n) => todo!(),
+#[derive(Debug)]
+struct InterfaceDeclaration<'a> {
+  node: &'a TSInterfaceDeclaration<'a>,
+  /// Each declaration declares its own type parameters
+  type_params: Vec<GenericParam<'a>>,
+}
+
+pub struct InterfaceType<'a> {
+  pub name: &'a Atom<'a>,
+  /// Where the interface is declared, or where the type parameters are instantiated
+  pub scope: TypeScopeId,
+  /// Only for generic interfaces, which are instantiated via `Ty::Instance`
+  pub type_params: Vec<GenericParam<'a>>,
+  /// Only for instances of generic interfaces
+  pub type_args: Vec<Ty<'a>>,
+  /// The generic interface, for instances
+  origin: Option<&'a InterfaceType<'a>>,
+  /// The merged declarations in source order. Empty for instances.
+  declarations: RefCell<Vec<InterfaceDeclaration<'a>>>,
+  /// Members are resolved lazily, because the bases may be declared later
+  state: Cell<InterfaceState>,
+  inner: RefCell<InterfaceTypeInner<'a>>,
+  /// So that the same type arguments give the same instance
+  instances: RefCell<FxHashMap<Vec<Ty<'a>>, &'a InterfaceType<'a>>>,
+}
+
+// Interfaces may reference themselves
+impl<'a> fmt::Debug for InterfaceType<'a> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("InterfaceType").field("name", &self.name).finish_non_exhaustive()
+  }
+}
+
+impl<'a> InterfaceType<'a> {
+  pub fn new(
+    node: &'a TSInterfaceDeclaration<'a>,
+    scope: TypeScopeId,
+    type_params: Vec<GenericParam<'a>>,
+  ) -> Self {
+    InterfaceType {
+      name: &node.id.name,
+      scope,
+      type_params: type_params.clone(),
+      type_args: vec![],
+      origin: None,
+      declarations: RefCell::new(vec![InterfaceDeclaration { node, type_params }]),
+      state: Cell::new(InterfaceState::Unresolved),
+      inner: Default::default(),
+      instances: Default::default(),
+    }
+  }
+
+  /// A built-in instance whose members are created by the analyzer rather than declared, e.g.
+  /// `Promise<T>`. The members are set by `set_builtin_members`.
+  pub fn builtin(name: &'a Atom<'a>, scope: TypeScopeId, type_args: Vec<Ty<'a>>) -> Self {
+    InterfaceType {
+      name,
+      scope,
+      type_params: vec![],
+      type_args,
+      origin: None,
+      declarations: Default::default(),
+      // Members may reference the interface itself while they are created
+      state: Cell::new(InterfaceState::Resolving),
+      inner: Default::default(),
+      instances: Default::default(),
+    }
+  }
+
+  pub fn set_builtin_members(&self, inner: InterfaceTypeInner<'a>) {
+    *self.inner.borrow_mut() = inner;
+    self.state.set(InterfaceState::Resolved);
+  }
+
+  pub fn is_generic(&self) -> bool {
+    !self.type_params.is_empty()
+  }
 
-      Ty::Intersection(i) => {
-        i.for_each(|ty| self.extend(ty));
+  /// Merges a later declaration of the same interface.
+  pub fn merge_declaration(
+    &self,
+    node: &'a TSInterfaceDeclaration<'a>,
+    type_params: Vec<GenericParam<'a>>,
+  ) {
+    self.declarations.borrow_mut().push(InterfaceDeclaration { node, type_params });
+    // Already resolved when used before the later declaration
+    self.invalidate();
+  }
+
+  /// Merges all the declarations of another interface with the same name, e.g. from another
+  /// `declare global` block.
+  pub fn merge_interface(&self, other: &InterfaceType<'a>) {
+    for declaration in other.declarations.borrow_mut().drain(..) {
+      self.declarations.borrow_mut().push(declaration);
+    }
+    self.invalidate();
+  }
+
+  fn invalidate(&self) {
+    if self.state.get() == InterfaceState::Resolved {
+      self.state.set(InterfaceState::Unresolved);
+    }
+    for instance in self.instances.borrow().values() {
+      instance.invalidate();
+    }
+  }
+
+  fn declaration_nodes(&self) -> Vec<&'a TSInterfaceDeclaration<'a>> {
+    let origin = self.origin.unwrap_or(self);
+    origin.declarations.borrow().iter().map(|declaration| declaration.node).collect()
+  }
+
+  /// Where diagnostics about the interface itself are reported.
+  fn span(&self) -> Span {
+    self.declaration_nodes()[0].id.span
+  }
+}
+
+impl<'a> Analyzer<'a> {
+  /// The members of the interface, which are resolved on first access.
+  pub fn get_interface_members(
+    &mut self,
+    interface: &'a InterfaceType<'a>,
+  ) -> Ref<'a, InterfaceTypeInner<'a>> {
+    self.resolve_interface(interface);
+    interface.inner.borrow()
+  }
+
+  pub fn is_empty_interface(&mut self, interface: &'a InterfaceType<'a>) -> bool {
+    let inner = self.get_interface_members(interface);
+    inner.record.is_empty() && inner.callables.is_empty()
+  }
+
+  pub fn instantiate_interface(
+    &mut self,
+    interface: &'a InterfaceType<'a>,
+    args: &Vec<Ty<'a>>,
+  ) -> Ty<'a> {
+    let cached = interface.instances.borrow().get(args).copied();
+    if let Some(instance) = cached {
+      return Ty::Interface(instance);
+    }
+    let scope = self.instantiate_generic_params(&interface.type_params, args);
+    // The type parameters of the merged declarations are the same ones
+    for declaration in interface.declarations.borrow().iter().skip(1) {
+      for (param, first) in declaration.type_params.iter().zip(&interface.type_params) {
+        let arg = self.type_scopes.get_on_scope(scope, first.symbol_id).unwrap_or(Ty::Error);
+        self.type_scopes.insert_on_scope(scope, param.symbol_id, arg);
       }
+    }
+    self.type_scopes.set_parent(scope, interface.scope);
+    let instance = &*self.allocator.alloc(InterfaceType {
+      name: interface.name,
+      scope,
+      type_params: vec![],
+      type_args: args.clone(),
+      origin: Some(interface),
+      declarations: Default::default(),
+      state: Cell::new(InterfaceState::Unresolved),
+      inner: Default::default(),
+      instances: Default::default(),
+    });
+    interface.instances.borrow_mut().insert(args.clone(), instance);
+    Ty::Interface(instance)
+  }
 
-      Ty::Unresolved(u) => {
-        self.unresolved_extends.push(u);
+  fn resolve_interface(&mut self, interface: &'a InterfaceType<'a>) {
+    if interface.state.get() != InterfaceState::Unresolved {
+      return;
+    }
+    interface.state.set(InterfaceState::Resolving);
+
+    let old_top = self.type_scopes.replace_top(interface.scope);
+    let inner = self.resolve_interface_members(interface);
+    self.type_scopes.replace_top(old_top);
+
+    *interface.inner.borrow_mut() = inner;
+    interface.state.set(InterfaceState::Resolved);
+  }
+
+  fn resolve_interface_members(
+    &mut self,
+    interface: &'a InterfaceType<'a>,
+  ) -> InterfaceTypeInner<'a> {
+    let nodes = interface.declaration_nodes();
+
+    let mut inherited = RecordType::default();
+    let mut inherited_callables = Vec::new();
+    let mut bases: Vec<(Ty<'a>, RecordType<'a>)> = Vec::new();
+    for heritage in nodes.iter().flat_map(|node| node.extends.iter().flatten()) {
+      let Some(base) = self.resolve_interface_heritage(heritage) else {
+        continue;
+      };
+      let mut record = RecordType::default();
+      if !self.collect_interface_base(interface, base, &mut record, &mut inherited_callables) {
+        self.report(Diagnostic::error(
+          DiagnosticCode::InvalidInterfaceBase,
+          "An interface can only extend an object type or intersection of object types with statically known members.",
+          heritage.span,
+        ));
+        continue;
       }
+      for (prev_base, prev_record) in &bases {
+        self.check_conflicting_bases(interface, (*prev_base, prev_record), (base, &record));
+      }
+      inherited.inherit(&record);
+      bases.push((base, record));
+    }
+
+    // Later declarations come first, so that their overloads take precedence
+    let mut callables = Vec::new();
+    let mut record = None;
+    for node in nodes.iter().rev() {
+      self.resolve_signature_vec_into(&node.body.body, &mut record, &mut callables);
+    }
+    let mut record = record.map(RecordTypeBuilder::build).unwrap_or_default();
+    for (base, base_record) in &bases {
+      self.check_interface_extends(interface, &record, *base, base_record);
+    }
+    record.inherit(&inherited);
+    callables.extend(inherited_callables);
 
+    InterfaceTypeInner { record, callables }
+  }
+
+  fn resolve_interface_heritage(
+    &mut self,
+    heritage: &'a TSInterfaceHeritage<'a>,
+  ) -> Option<Ty<'a>> {
+    let base = match &heritage.expression {
+      Expression::Identifier(id) => self.resolve_type_identifier_reference(id),
+      Expression::StaticMemberExpression(member) => {
+        match self.resolve_namespace_expression(&member.object) {
+          Some(namespace) => namespace.get_type(&member.property.name).unwrap_or(Ty::Error),
+          None => self.unsupported("qualified names"),
+        }
+      }
       _ => {
-        // Should error
+        self.report(Diagnostic::error(
+          DiagnosticCode::InvalidInterfaceHeritage,
+          "An interface can only extend an identifier/qualified-name with optional type arguments.",
+          heritage.span,
+        ));
+        return None;
+      }
+    };
+    Some(if let Some(type_parameters) = &heritage.type_parameters {
+      let args = self.resolve_type_parameter_instantiation(type_parameters);
+      self.create_generic_instance(base, args)
+    } else {
+      base
+    })
+  }
+
+  fn resolve_namespace_expression(
+    &mut self,
+    node: &'a Expression<'a>,
+  ) -> Option<&'a NamespaceType<'a>> {
+    match node {
+      Expression::Identifier(id) => {
+        let reference = self.semantic.symbols().get_reference(id.reference_id());
+        reference.symbol_id().and_then(|symbol| self.namespaces.get(&symbol).copied())
       }
+      Expression::StaticMemberExpression(member) => {
+        let parent = self.resolve_namespace_expression(&member.object)?;
+        match parent.values.borrow().get(member.property.name.as_str()) {
+          Some(Ty::Namespace(namespace)) => Some(*namespace),
+          _ => None,
+        }
+      }
+      _ => None,
     }
   }
-}
 
-impl<'a> InterfaceType<'a> {
-  pub fn get_property(&self, key: PropertyKeyType<'a>) -> Ty<'a> {
-    let inner = self.0.borrow();
-    inner.record.get_property(key)
+  /// Returns `false` if the base is not an object type.
+  fn collect_interface_base(
+    &mut self,
+    interface: &'a InterfaceType<'a>,
+    base: Ty<'a>,
+    record: &mut RecordType<'a>,
+    callables: &mut Vec<Ty<'a>>,
+  ) -> bool {
+    match base {
+      Ty::Error | Ty::Any => true,
+      Ty::Record(r) => {
+        record.inherit(r);
+        true
+      }
+      Ty::Function(_) | Ty::Constructor(_) => {
+        callables.push(base);
+        true
+      }
+      Ty::Interface(i) => {
+        if i.state.get() == InterfaceState::Resolving {
+          self.report(Diagnostic::error(
+            DiagnosticCode::RecursiveBaseType,
+            format!("Type '{}' recursively references itself as a base type.", interface.name),
+            interface.span(),
+          ));
+          return true;
+        }
+        let inner = self.get_interface_members(i);
+        record.inherit(&inner.record);
+        callables.extend(inner.callables.iter().copied());
+        true
+      }
+      Ty::Intersection(i) if i.kind == IntersectionBaseKind::NoBase && i.unresolved.is_empty() => {
+        let mut valid = true;
+        i.for_each(|ty| valid &= self.collect_interface_base(interface, ty, record, callables));
+        valid
+      }
+      Ty::Instance(i) => {
+        let unwrapped = self.unwrap_generic_instance(i);
+        self.collect_interface_base(interface, unwrapped, record, callables)
+      }
+      _ => false,
+    }
   }
 
-  pub fn is_empty(&self) -> bool {
-    let inner = self.0.borrow();
-    inner.record.is_empty() && inner.callables.is_empty() && inner.unresolved_extends.is_empty()
+  /// Properties with the same name should be identical in all the bases.
+  fn check_conflicting_bases(
+    &mut self,
+    interface: &'a InterfaceType<'a>,
+    (base1, record1): (Ty<'a>, &RecordType<'a>),
+    (base2, record2): (Ty<'a>, &RecordType<'a>),
+  ) {
+    for (key, property2) in &record2.string_keyed.0 {
+      let Some(property1) = record1.string_keyed.0.get(key) else {
+        continue;
+      };
+      let (ty1, ty2) = (property1.value, property2.value);
+      if ty1 != ty2
+        && (matches!(self.match_covariant_types(1, ty1, ty2), MatchResult::Unmatched)
+          || matches!(self.match_covariant_types(1, ty2, ty1), MatchResult::Unmatched))
+      {
+        let base1 = self.print_type(base1);
+        let base2 = self.print_type(base2);
+        self.report(
+          Diagnostic::error(
+            DiagnosticCode::ConflictingInterfaceBases,
+            format!(
+              "Interface '{}' cannot simultaneously extend types '{base1}' and '{base2}'.",
+              interface.name
+            ),
+            interface.span(),
+          )
+          .with_label(
+            interface.span(),
+            format!("Named property '{key}' of types '{base1}' and '{base2}' are not identical."),
+          ),
+        );
+        return;
+      }
+    }
   }
-}
 
-impl<'a> Analyzer<'a> {
+  /// Own properties should be assignable to the inherited ones. Only enabled with
+  /// `Config::check_assignability`.
+  fn check_interface_extends(
+    &mut self,
+    interface: &'a InterfaceType<'a>,
+    own: &RecordType<'a>,
+    base: Ty<'a>,
+    base_record: &RecordType<'a>,
+  ) {
+    if !self.config.check_assignability {
+      return;
+    }
+    for (key, property) in &own.string_keyed.0 {
+      let Some(inherited) = base_record.string_keyed.0.get(key) else {
+        continue;
+      };
+      if matches!(
+        self.match_covariant_types(1, property.value, inherited.value),
+        MatchResult::Unmatched
+      ) {
+        let kind = if matches!(base, Ty::Interface(_)) { "interface" } else { "type" };
+        let base = self.print_type(base);
+        self.report(
+          Diagnostic::error(
+            DiagnosticCode::IncorrectlyExtends,
+            format!("Interface '{}' incorrectly extends {kind} '{base}'.", interface.name),
+            interface.span(),
+          )
+          .with_label(interface.span(), format!("Types of property '{key}' are incompatible.")),
+        );
+        return;
+      }
+    }
+  }
+
+  /// Prints the interface by its name.
   pub fn serialize_interface_type(&mut self, interface: &InterfaceType<'a>) -> TSType<'a> {
-    todo!()
+    let name = TSTypeName::IdentifierReference(
+      self.ast_builder.alloc(self.ast_builder.identifier_reference(SPAN, interface.name.as_str())),
+    );
+    if interface.type_args.is_empty() {
+      self.ast_builder.ts_type_type_reference(SPAN, name, NONE)
+    } else {
+      let mut params = self.ast_builder.vec();
+      for arg in &interface.type_args {
+        params.push(self.serialize_type(*arg));
+      }
+      let type_args = self.ast_builder.alloc_ts_type_parameter_instantiation(SPAN, params);
+      self.ast_builder.ts_type_type_reference(SPAN, name, Some(type_args))
+    }
+  }
+
+  /// Prints the members of the interface as a type literal, with the call and construct signatures
+  /// intersected.
+  pub fn serialize_interface_literal(&mut self, interface: &'a InterfaceType<'a>) -> TSType<'a> {
+    let (record, callables) = {
+      let inner = self.get_interface_members(interface);
+      (inner.record.clone(), inner.callables.clone())
+    };
+    let literal = self.serialize_record_type(&record);
+    if callables.is_empty() {
+      return literal;
+    }
+    let mut types = self.ast_builder.vec();
+    for callable in callables {
+      types.push(self.serialize_type(callable));
+    }
+    if !record.is_empty() {
+      types.push(literal);
+    }
+    self.ast_builder.ts_type_intersection_type(SPAN, types)
   }
 }
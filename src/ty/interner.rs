@@ -0,0 +1,80 @@
+use std::hash::{Hash, Hasher};
+
+use oxc::allocator::Allocator;
+use rustc_hash::{FxHashSet, FxHasher};
+
+use super::{
+  callable::{ConstructorType, FunctionType},
+  intersection::IntersectionType,
+  record::RecordType,
+  tuple::TupleType,
+  union::UnionType,
+  Ty,
+};
+use crate::analyzer::Analyzer;
+
+/// Hash-consing storage of structural types. Structurally equal types share one allocation, so
+/// that they are also equal by pointer, which is how `Ty` compares compound types.
+///
+/// Interfaces, instances and enum members are not interned, because they have identity.
+#[derive(Debug, Default)]
+pub struct TypeInterner<'a> {
+  records: FxHashSet<&'a RecordType<'a>>,
+  tuples: FxHashSet<&'a TupleType<'a>>,
+  functions: FxHashSet<&'a FunctionType<'a>>,
+  constructors: FxHashSet<&'a ConstructorType<'a>>,
+  unions: FxHashSet<&'a UnionType<'a>>,
+  intersections: FxHashSet<&'a IntersectionType<'a>>,
+}
+
+fn intern<'a, T: Hash + Eq>(
+  set: &mut FxHashSet<&'a T>,
+  allocator: &'a Allocator,
+  value: T,
+) -> &'a T {
+  if let Some(interned) = set.get(&value) {
+    interned
+  } else {
+    let allocated = &*allocator.alloc(value);
+    set.insert(allocated);
+    allocated
+  }
+}
+
+/// Hashes the items regardless of their order, for hash sets and hash maps.
+pub fn hash_unordered<T: Hash, H: Hasher>(items: impl ExactSizeIterator<Item = T>, state: &mut H) {
+  state.write_usize(items.len());
+  let mut sum = 0u64;
+  for item in items {
+    let mut hasher = FxHasher::default();
+    item.hash(&mut hasher);
+    sum = sum.wrapping_add(hasher.finish());
+  }
+  state.write_u64(sum);
+}
+
+impl<'a> Analyzer<'a> {
+  pub fn intern_record(&mut self, record: RecordType<'a>) -> Ty<'a> {
+    Ty::Record(intern(&mut self.interner.records, self.allocator, record))
+  }
+
+  pub fn intern_tuple(&mut self, tuple: TupleType<'a>) -> Ty<'a> {
+    Ty::Tuple(intern(&mut self.interner.tuples, self.allocator, tuple))
+  }
+
+  pub fn intern_function(&mut self, function: FunctionType<'a>) -> Ty<'a> {
+    Ty::Function(intern(&mut self.interner.functions, self.allocator, function))
+  }
+
+  pub fn intern_constructor(&mut self, constructor: ConstructorType<'a>) -> Ty<'a> {
+    Ty::Constructor(intern(&mut self.interner.constructors, self.allocator, constructor))
+  }
+
+  pub fn intern_union(&mut self, union: UnionType<'a>) -> Ty<'a> {
+    Ty::Union(intern(&mut self.interner.unions, self.allocator, union))
+  }
+
+  pub fn intern_intersection(&mut self, intersection: IntersectionType<'a>) -> Ty<'a> {
+    Ty::Intersection(intern(&mut self.interner.intersections, self.allocator, intersection))
+  }
+}
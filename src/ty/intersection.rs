@@ -1,11 +1,13 @@
 use oxc::{
-  allocator::Allocator,
   ast::ast::TSType,
   semantic::SymbolId,
   span::{Atom, SPAN},
 };
 
-use super::{unresolved::UnresolvedType, Ty};
+use super::{
+  property_key::PropertyKeyType, r#enum::EnumMemberType, record::RecordPropertyValue,
+  unresolved::UnresolvedType, Ty,
+};
 use crate::{analyzer::Analyzer, utils::F64WithEq};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
@@ -70,6 +72,8 @@ impl<'a> IntersectionBuilderState<'a> {
 #[derive(Debug, Default)]
 pub struct IntersectionTypeBuilder<'a> {
   kind: IntersectionBuilderState<'a>,
+  /// The enum member whose value is `kind`, e.g. `E.A` in `E.A & { tag: 1 }`
+  enum_member: Option<&'a EnumMemberType<'a>>,
   object_like: Vec<Ty<'a>>,
   unresolved: Vec<UnresolvedType<'a>>,
 
@@ -104,6 +108,15 @@ impl<'a> IntersectionTypeBuilder<'a> {
         Ty::BigIntLiteral(n) => IntersectionBuilderState::BigInt(Some(n)),
         Ty::BooleanLiteral(b) => IntersectionBuilderState::Boolean(Some(b)),
         Ty::UniqueSymbol(s) => IntersectionBuilderState::Symbol(Some(s)),
+        Ty::EnumMember(member) => match self.enum_member {
+          // Distinct enum members never overlap, even if their values are equal
+          Some(existing) if existing != member => IntersectionBuilderState::Never,
+          _ => {
+            self.enum_member = Some(member);
+            self.add(analyzer, member.value);
+            return;
+          }
+        },
 
         Ty::Record(_) | Ty::Interface(_) | Ty::Tuple(_) | Ty::Function(_) | Ty::Constructor(_) => {
           self.object_like.push(ty);
@@ -113,8 +126,11 @@ impl<'a> IntersectionTypeBuilder<'a> {
         Ty::Union(u) => {
           let mut union = Vec::new();
           u.for_each(|ty| {
-            let mut builder = IntersectionTypeBuilder::default();
-            builder.kind = self.kind;
+            let mut builder = IntersectionTypeBuilder {
+              kind: self.kind,
+              enum_member: self.enum_member,
+              ..Default::default()
+            };
             builder.add(analyzer, ty);
             if builder.kind != IntersectionBuilderState::Never {
               union.push(builder);
@@ -149,12 +165,28 @@ impl<'a> IntersectionTypeBuilder<'a> {
   }
 
   fn build_without_union(
-    allocator: &'a Allocator,
+    analyzer: &mut Analyzer<'a>,
     kind: IntersectionBuilderState<'a>,
+    enum_member: Option<&'a EnumMemberType<'a>>,
     object_like: Vec<Ty<'a>>,
     unresolved: Vec<UnresolvedType<'a>>,
   ) -> Ty<'a> {
     let primitive_only = object_like.is_empty();
+    // The enum member is kept if the intersection is still its value, e.g. `E.A & string` is `E.A`
+    let enum_member = enum_member.filter(|member| {
+      matches!(
+        (kind, member.value),
+        (IntersectionBuilderState::String(Some(_)), Ty::StringLiteral(_))
+          | (IntersectionBuilderState::Number(Some(_)), Ty::NumericLiteral(_))
+      )
+    });
+    if let Some(member) = enum_member {
+      if primitive_only {
+        return Ty::EnumMember(member);
+      }
+      let kind = IntersectionBaseKind::EnumMember(member);
+      return analyzer.intern_intersection(IntersectionType { kind, object_like, unresolved });
+    }
     let kind = match kind {
       // Ignore complex types
       IntersectionBuilderState::Error => return Ty::Error,
@@ -195,13 +227,12 @@ impl<'a> IntersectionTypeBuilder<'a> {
         }
       }
     };
-    Ty::Intersection(allocator.alloc(IntersectionType { kind, object_like, unresolved }))
+    analyzer.intern_intersection(IntersectionType { kind, object_like, unresolved })
   }
 
   pub fn build(self, analyzer: &mut Analyzer<'a>) -> Ty<'a> {
-    let allocator = analyzer.allocator;
-    let Self { kind, object_like, unresolved, union } = self;
-    let base = Self::build_without_union(allocator, kind, object_like, unresolved);
+    let Self { kind, enum_member, object_like, unresolved, union } = self;
+    let base = Self::build_without_union(analyzer, kind, enum_member, object_like, unresolved);
     if base == Ty::Never {
       return Ty::Never;
     }
@@ -220,9 +251,10 @@ impl<'a> IntersectionTypeBuilder<'a> {
   }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IntersectionBaseKind<'a> {
   NoBase,
+  EnumMember(&'a EnumMemberType<'a>),
   Number(Option<F64WithEq>),
   String(Option<&'a Atom<'a>>),
   Boolean(Option<bool>),
@@ -232,7 +264,7 @@ pub enum IntersectionBaseKind<'a> {
   Void,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IntersectionType<'a> {
   pub kind: IntersectionBaseKind<'a>,
   /// non empty
@@ -244,6 +276,7 @@ impl<'a> IntersectionType<'a> {
   pub fn kind_to_ty(&self) -> Option<Ty<'a>> {
     match self.kind {
       IntersectionBaseKind::NoBase => None,
+      IntersectionBaseKind::EnumMember(member) => Some(Ty::EnumMember(member)),
       IntersectionBaseKind::Number(Some(n)) => Some(Ty::NumericLiteral(n)),
       IntersectionBaseKind::Number(None) => Some(Ty::Number),
       IntersectionBaseKind::String(Some(s)) => Some(Ty::StringLiteral(s)),
@@ -259,7 +292,7 @@ impl<'a> IntersectionType<'a> {
     }
   }
 
-  pub fn for_each(&self, mut f: impl FnMut(Ty<'a>) -> ()) {
+  pub fn for_each(&self, mut f: impl FnMut(Ty<'a>)) {
     self.kind_to_ty().map(&mut f);
     self.object_like.iter().copied().for_each(&mut f);
     self.unresolved.iter().copied().map(Ty::Unresolved).for_each(f);
@@ -310,6 +343,42 @@ impl<'a> Analyzer<'a> {
     }
   }
 
+  /// The property types of all the object types declaring the property are intersected. It is
+  /// optional or readonly only if it is so in all of them. The primitive of a branded primitive,
+  /// e.g. `string & { __brand: "Id" }`, is only looked up for the other properties, because the
+  /// brand does not exist at runtime.
+  pub fn get_intersection_property_value(
+    &mut self,
+    intersection: &IntersectionType<'a>,
+    key: PropertyKeyType<'a>,
+  ) -> Option<RecordPropertyValue<'a>> {
+    let mut types = vec![];
+    let mut optional = true;
+    let mut readonly = true;
+    let unresolved = intersection.unresolved.iter().copied().map(Ty::Unresolved);
+    for ty in intersection.object_like.iter().copied().chain(unresolved) {
+      if let Some(property) = self.get_property_value(ty, key) {
+        types.push(property.value);
+        optional &= property.optional;
+        readonly &= property.readonly;
+      }
+    }
+    if types.is_empty() {
+      let primitive = intersection.kind_to_ty()?;
+      self.get_property_value(primitive, key)
+    } else {
+      Some(RecordPropertyValue { value: self.into_intersection(types), optional, readonly })
+    }
+  }
+
+  pub fn get_intersection_property(
+    &mut self,
+    intersection: &IntersectionType<'a>,
+    key: PropertyKeyType<'a>,
+  ) -> Ty<'a> {
+    self.get_intersection_property_value(intersection, key).map_or(Ty::Error, |p| p.value)
+  }
+
   pub fn serialize_intersection_type(&mut self, intersection: &IntersectionType<'a>) -> TSType<'a> {
     let mut types = self.ast_builder.vec();
     intersection.for_each(|ty| types.push(self.serialize_type(ty)));
@@ -4,12 +4,13 @@ use crate::analyzer::Analyzer;
 
 #[derive(Debug, Clone, Copy)]
 pub struct IntrinsicType {
-  name: &'static str,
-  handler: fn(&str) -> String,
+  pub name: &'static str,
+  pub handler: fn(&str) -> String,
 }
 
 impl<'a> Analyzer<'a> {
-  pub fn serialize_intrinsic_type(&mut self, intrinsic: &IntrinsicType) -> TSType<'a> {
-    todo!()
+  pub fn serialize_intrinsic_type(&mut self, _intrinsic: &IntrinsicType) -> TSType<'a> {
+    let ty = self.unsupported("printing intrinsic types");
+    self.serialize_type(ty)
   }
 }
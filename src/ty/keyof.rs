@@ -0,0 +1,65 @@
+use oxc::span::Atom;
+use oxc_syntax::number::ToJsString;
+
+use super::{record::RecordType, unresolved::UnresolvedType, Ty};
+use crate::analyzer::Analyzer;
+
+impl<'a> Analyzer<'a> {
+  /// `keyof T`, which is deferred while `T` is not resolved.
+  pub fn get_keyof_type(&mut self, ty: Ty<'a>) -> Ty<'a> {
+    match ty {
+      Ty::Error => Ty::Error,
+      Ty::Any | Ty::Never => self.into_union([Ty::String, Ty::Number, Ty::Symbol]).unwrap(),
+      Ty::Unknown | Ty::Void | Ty::Null | Ty::Undefined => Ty::Never,
+
+      Ty::Record(record) => self.get_record_keys(record),
+      Ty::Interface(interface) => {
+        let members = self.get_interface_members(interface);
+        self.get_record_keys(&members.record)
+      }
+
+      // Only the keys which exist in every member
+      Ty::Union(union) => {
+        let mut keys = vec![];
+        union.for_each(|ty| keys.push(self.get_keyof_type(ty)));
+        self.into_intersection(keys)
+      }
+      Ty::Intersection(intersection) => {
+        let mut keys = vec![];
+        intersection.for_each(|ty| keys.push(self.get_keyof_type(ty)));
+        self.into_union(keys).unwrap()
+      }
+      Ty::Instance(instance) => {
+        let unwrapped = self.unwrap_generic_instance(instance);
+        self.get_keyof_type(unwrapped)
+      }
+
+      _ => Ty::Unresolved(UnresolvedType::Keyof(self.allocator.alloc(ty))),
+    }
+  }
+
+  /// Index signatures give `string | number`, `number` or `symbol`. Template pattern index
+  /// signatures are widened to `string`, until template literal types are supported.
+  fn get_record_keys(&mut self, record: &RecordType<'a>) -> Ty<'a> {
+    let mut keys = vec![];
+    for key in record.string_keyed.0.keys() {
+      keys.push(match key.parse::<f64>() {
+        Ok(n) if n.is_finite() && n.to_js_string() == *key => Ty::NumericLiteral(n.into()),
+        _ => Ty::StringLiteral(self.allocator.alloc(Atom::from(*key))),
+      });
+    }
+    keys.extend(record.symbol_keyed.0.keys().map(|symbol| Ty::UniqueSymbol(*symbol)));
+    if record.string_mapped.is_some() {
+      keys.extend([Ty::String, Ty::Number]);
+    } else if record.number_mapped.is_some() {
+      keys.push(Ty::Number);
+    }
+    if record.symbol_mapped.is_some() {
+      keys.push(Ty::Symbol);
+    }
+    if !record.pattern_mapped.is_empty() {
+      keys.push(Ty::String);
+    }
+    self.into_union(keys).unwrap_or(Ty::Never)
+  }
+}
@@ -8,27 +8,35 @@ impl<'a> Analyzer<'a> {
   pub fn get_lowest_type(&mut self, ty: Ty<'a>) -> Ty<'a> {
     match ty {
       Ty::Instance(i) => self.unwrap_generic_instance(i),
-      Ty::Generic(g) => todo!(),
-      Ty::Intrinsic(i) => todo!(),
+      // Written without type arguments, so the defaults apply
+      Ty::Generic(_) => match self.create_generic_instance(ty, vec![]) {
+        Ty::Instance(i) => self.unwrap_generic_instance(i),
+        ty => ty,
+      },
+      Ty::Intrinsic(_) => Ty::Error,
       Ty::Namespace(_) => Ty::Error,
 
       Ty::Unresolved(unresolved) => match unresolved {
-        UnresolvedType::UnInitVariable(_) => Ty::Unknown,
+        UnresolvedType::UnInitVariable(symbol) => match self.read_variable(symbol) {
+          Ty::Unresolved(UnresolvedType::UnInitVariable(s)) if s == symbol => Ty::Unknown,
+          ty => ty,
+        },
         UnresolvedType::UnInitType(symbol) => match self.type_scopes.search(symbol) {
           Ty::Unresolved(UnresolvedType::UnInitType(s)) if s == symbol => Ty::Unknown,
           ty => ty,
         },
+        // The constraint may refer to other type parameters, which are unwrapped by the caller
         UnresolvedType::GenericParam(symbol) => {
-          // if let Some(constraint) =
-          //   self.type_scopes.get_on_scope(self.type_scopes.generic_constraints, symbol)
-          // {
-          //   self.resolve_ctx_ty(self.type_scopes.generic_constraints, *constraint)
-          // } else {
-          //   Ty::Unknown
-          // }
-          todo!()
+          match self.generic_constraints.get(&symbol).copied() {
+            Some(constraint) => self.resolve_ctx_ty(self.type_scopes.empty_scope, constraint),
+            None => Ty::Unknown,
+          }
         }
         UnresolvedType::Keyof(_) => Ty::String,
+        // Either of the branches
+        UnresolvedType::Conditional(conditional) => {
+          self.into_union([conditional.true_type, conditional.false_type]).unwrap()
+        }
         UnresolvedType::InferType(_) => Ty::Unknown,
         UnresolvedType::Placeholder(_) => unreachable!(),
       },
@@ -3,7 +3,13 @@ use std::{collections::hash_map::Entry, hash::Hash};
 use oxc::semantic::SymbolId;
 use rustc_hash::FxHashMap;
 
-use super::{callable::CallableType, record::KeyedPropertyMap, unresolved::UnresolvedType, Ty};
+use super::{
+  callable::CallableType,
+  record::{KeyedPropertyMap, RecordType},
+  tuple::TupleType,
+  unresolved::UnresolvedType,
+  Ty,
+};
 use crate::Analyzer;
 
 pub enum MatchResult<'a> {
@@ -72,13 +78,29 @@ impl<'a> Analyzer<'a> {
       (_, Ty::Error | Ty::Any | Ty::Unknown) => MatchResult::Matched,
       (Ty::Unknown, _) => MatchResult::Unmatched,
 
+      // References to recursive type aliases, which are resolved by now
+      (Ty::Unresolved(UnresolvedType::UnInitType(_)), _) => {
+        let target = self.get_lowest_type(target);
+        self.match_covariant_types(specificity, target, pattern)
+      }
+      (_, Ty::Unresolved(UnresolvedType::UnInitType(_))) => {
+        let pattern = self.get_lowest_type(pattern);
+        self.match_covariant_types(specificity, target, pattern)
+      }
+
       (Ty::Unresolved(target), Ty::Unresolved(pattern)) => match (target, pattern) {
         (UnresolvedType::Placeholder(_), _) | (_, UnresolvedType::Placeholder(_)) => {
           MatchResult::Unmatched
         }
-        _ => todo!(),
+        _ => {
+          self.unsupported("matching unresolved types");
+          MatchResult::Error
+        }
       },
-      (Ty::Unresolved(_), _) | (_, Ty::Unresolved(_)) => todo!(),
+      (Ty::Unresolved(_), _) | (_, Ty::Unresolved(_)) => {
+        self.unsupported("matching unresolved types");
+        MatchResult::Error
+      }
 
       (Ty::Union(target), pattern) => {
         let mut error = false;
@@ -213,7 +235,7 @@ impl<'a> Analyzer<'a> {
       }
 
       (Ty::Record(target), Ty::Record(pattern)) => {
-        let mut builder = BuilderBySpecificity::default();
+        let mut builder = BuilderForAll::default();
 
         self.match_record_keyed_properties(
           &mut builder,
@@ -232,16 +254,24 @@ impl<'a> Analyzer<'a> {
 
         builder.into_result()
       }
+      (
+        Ty::Record(_) | Ty::Interface(_) | Ty::Function(_) | Ty::Constructor(_),
+        Ty::Interface(_),
+      )
+      | (Ty::Interface(_), Ty::Record(_)) => {
+        self.match_interface_types(specificity, target, pattern)
+      }
       (Ty::Object, Ty::Record(pattern)) => MatchResult::from(pattern.is_empty()),
       (Ty::Record(_), Ty::Object) => MatchResult::Matched,
-      (_, Ty::Record(_)) | (Ty::Record(_), _) => MatchResult::Unmatched,
-
-      (Ty::Interface(target), Ty::Interface(pattern)) => todo!(),
-      (Ty::Object, Ty::Interface(pattern)) => MatchResult::from(pattern.is_empty()),
+      (Ty::Object, Ty::Interface(pattern)) => MatchResult::from(self.is_empty_interface(pattern)),
       (Ty::Interface(_), Ty::Object) => MatchResult::Matched,
-      (_, Ty::Interface(_)) | (Ty::Interface(_), _) => MatchResult::Unmatched,
+      (_, Ty::Record(_) | Ty::Interface(_)) | (Ty::Record(_) | Ty::Interface(_), _) => {
+        MatchResult::Unmatched
+      }
 
-      (Ty::Tuple(target), Ty::Tuple(pattern)) => todo!(),
+      (Ty::Tuple(target), Ty::Tuple(pattern)) => {
+        self.match_tuple_types(specificity, target, pattern)
+      }
       (_, Ty::Tuple(_)) | (Ty::Tuple(_), _) => MatchResult::Unmatched,
 
       (Ty::Function(target), Ty::Function(pattern)) => {
@@ -256,6 +286,12 @@ impl<'a> Analyzer<'a> {
       (Ty::Constructor(_), Ty::Object) => MatchResult::Matched,
       (Ty::Constructor(_), _) | (_, Ty::Constructor(_)) => MatchResult::Unmatched,
 
+      // Enum members are subtypes of their values, e.g. `E.A` of `0` and `number`
+      (Ty::EnumMember(target), pattern) => {
+        self.match_covariant_types(specificity, target.value, pattern)
+      }
+      (_, Ty::EnumMember(_)) => MatchResult::Unmatched,
+
       (Ty::Undefined, Ty::Void) => MatchResult::Matched,
       (Ty::Undefined, _) => MatchResult::Unmatched,
       (_, Ty::Void) => MatchResult::Unmatched,
@@ -402,18 +438,104 @@ impl<'a> Analyzer<'a> {
     MatchResult::Inferred(inferred)
   }
 
+  /// Interfaces are matched structurally. For recursive interfaces, the pairs being matched are
+  /// assumed to match when visited again.
+  fn match_interface_types(
+    &mut self,
+    specificity: i32,
+    target: Ty<'a>,
+    pattern: Ty<'a>,
+  ) -> MatchResult<'a> {
+    if self.matching_interfaces.contains(&(target, pattern)) {
+      return MatchResult::Matched;
+    }
+    let (target_record, target_callables) = self.get_object_members(target);
+    let (pattern_record, pattern_callables) = self.get_object_members(pattern);
+
+    self.matching_interfaces.push((target, pattern));
+    let mut builder = BuilderForAll::default();
+    self.match_record_keyed_properties(
+      &mut builder,
+      specificity + 1,
+      &target_record.string_keyed,
+      &pattern_record.string_keyed,
+    );
+    self.match_record_keyed_properties(
+      &mut builder,
+      specificity + 1,
+      &target_record.symbol_keyed,
+      &pattern_record.symbol_keyed,
+    );
+    // Each signature of the pattern should be matched by one of the target
+    for pattern in pattern_callables {
+      let mut signature_builder = BuilderBySpecificity::default();
+      for target in &target_callables {
+        signature_builder.add(self.match_covariant_types(specificity, *target, pattern));
+      }
+      builder.add(signature_builder.into_result());
+    }
+    self.matching_interfaces.pop();
+
+    builder.into_result()
+  }
+
+  /// Tuples are matched element by element. Variadic tuples are not supported yet.
+  fn match_tuple_types(
+    &mut self,
+    specificity: i32,
+    target: &'a TupleType<'a>,
+    pattern: &'a TupleType<'a>,
+  ) -> MatchResult<'a> {
+    let is_variadic = |tuple: &TupleType| tuple.elements.iter().any(|element| element.spread);
+    if is_variadic(target) || is_variadic(pattern) {
+      self.unsupported("matching variadic tuples");
+      return MatchResult::Error;
+    }
+    if (target.readonly && !pattern.readonly) || target.elements.len() > pattern.elements.len() {
+      return MatchResult::Unmatched;
+    }
+
+    let mut builder = BuilderForAll::default();
+    for (index, pattern) in pattern.elements.iter().enumerate() {
+      match target.elements.get(index) {
+        Some(target) if target.optional && !pattern.optional => {
+          builder.add(MatchResult::Unmatched);
+        }
+        Some(target) => {
+          let result = self.match_covariant_types(specificity + 1, target.ty, pattern.ty);
+          builder.add(result);
+        }
+        None if pattern.optional => {}
+        None => builder.add(MatchResult::Unmatched),
+      }
+    }
+    builder.into_result()
+  }
+
+  fn get_object_members(&mut self, ty: Ty<'a>) -> (RecordType<'a>, Vec<Ty<'a>>) {
+    match ty {
+      Ty::Record(record) => (record.clone(), vec![]),
+      Ty::Interface(interface) => {
+        let members = self.get_interface_members(interface);
+        (members.record.clone(), members.callables.clone())
+      }
+      _ => (RecordType::default(), vec![ty]),
+    }
+  }
+
+  /// Every property of the pattern should be present in the target, unless it is optional.
   fn match_record_keyed_properties<K: Eq + Hash>(
     &mut self,
-    builder: &mut BuilderBySpecificity<'a>,
+    builder: &mut BuilderForAll<'a>,
     specificity: i32,
     target: &KeyedPropertyMap<'a, K>,
     pattern: &KeyedPropertyMap<'a, K>,
   ) {
-    for (key, target) in &target.0 {
-      if let Some(pattern) = pattern.0.get(key) {
+    for (key, pattern) in &pattern.0 {
+      if let Some(target) = target.0.get(key) {
         let result = self.match_covariant_types(specificity, target.value, pattern.value);
         builder.add(result);
-      } else {
+      } else if !pattern.optional {
         builder.add(MatchResult::Unmatched);
       }
     }
@@ -466,3 +588,29 @@ impl<'a> BuilderBySpecificity<'a> {
     }
   }
 }
+
+/// Combines the results which should all match, e.g. of the properties of a record.
+#[derive(Debug, Default)]
+struct BuilderForAll<'a> {
+  unmatched: bool,
+  inner: BuilderBySpecificity<'a>,
+}
+
+impl<'a> BuilderForAll<'a> {
+  fn add(&mut self, result: MatchResult<'a>) {
+    match result {
+      MatchResult::Unmatched => self.unmatched = true,
+      result => self.inner.add(result),
+    }
+  }
+
+  fn into_result(self) -> MatchResult<'a> {
+    if self.inner.error {
+      MatchResult::Error
+    } else if self.unmatched {
+      MatchResult::Unmatched
+    } else {
+      MatchResult::Inferred(self.inner.inferred)
+    }
+  }
+}
@@ -1,15 +1,22 @@
 pub mod accumulator;
+pub mod assignable;
 pub mod callable;
+pub mod conditional;
 pub mod ctx;
+pub mod r#enum;
 pub mod facts;
 pub mod generic;
 pub mod get_property;
+pub mod inference;
 pub mod interface;
+pub mod interner;
 pub mod intersection;
 pub mod intrinsics;
+pub mod keyof;
 pub mod lowest;
 pub mod r#match;
 pub mod namespace;
+pub mod narrow;
 pub mod operations;
 pub mod print;
 pub mod property_key;
@@ -19,9 +26,9 @@ pub mod union;
 pub mod unresolved;
 pub mod widen;
 
-use std::{hash, mem};
+use std::{hash, mem, ptr};
 
-use callable::{ConstructorType, FunctionType};
+use callable::{ConstructorType, ExtractedCallable, FunctionType};
 use generic::{GenericInstanceType, GenericType};
 use interface::InterfaceType;
 use intersection::IntersectionType;
@@ -29,6 +36,7 @@ use intrinsics::IntrinsicType;
 use namespace::NamespaceType;
 use oxc::{semantic::SymbolId, span::Atom};
 use property_key::PropertyKeyType;
+use r#enum::EnumMemberType;
 use record::RecordType;
 use tuple::TupleType;
 use union::UnionType;
@@ -36,6 +44,9 @@ use unresolved::UnresolvedType;
 
 use crate::{analyzer::Analyzer, utils::F64WithEq};
 
+/// Thenables unwrapped within each other by `await`, before giving up.
+const MAX_AWAITED_DEPTH: usize = 50;
+
 #[derive(Debug, Clone, Copy)]
 pub enum Ty<'a> {
   Error,
@@ -62,6 +73,7 @@ pub enum Ty<'a> {
   BigIntLiteral(&'a Atom<'a>),
   BooleanLiteral(bool),
   UniqueSymbol(SymbolId),
+  EnumMember(&'a EnumMemberType<'a>),
 
   /* Object like */
   Record(&'a RecordType<'a>),
@@ -106,16 +118,18 @@ impl<'a> PartialEq for Ty<'a> {
       (Ty::BigIntLiteral(a), Ty::BigIntLiteral(b)) => a == b,
       (Ty::BooleanLiteral(a), Ty::BooleanLiteral(b)) => a == b,
       (Ty::UniqueSymbol(a), Ty::UniqueSymbol(b)) => a == b,
-      (Ty::Record(a), Ty::Record(b)) => a as *const _ == b,
-      (Ty::Interface(a), Ty::Interface(b)) => a as *const _ == b,
-      (Ty::Function(a), Ty::Function(b)) => a as *const _ == b,
-      (Ty::Constructor(a), Ty::Constructor(b)) => a as *const _ == b,
-      (Ty::Union(a), Ty::Union(b)) => a as *const _ == b,
-      (Ty::Intersection(a), Ty::Intersection(b)) => a as *const _ == b,
-      (Ty::Instance(a), Ty::Instance(b)) => a as *const _ == b,
-      (Ty::Generic(a), Ty::Generic(b)) => a as *const _ == b,
-      (Ty::Intrinsic(a), Ty::Intrinsic(b)) => a as *const _ == b,
-      (Ty::Namespace(a), Ty::Namespace(b)) => a as *const _ == b,
+      (Ty::EnumMember(a), Ty::EnumMember(b)) => ptr::eq(*a, *b),
+      (Ty::Record(a), Ty::Record(b)) => ptr::eq(*a, *b),
+      (Ty::Interface(a), Ty::Interface(b)) => ptr::eq(*a, *b),
+      (Ty::Tuple(a), Ty::Tuple(b)) => ptr::eq(*a, *b),
+      (Ty::Function(a), Ty::Function(b)) => ptr::eq(*a, *b),
+      (Ty::Constructor(a), Ty::Constructor(b)) => ptr::eq(*a, *b),
+      (Ty::Union(a), Ty::Union(b)) => ptr::eq(*a, *b),
+      (Ty::Intersection(a), Ty::Intersection(b)) => ptr::eq(*a, *b),
+      (Ty::Instance(a), Ty::Instance(b)) => ptr::eq(*a, *b),
+      (Ty::Generic(a), Ty::Generic(b)) => ptr::eq(*a, *b),
+      (Ty::Intrinsic(a), Ty::Intrinsic(b)) => ptr::eq(*a, *b),
+      (Ty::Namespace(a), Ty::Namespace(b)) => ptr::eq(*a, *b),
       (Ty::Unresolved(a), Ty::Unresolved(b)) => a == b,
       _ => false,
     }
@@ -133,16 +147,18 @@ impl<'a> hash::Hash for Ty<'a> {
       Ty::BigIntLiteral(atom) => atom.hash(state),
       Ty::BooleanLiteral(b) => b.hash(state),
       Ty::UniqueSymbol(id) => id.hash(state),
-      Ty::Record(r) => (r as *const _ as usize).hash(state),
-      Ty::Interface(i) => (i as *const _ as usize).hash(state),
-      Ty::Function(f) => (f as *const _ as usize).hash(state),
-      Ty::Constructor(c) => (c as *const _ as usize).hash(state),
-      Ty::Union(u) => (u as *const _ as usize).hash(state),
-      Ty::Intersection(i) => (i as *const _ as usize).hash(state),
-      Ty::Instance(i) => (i as *const _ as usize).hash(state),
-      Ty::Generic(g) => (g as *const _ as usize).hash(state),
-      Ty::Intrinsic(i) => (i as *const _ as usize).hash(state),
-      Ty::Namespace(n) => (n as *const _ as usize).hash(state),
+      Ty::EnumMember(m) => ptr::hash(*m, state),
+      Ty::Record(r) => ptr::hash(*r, state),
+      Ty::Interface(i) => ptr::hash(*i, state),
+      Ty::Tuple(t) => ptr::hash(*t, state),
+      Ty::Function(f) => ptr::hash(*f, state),
+      Ty::Constructor(c) => ptr::hash(*c, state),
+      Ty::Union(u) => ptr::hash(*u, state),
+      Ty::Intersection(i) => ptr::hash(*i, state),
+      Ty::Instance(i) => ptr::hash(*i, state),
+      Ty::Generic(g) => ptr::hash(*g, state),
+      Ty::Intrinsic(i) => ptr::hash(*i, state),
+      Ty::Namespace(n) => ptr::hash(*n, state),
       Ty::Unresolved(u) => u.hash(state),
       _ => {}
     }
@@ -158,32 +174,85 @@ impl<'a> Analyzer<'a> {
     // Do nothing
   }
 
-  pub fn iterate_result_union(&mut self, target: Ty<'a>) -> Ty<'a> {
-    todo!()
+  pub fn iterate_result_union(&mut self, _target: Ty<'a>) -> Ty<'a> {
+    self.unsupported("iteration")
   }
 
   pub fn destruct_as_array(
     &mut self,
-    target: Ty<'a>,
+    _target: Ty<'a>,
     len: usize,
     need_rest: bool,
   ) -> (Vec<Ty<'a>>, Option<Ty<'a>>) {
-    todo!()
+    let ty = self.unsupported("array destructuring");
+    (vec![ty; len], need_rest.then_some(ty))
   }
 
+  /// The type of `-target` or `~target`. `bigint` stays `bigint`, and everything else is converted
+  /// to `number`.
   pub fn get_to_numeric(&mut self, target: Ty<'a>) -> Ty<'a> {
-    todo!()
+    match target {
+      Ty::Error | Ty::Never => target,
+      // TODO: Negated literal types, e.g. `-1`
+      Ty::BigInt | Ty::BigIntLiteral(_) => Ty::BigInt,
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|ty| members.push(self.get_to_numeric(ty)));
+        self.into_union(members).unwrap()
+      }
+      _ => Ty::Number,
+    }
   }
 
-  pub fn get_to_string(&mut self, target: Ty<'a>) -> Ty<'a> {
-    todo!()
+  pub fn get_to_string(&mut self, _target: Ty<'a>) -> Ty<'a> {
+    self.unsupported("string conversion")
   }
 
   pub fn get_to_boolean(&mut self, target: Ty<'a>) -> Ty<'a> {
     self.test_nullish(target).map_or(Ty::Boolean, Ty::BooleanLiteral)
   }
 
+  /// The type of `await target`. Thenables are unwrapped recursively, like `Awaited<T>`.
   pub fn get_to_awaited(&mut self, target: Ty<'a>) -> Ty<'a> {
-    todo!()
+    self.get_to_awaited_with_depth(target, 0)
+  }
+
+  fn get_to_awaited_with_depth(&mut self, target: Ty<'a>, depth: usize) -> Ty<'a> {
+    // A thenable which is fulfilled with itself, e.g. `interface T { then(f: (v: T) => void) }`
+    if depth > MAX_AWAITED_DEPTH {
+      return Ty::Error;
+    }
+    match target {
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|ty| members.push(self.get_to_awaited_with_depth(ty, depth)));
+        self.into_union(members).unwrap()
+      }
+      Ty::Record(_) | Ty::Interface(_) | Ty::Instance(_) => {
+        match self.get_fulfilled_value(target) {
+          Some(value) => self.get_to_awaited_with_depth(value, depth + 1),
+          None => target,
+        }
+      }
+      _ => target,
+    }
+  }
+
+  /// The first parameter of the `onfulfilled` callback of `target.then`, if `target` is a thenable.
+  fn get_fulfilled_value(&mut self, target: Ty<'a>) -> Option<Ty<'a>> {
+    let then = self.allocator.alloc(self.ast_builder.atom("then"));
+    let then = self.get_property(target, PropertyKeyType::StringLiteral(then));
+    let Some(ExtractedCallable::Single(then)) = self.extract_callable_function(then) else {
+      return None;
+    };
+    let scope = self.type_scopes.empty_scope;
+    let onfulfilled = self.resolve_ctx_ty(scope, then.params.first()?.1);
+    let Some(ExtractedCallable::Single(onfulfilled)) = self.extract_callable_function(onfulfilled)
+    else {
+      return None;
+    };
+    Some(
+      onfulfilled.params.first().map_or(Ty::Undefined, |(_, ty)| self.resolve_ctx_ty(scope, *ty)),
+    )
   }
 }
@@ -1,16 +1,67 @@
-use oxc::ast::ast::TSType;
+use std::cell::{Cell, RefCell};
+
+use oxc::{
+  ast::{
+    ast::{TSType, TSTypeQueryExprName},
+    NONE,
+  },
+  span::{Atom, SPAN},
+};
 use rustc_hash::FxHashMap;
 
-use super::Ty;
+use super::{property_key::PropertyKeyType, Ty};
 use crate::analyzer::Analyzer;
 
-#[derive(Debug, Clone)]
+/// A namespace, or the augmentation of a module. All the declarations with the same symbol are
+/// merged into one namespace.
+#[derive(Debug)]
 pub struct NamespaceType<'a> {
-  pub members: FxHashMap<&'a str, Ty<'a>>,
+  pub name: &'a Atom<'a>,
+  /// Exported values
+  pub values: RefCell<FxHashMap<&'a str, Ty<'a>>>,
+  /// Exported types
+  pub types: RefCell<FxHashMap<&'a str, Ty<'a>>>,
+  /// The function or enum merged with the namespace
+  pub merged: Cell<Option<Ty<'a>>>,
+}
+
+impl<'a> NamespaceType<'a> {
+  pub fn new(name: &'a Atom<'a>) -> Self {
+    NamespaceType {
+      name,
+      values: Default::default(),
+      types: Default::default(),
+      merged: Cell::new(None),
+    }
+  }
+
+  pub fn get_type(&self, name: &str) -> Option<Ty<'a>> {
+    self.types.borrow().get(name).copied()
+  }
 }
 
 impl<'a> Analyzer<'a> {
+  pub fn get_namespace_property(
+    &mut self,
+    namespace: &'a NamespaceType<'a>,
+    key: PropertyKeyType<'a>,
+  ) -> Ty<'a> {
+    let own = match key {
+      PropertyKeyType::StringLiteral(s) => namespace.values.borrow().get(s.as_str()).copied(),
+      _ => None,
+    };
+    match (own, namespace.merged.get()) {
+      (Some(own), _) => own,
+      (None, Some(merged)) => self.get_property(merged, key),
+      (None, None) => Ty::Error,
+    }
+  }
+
+  /// Prints `typeof N`.
   pub fn serialize_namespace_type(&mut self, namespace: &NamespaceType<'a>) -> TSType<'a> {
-    todo!()
+    let name = TSTypeQueryExprName::IdentifierReference(
+      self.ast_builder.alloc(self.ast_builder.identifier_reference(SPAN, namespace.name.as_str())),
+    );
+    self.ast_builder.ts_type_type_query(SPAN, name, NONE)
   }
 }
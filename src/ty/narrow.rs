@@ -0,0 +1,82 @@
+use super::{facts::Facts, unresolved::UnresolvedType, Ty};
+use crate::analyzer::Analyzer;
+
+impl<'a> Analyzer<'a> {
+  /// Removes the members of the type which have any of the excluded facts, e.g. `undefined` is
+  /// removed from `string | undefined` with `Facts::EQ_UNDEFINED`.
+  pub fn narrow_by_facts(&mut self, ty: Ty<'a>, excluded: Facts) -> Ty<'a> {
+    self.narrow_members(ty, &mut |analyzer, member| {
+      if analyzer.get_facts(member).intersects(excluded) {
+        Ty::Never
+      } else {
+        member
+      }
+    })
+  }
+
+  /// Narrows the type by the result of `typeof`, e.g. `typeof x === "string"`.
+  pub fn narrow_by_typeof(&mut self, ty: Ty<'a>, name: &str, equal: bool) -> Ty<'a> {
+    let (eq, ne, primitive) = match name {
+      "string" => (Facts::T_EQ_STRING, Facts::T_NE_STRING, Some(Ty::String)),
+      "number" => (Facts::T_EQ_NUMBER, Facts::T_NE_NUMBER, Some(Ty::Number)),
+      "bigint" => (Facts::T_EQ_BIGINT, Facts::T_NE_BIGINT, Some(Ty::BigInt)),
+      "boolean" => (Facts::T_EQ_BOOLEAN, Facts::T_NE_BOOLEAN, Some(Ty::Boolean)),
+      "symbol" => (Facts::T_EQ_SYMBOL, Facts::T_NE_SYMBOL, Some(Ty::Symbol)),
+      "undefined" => (Facts::EQ_UNDEFINED, Facts::NE_UNDEFINED, Some(Ty::Undefined)),
+      "object" => (Facts::T_EQ_OBJECT, Facts::T_NE_OBJECT, None),
+      "function" => (Facts::T_EQ_FUNCTION, Facts::T_NE_FUNCTION, None),
+      _ => return ty,
+    };
+    self.narrow_members(ty, &mut |analyzer, member| match member {
+      // `unknown` is narrowed to the primitive, but not to an object type
+      Ty::Any | Ty::Unknown if equal => primitive.unwrap_or(member),
+      _ if analyzer.get_facts(member).intersects(if equal { ne } else { eq }) => Ty::Never,
+      _ => member,
+    })
+  }
+
+  /// Maps each member of the type, where `Ty::Never` removes the member. The type is returned as is
+  /// if no member changes, so that aliases are kept.
+  fn narrow_members(
+    &mut self,
+    ty: Ty<'a>,
+    f: &mut impl FnMut(&mut Self, Ty<'a>) -> Ty<'a>,
+  ) -> Ty<'a> {
+    let members = match ty {
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|member| members.push(member));
+        members
+      }
+      // Either of the literals may be removed
+      Ty::Boolean => vec![Ty::BooleanLiteral(true), Ty::BooleanLiteral(false)],
+      Ty::Instance(_)
+      | Ty::Unresolved(UnresolvedType::UnInitType(_) | UnresolvedType::UnInitVariable(_)) => {
+        let lowest = self.get_lowest_type(ty);
+        if lowest == ty {
+          return f(self, ty);
+        }
+        return match self.narrow_members(lowest, f) {
+          narrowed if narrowed == lowest => ty,
+          narrowed => narrowed,
+        };
+      }
+      _ => return f(self, ty),
+    };
+
+    let mut changed = false;
+    let mut narrowed = vec![];
+    for member in members {
+      let member_narrowed = self.narrow_members(member, f);
+      changed |= member_narrowed != member;
+      if member_narrowed != Ty::Never {
+        narrowed.push(member_narrowed);
+      }
+    }
+    if changed {
+      self.into_union(narrowed).unwrap_or(Ty::Never)
+    } else {
+      ty
+    }
+  }
+}
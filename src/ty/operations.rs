@@ -78,7 +78,15 @@ impl<'a> Analyzer<'a> {
       }
       Ty::Generic(_) | Ty::Intrinsic(_) => Ty::Error,
 
-      Ty::Unresolved(_) => self.create_generic_instance(todo!("builtins::NonNullable"), vec![ty]),
+      // Kept as is if the constraint is not nullable, e.g. `T extends { id: string }`
+      Ty::Unresolved(_) => {
+        let lowest = self.get_lowest_type(ty);
+        if self.get_facts(lowest).contains(Facts::NE_NULL | Facts::NE_UNDEFINED) {
+          ty
+        } else {
+          self.non_nullable(lowest)
+        }
+      }
 
       _ => ty,
     }
@@ -1,5 +1,9 @@
 use oxc::{
-  ast::ast::{TSType, TSTypeOperatorOperator},
+  ast::{
+    ast::{TSType, TSTypeOperatorOperator},
+    NONE,
+  },
+  codegen::{Codegen, Context, Gen},
   span::SPAN,
 };
 use oxc_syntax::number::{BigintBase, NumberBase};
@@ -43,6 +47,7 @@ impl<'a> Analyzer<'a> {
         TSTypeOperatorOperator::Unique,
         self.ast_builder.ts_type_symbol_keyword(SPAN),
       ),
+      Ty::EnumMember(member) => self.serialize_enum_member_type(member),
 
       Ty::Record(r) => self.serialize_record_type(r),
       Ty::Interface(i) => self.serialize_interface_type(i),
@@ -51,7 +56,13 @@ impl<'a> Analyzer<'a> {
       Ty::Constructor(r) => self.serialize_callable_type(r),
       Ty::Namespace(r) => self.serialize_namespace_type(r),
 
-      Ty::Union(u) => self.serialize_union_type(u),
+      Ty::Union(u) => match self.get_enum_symbol_of_union(ty) {
+        Some(symbol) => {
+          let name = self.serialize_enum_reference(symbol);
+          self.ast_builder.ts_type_type_reference(SPAN, name, NONE)
+        }
+        None => self.serialize_union_type(u),
+      },
       Ty::Intersection(i) => self.serialize_intersection_type(i),
 
       Ty::Instance(i) => self.serialize_instance_type(i),
@@ -61,4 +72,12 @@ impl<'a> Analyzer<'a> {
       Ty::Unresolved(u) => self.serialize_unresolved_type(u),
     }
   }
+
+  /// Prints the type as TypeScript source, for messages.
+  pub fn print_type(&mut self, ty: Ty<'a>) -> String {
+    let ts_type = self.serialize_type(ty);
+    let mut codegen = Codegen::new();
+    ts_type.print(&mut codegen, Context::default());
+    codegen.into_source_text()
+  }
 }
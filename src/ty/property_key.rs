@@ -16,6 +16,49 @@ pub enum PropertyKeyType<'a> {
   UniqueSymbol(SymbolId),
 }
 
+/// The key of a template pattern index signature, e.g. `[key: `data-${string}`]: T`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TemplatePattern<'a> {
+  /// The texts around the holes, one more than the holes
+  pub quasis: Vec<&'a str>,
+  pub holes: Vec<TemplateHole>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TemplateHole {
+  /// `${string}`
+  String,
+  /// `${number}`
+  Number,
+}
+
+impl TemplateHole {
+  fn accepts(self, text: &str) -> bool {
+    match self {
+      TemplateHole::String => true,
+      TemplateHole::Number => !text.trim().is_empty() && text.parse::<f64>().is_ok(),
+    }
+  }
+}
+
+impl<'a> TemplatePattern<'a> {
+  pub fn matches(&self, key: &str) -> bool {
+    fn match_from(quasis: &[&str], holes: &[TemplateHole], text: &str) -> bool {
+      let Some(rest) = text.strip_prefix(quasis[0]) else {
+        return false;
+      };
+      let Some((hole, holes)) = holes.split_first() else {
+        return rest.is_empty();
+      };
+      (0..=rest.len()).filter(|index| rest.is_char_boundary(*index)).any(|index| {
+        let (taken, remaining) = rest.split_at(index);
+        hole.accepts(taken) && match_from(&quasis[1..], holes, remaining)
+      })
+    }
+    match_from(&self.quasis, &self.holes, key)
+  }
+}
+
 impl<'a> Analyzer<'a> {
   pub fn to_property_key(&mut self, ty: Ty<'a>) -> PropertyKeyType<'a> {
     match ty {
@@ -35,6 +78,7 @@ impl<'a> Analyzer<'a> {
       Ty::StringLiteral(s) => PropertyKeyType::StringLiteral(s),
       Ty::NumericLiteral(n) => PropertyKeyType::NumericLiteral(n),
       Ty::UniqueSymbol(s) => PropertyKeyType::UniqueSymbol(s),
+      Ty::EnumMember(member) => self.to_property_key(member.value),
       Ty::BigIntLiteral(_) | Ty::BooleanLiteral(_) => PropertyKeyType::Error,
 
       Ty::Record(_)
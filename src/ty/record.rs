@@ -1,17 +1,28 @@
-use std::{collections::hash_map::Entry, hash::Hash};
+use std::{
+  collections::hash_map::Entry,
+  hash::{Hash, Hasher},
+};
 
 use oxc::{
-  ast::ast::{PropertyKey, TSSignature, TSType},
+  ast::ast::{PropertyKey, TSSignature, TSType, TemplateElementValue},
   semantic::SymbolId,
-  span::SPAN,
+  span::{Atom, SPAN},
 };
 use oxc_syntax::number::ToJsString;
 use rustc_hash::FxHashMap;
 
-use super::{accumulator::TypeAccumulator, property_key::PropertyKeyType, Ty};
-use crate::{analyzer::Analyzer, ty::union::UnionType};
+use super::{
+  accumulator::TypeAccumulator,
+  interner::hash_unordered,
+  property_key::{PropertyKeyType, TemplateHole, TemplatePattern},
+  Ty,
+};
+use crate::{
+  analyzer::Analyzer,
+  ty::intersection::{IntersectionBaseKind, IntersectionType},
+};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RecordPropertyValue<'a> {
   pub value: Ty<'a>,
   pub optional: bool,
@@ -28,15 +39,26 @@ impl<'a, K> Default for KeyedPropertyMap<'a, K> {
   }
 }
 
+impl<'a, K: Eq + Hash> PartialEq for KeyedPropertyMap<'a, K> {
+  fn eq(&self, other: &Self) -> bool {
+    self.0 == other.0
+  }
+}
+
+impl<'a, K: Eq + Hash> Eq for KeyedPropertyMap<'a, K> {}
+
+impl<'a, K: Hash> Hash for KeyedPropertyMap<'a, K> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    hash_unordered(self.0.iter(), state);
+  }
+}
+
 impl<'a, K: Eq + Hash> KeyedPropertyMap<'a, K> {
   pub fn init(&mut self, analyzer: &mut Analyzer<'a>, key: K, mut value: RecordPropertyValue<'a>) {
-    fn union_is_bivariant<'a>(u: &UnionType<'a>) -> bool {
-      let mut bivariant = true;
-      u.for_each(|ty| match ty {
-        Ty::Function(f) => bivariant &= f.is_method,
-        _ => bivariant = false,
-      });
-      bivariant
+    fn is_method_overloads(i: &IntersectionType<'_>) -> bool {
+      i.kind == IntersectionBaseKind::NoBase
+        && i.unresolved.is_empty()
+        && i.object_like.iter().all(|ty| matches!(ty, Ty::Function(f) if f.is_method))
     }
 
     match self.0.entry(key) {
@@ -44,10 +66,12 @@ impl<'a, K: Eq + Hash> KeyedPropertyMap<'a, K> {
         let prev = entry.get();
         value.value = match (prev.value, value.value) {
           (Ty::Function(f1), Ty::Function(f2)) if f1.is_method && f2.is_method => {
-            analyzer.into_union([prev.value, value.value]).unwrap()
+            analyzer.into_overloaded(vec![prev.value, value.value])
           }
-          (Ty::Union(u1), Ty::Function(f2)) if union_is_bivariant(u1) && f2.is_method => {
-            analyzer.into_union([prev.value, value.value]).unwrap()
+          (Ty::Intersection(i1), Ty::Function(f2)) if is_method_overloads(i1) && f2.is_method => {
+            let mut overloads = i1.object_like.clone();
+            overloads.push(value.value);
+            analyzer.into_overloaded(overloads)
           }
           _ => value.value,
         };
@@ -61,7 +85,7 @@ impl<'a, K: Eq + Hash> KeyedPropertyMap<'a, K> {
 
   pub fn get(&self, key: K) -> Ty<'a> {
     if let Some(property) = self.0.get(&key) {
-      property.value.clone()
+      property.value
     } else {
       Ty::Error
     }
@@ -82,6 +106,7 @@ pub struct RecordTypeBuilder<'a> {
   pub string_mapped: MappedPropertyBuilder<'a>,
   pub number_mapped: MappedPropertyBuilder<'a>,
   pub symbol_mapped: MappedPropertyBuilder<'a>,
+  pub pattern_mapped: Vec<(TemplatePattern<'a>, RecordPropertyValue<'a>)>,
 }
 
 impl<'a> RecordTypeBuilder<'a> {
@@ -98,13 +123,15 @@ impl<'a> RecordTypeBuilder<'a> {
       PropertyKeyType::Error => {}
       PropertyKeyType::AnyString => {
         self.string_mapped.value.add(value, analyzer.allocator);
+        self.string_mapped.readonly |= readonly;
       }
       PropertyKeyType::AnyNumber => {
-        self.string_mapped.value.add(value, analyzer.allocator);
         self.number_mapped.value.add(value, analyzer.allocator);
+        self.number_mapped.readonly |= readonly;
       }
       PropertyKeyType::AnySymbol => {
         self.symbol_mapped.value.add(value, analyzer.allocator);
+        self.symbol_mapped.readonly |= readonly;
       }
       PropertyKeyType::StringLiteral(s) => {
         self.string_keyed.init(analyzer, s.as_str(), keyed_property);
@@ -119,12 +146,27 @@ impl<'a> RecordTypeBuilder<'a> {
     }
   }
 
-  pub fn init_spread(&mut self, analyzer: &mut Analyzer<'a>, value: Ty<'a>) {
-    todo!()
+  /// A template pattern index signature, e.g. `[key: `data-${string}`]: T`.
+  pub fn init_pattern_property(
+    &mut self,
+    pattern: TemplatePattern<'a>,
+    value: Ty<'a>,
+    readonly: bool,
+  ) {
+    let property = RecordPropertyValue { value, optional: false, readonly };
+    if let Some((_, existing)) = self.pattern_mapped.iter_mut().find(|(p, _)| *p == pattern) {
+      *existing = property;
+    } else {
+      self.pattern_mapped.push((pattern, property));
+    }
   }
 
-  pub fn remove_property(&mut self, analyzer: &mut Analyzer<'a>, key: PropertyKeyType<'a>) {
-    todo!()
+  pub fn init_spread(&mut self, analyzer: &mut Analyzer<'a>, _value: Ty<'a>) {
+    analyzer.unsupported("object spread");
+  }
+
+  pub fn remove_property(&mut self, analyzer: &mut Analyzer<'a>, _key: PropertyKeyType<'a>) {
+    analyzer.unsupported("removing properties");
   }
 
   pub fn build(mut self) -> RecordType<'a> {
@@ -146,11 +188,12 @@ impl<'a> RecordTypeBuilder<'a> {
         optional: false,
         readonly: self.symbol_mapped.readonly,
       }),
+      pattern_mapped: self.pattern_mapped,
     }
   }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct RecordType<'a> {
   pub string_keyed: KeyedPropertyMap<'a, &'a str>,
   pub symbol_keyed: KeyedPropertyMap<'a, SymbolId>,
@@ -158,18 +201,46 @@ pub struct RecordType<'a> {
   pub string_mapped: Option<RecordPropertyValue<'a>>,
   pub number_mapped: Option<RecordPropertyValue<'a>>,
   pub symbol_mapped: Option<RecordPropertyValue<'a>>,
+  /// Template pattern index signatures, in declaration order
+  pub pattern_mapped: Vec<(TemplatePattern<'a>, RecordPropertyValue<'a>)>,
 }
 
 impl<'a> RecordType<'a> {
   pub fn get_property(&self, key: PropertyKeyType<'a>) -> Ty<'a> {
+    self.get_property_value(key).map_or(Ty::Error, |property| property.value)
+  }
+
+  /// The property with its flags, or `None` if it is not declared.
+  pub fn get_property_value(&self, key: PropertyKeyType<'a>) -> Option<RecordPropertyValue<'a>> {
+    self.get_keyed_property(key).or_else(|| self.get_index_signature(key)).cloned()
+  }
+
+  pub fn get_keyed_property(&self, key: PropertyKeyType<'a>) -> Option<&RecordPropertyValue<'a>> {
     match key {
-      PropertyKeyType::Error => Ty::Error,
-      PropertyKeyType::AnyString => self.string_mapped.as_ref().map_or(Ty::Error, |p| p.value),
-      PropertyKeyType::AnyNumber => self.number_mapped.as_ref().map_or(Ty::Error, |p| p.value),
-      PropertyKeyType::AnySymbol => self.symbol_mapped.as_ref().map_or(Ty::Error, |p| p.value),
-      PropertyKeyType::StringLiteral(s) => self.string_keyed.get(s.as_str()),
-      PropertyKeyType::NumericLiteral(n) => self.string_keyed.get(n.0.to_js_string().as_str()),
-      PropertyKeyType::UniqueSymbol(s) => self.symbol_keyed.get(s),
+      PropertyKeyType::StringLiteral(s) => self.string_keyed.0.get(s.as_str()),
+      PropertyKeyType::NumericLiteral(n) => self.string_keyed.0.get(n.0.to_js_string().as_str()),
+      PropertyKeyType::UniqueSymbol(s) => self.symbol_keyed.0.get(&s),
+      _ => None,
+    }
+  }
+
+  /// The index signature which applies to the key. Numeric keys fall back to the string index
+  /// signature.
+  pub fn get_index_signature(&self, key: PropertyKeyType<'a>) -> Option<&RecordPropertyValue<'a>> {
+    let find_pattern = |key: &str| {
+      self.pattern_mapped.iter().find(|(pattern, _)| pattern.matches(key)).map(|(_, p)| p)
+    };
+    match key {
+      PropertyKeyType::Error => None,
+      PropertyKeyType::AnyString => self.string_mapped.as_ref(),
+      PropertyKeyType::AnyNumber => self.number_mapped.as_ref().or(self.string_mapped.as_ref()),
+      PropertyKeyType::AnySymbol | PropertyKeyType::UniqueSymbol(_) => self.symbol_mapped.as_ref(),
+      PropertyKeyType::StringLiteral(s) => find_pattern(s.as_str()).or(self.string_mapped.as_ref()),
+      PropertyKeyType::NumericLiteral(n) => self
+        .number_mapped
+        .as_ref()
+        .or_else(|| find_pattern(&n.0.to_js_string()))
+        .or(self.string_mapped.as_ref()),
     }
   }
 
@@ -180,6 +251,31 @@ impl<'a> RecordType<'a> {
     self.string_mapped = other.string_mapped;
     self.number_mapped = other.number_mapped;
     self.symbol_mapped = other.symbol_mapped;
+    self.pattern_mapped.extend(other.pattern_mapped);
+  }
+
+  /// Adds the properties of `base` which are not declared by this record.
+  pub fn inherit(&mut self, base: &RecordType<'a>) {
+    for (key, property) in &base.string_keyed.0 {
+      self.string_keyed.0.entry(*key).or_insert_with(|| property.clone());
+    }
+    for (key, property) in &base.symbol_keyed.0 {
+      self.symbol_keyed.0.entry(*key).or_insert_with(|| property.clone());
+    }
+    if self.string_mapped.is_none() {
+      self.string_mapped = base.string_mapped.clone();
+    }
+    if self.number_mapped.is_none() {
+      self.number_mapped = base.number_mapped.clone();
+    }
+    if self.symbol_mapped.is_none() {
+      self.symbol_mapped = base.symbol_mapped.clone();
+    }
+    for (pattern, property) in &base.pattern_mapped {
+      if !self.pattern_mapped.iter().any(|(p, _)| p == pattern) {
+        self.pattern_mapped.push((pattern.clone(), property.clone()));
+      }
+    }
   }
 
   pub fn is_empty(&self) -> bool {
@@ -188,6 +284,7 @@ impl<'a> RecordType<'a> {
       && self.string_mapped.is_none()
       && self.number_mapped.is_none()
       && self.symbol_mapped.is_none()
+      && self.pattern_mapped.is_empty()
   }
 }
 
@@ -210,14 +307,14 @@ impl<'a> Analyzer<'a> {
   fn serialize_mapped_property(
     &mut self,
     key_type: TSType<'a>,
-    property: &Option<RecordPropertyValue<'a>>,
+    property: Option<&RecordPropertyValue<'a>>,
   ) -> Option<TSSignature<'a>> {
-    let property = property.as_ref()?;
+    let property = property?;
     Some(self.ast_builder.ts_signature_index_signature(
       SPAN,
       self.ast_builder.vec1(self.ast_builder.ts_index_signature_name(
         SPAN,
-        "1",
+        "key",
         self.ast_builder.ts_type_annotation(SPAN, key_type),
       )),
       self.ast_builder.ts_type_annotation(SPAN, self.serialize_type(property.value)),
@@ -226,6 +323,23 @@ impl<'a> Analyzer<'a> {
     ))
   }
 
+  fn serialize_template_pattern(&mut self, pattern: &TemplatePattern<'a>) -> TSType<'a> {
+    let mut quasis = self.ast_builder.vec();
+    for (index, quasi) in pattern.quasis.iter().enumerate() {
+      let value =
+        TemplateElementValue { raw: Atom::from(*quasi), cooked: Some(Atom::from(*quasi)) };
+      quasis.push(self.ast_builder.template_element(SPAN, index == pattern.holes.len(), value));
+    }
+    let mut types = self.ast_builder.vec();
+    for hole in &pattern.holes {
+      types.push(match hole {
+        TemplateHole::String => self.ast_builder.ts_type_string_keyword(SPAN),
+        TemplateHole::Number => self.ast_builder.ts_type_number_keyword(SPAN),
+      });
+    }
+    self.ast_builder.ts_type_template_literal_type(SPAN, quasis, types)
+  }
+
   pub fn serialize_record_type(&mut self, record: &RecordType<'a>) -> TSType<'a> {
     let mut members = self.ast_builder.vec();
     for (key, property) in &record.string_keyed.0 {
@@ -234,27 +348,31 @@ impl<'a> Analyzer<'a> {
         property,
       ));
     }
-    for (key, property) in &record.symbol_keyed.0 {
-      members.push(self.serialize_keyed_property(todo!(), property));
+    if !record.symbol_keyed.0.is_empty() {
+      self.unsupported("printing symbol keys");
     }
     if let Some(node) = self.serialize_mapped_property(
       self.ast_builder.ts_type_number_keyword(SPAN),
-      &record.number_mapped,
+      record.number_mapped.as_ref(),
     ) {
       members.push(node);
     }
     if let Some(node) = self.serialize_mapped_property(
       self.ast_builder.ts_type_symbol_keyword(SPAN),
-      &record.symbol_mapped,
+      record.symbol_mapped.as_ref(),
     ) {
       members.push(node);
     }
     if let Some(node) = self.serialize_mapped_property(
       self.ast_builder.ts_type_string_keyword(SPAN),
-      &record.string_mapped,
+      record.string_mapped.as_ref(),
     ) {
       members.push(node);
     }
+    for (pattern, property) in &record.pattern_mapped {
+      let key_type = self.serialize_template_pattern(pattern);
+      members.extend(self.serialize_mapped_property(key_type, Some(property)));
+    }
     self.ast_builder.ts_type_type_literal(SPAN, members)
   }
 }
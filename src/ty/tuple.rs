@@ -3,11 +3,11 @@ use oxc::{
   span::{Atom, SPAN},
 };
 
-use crate::Analyzer;
+use crate::{utils::F64WithEq, Analyzer};
 
 use super::{property_key::PropertyKeyType, Ty};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TupleElement<'a> {
   pub name: Option<&'a Atom<'a>>,
   pub spread: bool,
@@ -15,7 +15,7 @@ pub struct TupleElement<'a> {
   pub ty: Ty<'a>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, PartialEq, Eq, Hash)]
 pub struct TupleType<'a> {
   pub elements: Vec<TupleElement<'a>>,
   pub readonly: bool,
@@ -42,24 +42,32 @@ impl<'a> TupleType<'a> {
       PropertyKeyType::AnyString => Ty::Error,
       PropertyKeyType::AnyNumber => self.iterate_result_union(analyzer),
       PropertyKeyType::AnySymbol => Ty::Error,
+      PropertyKeyType::StringLiteral(s) if s.as_str() == "length" => self.get_length(),
       PropertyKeyType::StringLiteral(s) => {
-        if let Some(index) = s.parse::<usize>().ok() {
+        if let Ok(index) = s.parse::<usize>() {
           self.get_element_by_index(index, analyzer)
         } else {
-          todo!("Array prototype");
+          analyzer.unsupported("array prototype")
         }
       }
       PropertyKeyType::NumericLiteral(n) => {
         let index = n.0 as usize;
         self.get_element_by_index(index, analyzer)
       }
-      PropertyKeyType::UniqueSymbol(s) => {
-        todo!("Array prototype");
-      }
+      PropertyKeyType::UniqueSymbol(_) => analyzer.unsupported("array prototype"),
     }
     // self.0.get(index).map(|e| e.ty)
   }
 
+  /// A literal for tuples of fixed length.
+  fn get_length(&self) -> Ty<'a> {
+    if self.elements.iter().any(|element| element.spread || element.optional) {
+      Ty::Number
+    } else {
+      Ty::NumericLiteral(F64WithEq(self.elements.len() as f64))
+    }
+  }
+
   fn get_element_by_index(&self, index: usize, analyzer: &mut Analyzer<'a>) -> Ty<'a> {
     let mut determinate = true;
     let mut types = Vec::new();
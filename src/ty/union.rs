@@ -1,4 +1,7 @@
-use std::{hash::Hash, i32};
+use std::{
+  hash::{Hash, Hasher},
+  mem,
+};
 
 use oxc::{
   ast::ast::TSType,
@@ -7,9 +10,23 @@ use oxc::{
 };
 use rustc_hash::FxHashSet;
 
-use super::{property_key::PropertyKeyType, unresolved::UnresolvedType, Ty};
+use super::{
+  interner::hash_unordered, property_key::PropertyKeyType, unresolved::UnresolvedType, Ty,
+};
 use crate::{analyzer::Analyzer, utils::F64WithEq};
 
+/// How the members of a union are normalized.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum UnionReduction {
+  /// Literal types are absorbed by their primitive types, and `true | false` becomes `boolean`.
+  /// Used for unions written in declarations.
+  #[default]
+  Literal,
+  /// Additionally removes the members which are strict subtypes of other object-like members.
+  /// Used for the element types of array literals and inferred return types.
+  Subtype,
+}
+
 #[derive(Debug, Default, Clone)]
 pub enum UnionTypeBuilder<'a> {
   #[default]
@@ -52,18 +69,35 @@ impl<'a> UnionTypeBuilder<'a> {
     }
   }
 
-  pub fn build(self, analyzer: &Analyzer<'a>) -> Ty<'a> {
+  pub fn build(self, analyzer: &mut Analyzer<'a>) -> Ty<'a> {
+    self.build_with_reduction(analyzer, UnionReduction::Literal)
+  }
+
+  pub fn build_with_reduction(
+    self,
+    analyzer: &mut Analyzer<'a>,
+    reduction: UnionReduction,
+  ) -> Ty<'a> {
     match self {
       UnionTypeBuilder::Never => Ty::Never,
       UnionTypeBuilder::Error => Ty::Error,
       UnionTypeBuilder::Any => Ty::Any,
       UnionTypeBuilder::Unknown => Ty::Unknown,
-      UnionTypeBuilder::Compound(compound) => Ty::Union(analyzer.allocator.alloc(compound)),
+      UnionTypeBuilder::Compound(compound) => {
+        if reduction == UnionReduction::Subtype && !compound.complex.is_empty() {
+          analyzer.remove_subtype_members(&compound)
+        } else if let Some(single) = compound.single_member() {
+          // So that `string | "a"` is equal to `string`
+          single
+        } else {
+          analyzer.intern_union(*compound)
+        }
+      }
     }
   }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct UnionType<'a> {
   pub string: LiteralAble<&'a Atom<'a>>,
   pub number: LiteralAble<F64WithEq>,
@@ -81,6 +115,22 @@ pub struct UnionType<'a> {
   pub unresolved: Vec<UnresolvedType<'a>>,
 }
 
+impl<'a> Hash for UnionType<'a> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.string.hash(state);
+    self.number.hash(state);
+    self.bigint.hash(state);
+    self.symbol.hash(state);
+    self.object.hash(state);
+    self.void.hash(state);
+    self.null.hash(state);
+    self.undefined.hash(state);
+    self.boolean.hash(state);
+    hash_unordered(self.complex.iter(), state);
+    self.unresolved.hash(state);
+  }
+}
+
 impl<'a> UnionType<'a> {
   pub fn add(&mut self, ty: Ty<'a>) {
     match ty {
@@ -102,7 +152,9 @@ impl<'a> UnionType<'a> {
       Ty::BooleanLiteral(true) => self.boolean.0 = true,
       Ty::BooleanLiteral(false) => self.boolean.1 = true,
 
-      Ty::Record(_)
+      Ty::EnumMember(_)
+      | Ty::Record(_)
+      | Ty::Tuple(_)
       | Ty::Function(_)
       | Ty::Constructor(_)
       | Ty::Interface(_)
@@ -117,7 +169,7 @@ impl<'a> UnionType<'a> {
     }
   }
 
-  pub fn for_each(&self, mut f: impl FnMut(Ty<'a>) -> ()) {
+  pub fn for_each(&self, mut f: impl FnMut(Ty<'a>)) {
     self.string.for_each(Ty::String, Ty::StringLiteral, &mut f);
     self.number.for_each(Ty::Number, Ty::NumericLiteral, &mut f);
     self.bigint.for_each(Ty::BigInt, Ty::BigIntLiteral, &mut f);
@@ -145,6 +197,17 @@ impl<'a> UnionType<'a> {
     self.complex.iter().copied().for_each(&mut f);
     self.unresolved.iter().copied().map(Ty::Unresolved).for_each(f);
   }
+
+  /// Returns the member if there is exactly one after normalization.
+  pub fn single_member(&self) -> Option<Ty<'a>> {
+    let mut count = 0;
+    let mut single = None;
+    self.for_each(|ty| {
+      count += 1;
+      single = Some(ty);
+    });
+    single.filter(|_| count == 1)
+  }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -155,6 +218,28 @@ pub enum LiteralAble<L> {
   Literals(FxHashSet<L>),
 }
 
+impl<L: Hash + Eq> PartialEq for LiteralAble<L> {
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (LiteralAble::Vacant, LiteralAble::Vacant) => true,
+      (LiteralAble::Any, LiteralAble::Any) => true,
+      (LiteralAble::Literals(a), LiteralAble::Literals(b)) => a == b,
+      _ => false,
+    }
+  }
+}
+
+impl<L: Hash + Eq> Eq for LiteralAble<L> {}
+
+impl<L: Hash> Hash for LiteralAble<L> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    mem::discriminant(self).hash(state);
+    if let LiteralAble::Literals(set) = self {
+      hash_unordered(set.iter(), state);
+    }
+  }
+}
+
 impl<'a, L> LiteralAble<L> {
   pub fn add(&mut self, literal: L)
   where
@@ -175,7 +260,7 @@ impl<'a, L> LiteralAble<L> {
     }
   }
 
-  pub fn for_each(&self, any: Ty<'a>, ctor: fn(L) -> Ty<'a>, mut f: impl FnMut(Ty<'a>) -> ())
+  pub fn for_each(&self, any: Ty<'a>, ctor: fn(L) -> Ty<'a>, mut f: impl FnMut(Ty<'a>))
   where
     L: Copy,
   {
@@ -183,7 +268,7 @@ impl<'a, L> LiteralAble<L> {
       LiteralAble::Vacant => {}
       LiteralAble::Any => f(any),
       LiteralAble::Literals(set) => {
-        set.iter().copied().map(ctor).for_each(&mut f as &mut dyn FnMut(Ty<'a>) -> ())
+        set.iter().copied().map(ctor).for_each(&mut f as &mut dyn FnMut(Ty<'a>))
       }
     }
   }
@@ -194,6 +279,17 @@ impl<'a> Analyzer<'a> {
     &mut self,
     types: impl IntoIterator<Item = Ty<'a>, IntoIter = Iter>,
   ) -> Option<Ty<'a>>
+  where
+    Iter: Iterator<Item = Ty<'a>> + ExactSizeIterator,
+  {
+    self.into_union_with_reduction(types, UnionReduction::Literal)
+  }
+
+  pub fn into_union_with_reduction<Iter>(
+    &mut self,
+    types: impl IntoIterator<Item = Ty<'a>, IntoIter = Iter>,
+    reduction: UnionReduction,
+  ) -> Option<Ty<'a>>
   where
     Iter: Iterator<Item = Ty<'a>> + ExactSizeIterator,
   {
@@ -201,15 +297,37 @@ impl<'a> Analyzer<'a> {
     match iter.len() {
       // FIXME: Should be Ty::Never
       0 => None,
-      1 => iter.next(),
+      1 if reduction == UnionReduction::Literal => iter.next(),
       _ => {
         let mut builder = UnionTypeBuilder::default();
         iter.for_each(|ty| builder.add(self, ty));
-        Some(builder.build(self))
+        Some(builder.build_with_reduction(self, reduction))
       }
     }
   }
 
+  /// Subtype reduction of TypeScript, e.g. `{ a: string } | { a: string; b: number }` is reduced to
+  /// `{ a: string }`. Members which are subtypes of each other are both kept.
+  fn remove_subtype_members(&mut self, union: &UnionType<'a>) -> Ty<'a> {
+    let mut members = Vec::new();
+    union.for_each(|ty| members.push(ty));
+
+    let mut builder = UnionTypeBuilder::default();
+    for member in members {
+      let is_subtype = is_subtype_reducible(member)
+        && union.complex.iter().any(|&target| {
+          target != member
+            && is_subtype_reducible(target)
+            && self.match_covariant_types(1, member, target).matched()
+            && !self.match_covariant_types(1, target, member).matched()
+        });
+      if !is_subtype {
+        builder.add(self, member);
+      }
+    }
+    builder.build(self)
+  }
+
   pub fn into_union_with_specificity<Iter>(
     &mut self,
     types: impl IntoIterator<Item = (i32, Ty<'a>), IntoIter = Iter>,
@@ -251,9 +369,43 @@ impl<'a> Analyzer<'a> {
     builder.build(self)
   }
 
+  /// The members of `target` which are not members of any of the `covered` types, e.g. the cases
+  /// missing from a `switch` statement. `boolean` is treated as `true | false`.
+  pub fn get_remaining_members(&mut self, target: Ty<'a>, covered: &[Ty<'a>]) -> Vec<Ty<'a>> {
+    let mut covered_members = FxHashSet::default();
+    for ty in covered {
+      for_each_member(*ty, &mut |member| {
+        covered_members.insert(member);
+      });
+    }
+    let mut remaining = vec![];
+    for_each_member(target, &mut |member| {
+      if !covered_members.contains(&member) {
+        remaining.push(member);
+      }
+    });
+    remaining
+  }
+
   pub fn serialize_union_type(&mut self, union: &UnionType<'a>) -> TSType<'a> {
     let mut types = self.ast_builder.vec();
     union.for_each(|ty| types.push(self.serialize_type(ty)));
     self.ast_builder.ts_type_union_type(SPAN, types)
   }
 }
+
+/// Matching the other complex types is not fully supported yet, which would report diagnostics.
+fn is_subtype_reducible(ty: Ty) -> bool {
+  matches!(ty, Ty::Record(_) | Ty::Function(_) | Ty::Constructor(_))
+}
+
+fn for_each_member<'a>(ty: Ty<'a>, f: &mut impl FnMut(Ty<'a>)) {
+  match ty {
+    Ty::Union(union) => union.for_each(|ty| for_each_member(ty, f)),
+    Ty::Boolean => {
+      f(Ty::BooleanLiteral(true));
+      f(Ty::BooleanLiteral(false));
+    }
+    ty => f(ty),
+  }
+}
@@ -7,15 +7,16 @@ use oxc::{
   span::SPAN,
 };
 
-use super::Ty;
+use super::{conditional::ConditionalType, Ty};
 use crate::Analyzer;
 
-#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UnresolvedType<'a> {
   UnInitVariable(SymbolId),
   UnInitType(SymbolId),
   GenericParam(SymbolId),
   Keyof(&'a Ty<'a>),
+  Conditional(&'a ConditionalType<'a>),
   InferType(SymbolId),
   Placeholder(usize),
 }
@@ -26,22 +27,42 @@ impl<'a> Analyzer<'a> {
     Ty::Unresolved(UnresolvedType::Placeholder(self.type_placeholder_count))
   }
 
+  /// Variables read before being initialized, e.g. by hoisted functions, have their types once
+  /// the initializers are executed.
+  pub fn resolve_uninit_variable(&self, ty: Ty<'a>) -> Ty<'a> {
+    match ty {
+      Ty::Unresolved(UnresolvedType::UnInitVariable(symbol)) => self.read_variable(symbol),
+      ty => ty,
+    }
+  }
+
   pub fn serialize_unresolved_type(&mut self, unresolved: UnresolvedType<'a>) -> TSType<'a> {
     match unresolved {
-      UnresolvedType::UnInitVariable(symbol) => todo!(),
-      UnresolvedType::UnInitType(symbol) => todo!(),
-      UnresolvedType::GenericParam(symbol) => self.ast_builder.ts_type_type_reference(
-        SPAN,
-        TSTypeName::IdentifierReference(
-          self.ast_builder.alloc(self.serialize_identifier_reference(symbol)),
-        ),
-        NONE,
-      ),
+      UnresolvedType::UnInitVariable(symbol) => {
+        let ty = match self.read_variable(symbol) {
+          Ty::Unresolved(UnresolvedType::UnInitVariable(_)) => {
+            self.unsupported("printing uninitialized variables")
+          }
+          ty => ty,
+        };
+        self.serialize_type(ty)
+      }
+      // Recursive type aliases are printed by name
+      UnresolvedType::UnInitType(symbol) | UnresolvedType::GenericParam(symbol) => {
+        self.ast_builder.ts_type_type_reference(
+          SPAN,
+          TSTypeName::IdentifierReference(
+            self.ast_builder.alloc(self.serialize_identifier_reference(symbol)),
+          ),
+          NONE,
+        )
+      }
       UnresolvedType::Keyof(ty) => self.ast_builder.ts_type_type_operator(
         SPAN,
         TSTypeOperatorOperator::Keyof,
         self.serialize_type(*ty),
       ),
+      UnresolvedType::Conditional(conditional) => self.serialize_conditional_type(conditional),
       UnresolvedType::InferType(symbol) => self.ast_builder.ts_type_infer_type(
         SPAN,
         self.ast_builder.ts_type_parameter(
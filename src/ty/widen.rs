@@ -23,6 +23,8 @@ impl<'a> Analyzer<'a> {
       Ty::BigIntLiteral(_) => Ty::BigInt,
       Ty::BooleanLiteral(_) => Ty::Boolean,
       Ty::UniqueSymbol(_) => Ty::Symbol,
+      // `E.A` is widened to `E`, not to its value
+      Ty::EnumMember(member) => self.get_enum_type(member),
 
       Ty::Record(_) | Ty::Interface(_) | Ty::Tuple(_) | Ty::Function(_) | Ty::Constructor(_) => ty,
 
@@ -38,7 +40,7 @@ impl<'a> Analyzer<'a> {
 
       Ty::Generic(_) | Ty::Intrinsic(_) | Ty::Namespace(_) => Ty::Error,
 
-      Ty::Unresolved(_) => todo!(),
+      Ty::Unresolved(_) => self.unsupported("widening unresolved types"),
     }
   }
 }
@@ -0,0 +1,7 @@
+function g(): number {
+  return "s";
+}
+
+const a: number = "s";
+
+const o: { p: number } = { p: "line\nbreak" };
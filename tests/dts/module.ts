@@ -0,0 +1,43 @@
+interface Options {
+  verbose: boolean;
+}
+
+export const version = 1;
+export let count = 0;
+const config = { name: "a", debug: false };
+export { config };
+
+export function configure(options: Options): void {}
+
+export function pad(text: string, width: number = 2) {
+  return text;
+}
+
+export function parse(input: string): number;
+export function parse(input: number): string;
+export function parse(input: any) {
+  return input;
+}
+
+export function identity<T>(value: T) {
+  return value;
+}
+
+export const alias = identity;
+
+export async function load(path: string) {
+  return path.length;
+}
+
+export const loadLater = async () => load("later");
+
+export enum Color {
+  Red,
+  Green = 2,
+}
+
+export namespace Shapes {
+  export const sides: number = 4;
+}
+
+export default version;
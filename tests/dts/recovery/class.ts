@@ -0,0 +1,13 @@
+import type { Options } from "./options";
+
+export function configure(options: Options): void {}
+
+export class Point {
+  x: number = 0;
+  y = 0;
+  #secret = 1;
+  constructor(x: number) {}
+  length(): number {
+    return 0;
+  }
+}
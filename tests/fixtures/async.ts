@@ -0,0 +1,30 @@
+async function load() {
+  return 1;
+}
+const a = load();
+//    ^? A
+
+const loadLater = async () => "text";
+const b = loadLater();
+//    ^? B
+
+async function nested() {
+  return load();
+}
+const c = nested();
+//    ^? C
+
+async function empty() {}
+const d = empty();
+//    ^? D
+
+declare const promise: Promise<boolean>;
+const run = async () => {
+  const e = await promise;
+  //    ^? E
+  const f = await load();
+  //    ^? F
+};
+
+const g = promise.finally(() => {});
+//    ^? G
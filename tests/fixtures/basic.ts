@@ -21,3 +21,11 @@ let e = null
 const f = 1
   f
 //^? F
+
+const g = -b
+  g
+//^? G
+
+const h = -d
+  h
+//^? H
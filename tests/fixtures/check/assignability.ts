@@ -0,0 +1,26 @@
+const a: number = "s";
+
+let b: string = "s";
+b = 1;
+
+function f(x: string) {}
+f(1);
+f("ok");
+
+function g(): number {
+  return "s";
+}
+
+const o: { p: number } = { p: "s" };
+
+const c: "a" | "b" = "a";
+const d: "a" = "c";
+
+function pair<T>(a: T, b: T) {}
+pair(1, "s");
+
+function h(a: string): void;
+function h(a: number): void;
+function h(a: any) {}
+h(true);
+h("ok");
@@ -15,3 +15,36 @@ type T5 = ((a: 1) => 2) extends ((a: any) => infer S) ? S : 2;
 
 type T6 = ((a: 1) => 2) extends ((a: infer S) => 2) ? S : 2;
 //   ^? T6
+
+type IsString<T> = T extends string ? true : 0;
+type T7 = IsString<"a" | 1>;
+//   ^? T7
+
+type Wrapped<T> = [T] extends [string] ? true : 0;
+type T8 = Wrapped<"a" | 1>;
+//   ^? T8
+
+type Boxed<T> = T extends string ? { value: T } : T;
+type T9 = Boxed<"a" | 1>;
+//   ^? T9
+
+type StringOrOne = "a" | 1;
+type T10 = StringOrOne extends string ? true : 0;
+//   ^? T10
+
+type T11 = IsString<never>;
+//   ^? T11
+
+type IsTrue<T> = T extends true ? "yes" : 0;
+type T12 = IsTrue<boolean>;
+//   ^? T12
+
+function f1<T>(value: T) {
+  const deferred = value as T extends string ? true : 0;
+  //    ^? D1
+  const wrapped = value as [T] extends [string] ? true : 0;
+  //    ^? D2
+  return deferred;
+}
+const t13 = f1("a");
+//    ^? T13
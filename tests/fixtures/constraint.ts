@@ -0,0 +1,26 @@
+function f1<T extends { id: string }>(x: T) {
+  const c1 = x.id;
+  //    ^? C1
+  const c2 = x?.id;
+  //    ^? C2
+}
+
+function f2<T extends { id: string }, U extends T>(y: U) {
+  const c3 = y.id;
+  //    ^? C3
+}
+
+function f3<T extends number>(n: T) {
+  const c4 = n + 1;
+  //    ^? C4
+}
+
+function f4<T extends string | undefined>(s: T) {
+  const c5 = s!;
+  //    ^? C5
+}
+
+function f5<F extends () => boolean>(f: F) {
+  const c6 = f();
+  //    ^? C6
+}
@@ -0,0 +1,35 @@
+const f1: (a: number) => void = (a) => {
+  const t1 = a;
+  //    ^? T1
+};
+
+declare function apply<T, U>(value: T, fn: (value: T) => U): U;
+
+const t2 = apply("s", (v) => v);
+//    ^? T2
+
+apply(1, function (v) {
+  const t3 = v;
+  //    ^? T3
+});
+
+const o: { m: (x: boolean) => void } = {
+  m(x) {
+    const t4 = x;
+    //    ^? T4
+  },
+};
+
+interface List<T> {
+  map<U>(fn: (value: T, index: number) => U): List<U>;
+}
+declare const items: List<{ id: number }>;
+const ids = items.map((x) => x.id);
+//                     ^? T5
+
+declare function each<T>(a: T, b: T, fn: (x: T) => void): void;
+declare const pair: [number, number];
+each(...pair, (y) => {
+  const t6 = y;
+  //    ^? T6
+});
@@ -0,0 +1,43 @@
+enum E {
+  A,
+  B,
+  C = "c",
+}
+
+type T1 = E;
+//   ^? T1
+
+type T2 = E.B;
+//   ^? T2
+
+const t3 = E.A;
+//    ^? T3
+
+let t4 = E.A;
+//  ^? T4
+
+function f(e: E) {
+  return e;
+}
+const t5 = f(E.C);
+//    ^? T5
+
+enum F {
+  A = -1,
+  B,
+}
+
+type T6 = F.B;
+//   ^? T6
+
+type T7 = E.C & string;
+//   ^? T7
+
+type T8 = E.C & { tag: 1 };
+//   ^? T8
+
+type T9 = E.A & F.A;
+//   ^? T9
+
+type T10 = E & 1;
+//   ^? T10
@@ -14,3 +14,12 @@ function f3<T>(): T {
 }
 const t3 = f3<3>()
 //    ^? T3
+
+function f4(a: { x: string }, b: { x: string; y: number }) {
+  if (a) {
+    return b;
+  }
+  return a;
+}
+const t4 = f4();
+//    ^? T4
@@ -0,0 +1,37 @@
+type Dict = { [key: string]: number };
+const dict: Dict;
+const i1 = dict.anything;
+//    ^? I1
+const i2 = dict;
+//    ^? I2
+
+const arr: { [key: number]: boolean };
+const i3 = arr[0];
+//    ^? I3
+
+interface Attrs {
+  id: string;
+  [key: `data-${string}`]: number;
+}
+const attrs: Attrs;
+const i4 = attrs["data-x"];
+//    ^? I4
+const i5 = attrs.id;
+//    ^? I5
+
+const pattern: { [key: `data-${string}`]: number };
+const i6 = pattern;
+//    ^? I6
+
+const keys: keyof Dict;
+const i7 = keys;
+//    ^? I7
+
+type Point = { readonly x: number };
+const mapped: { -readonly [K in keyof Point]?: Point[K] };
+const i8 = mapped;
+//    ^? I8
+
+const literal: { [K in "a"]: K };
+const i9 = literal;
+//    ^? I9
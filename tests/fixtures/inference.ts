@@ -0,0 +1,33 @@
+interface User {
+  name: string;
+}
+
+declare function identity<T>(value: T): T;
+declare function box<T>(value: T): { value: T };
+declare function first<T>(a: T, b: T): T;
+declare function useState<S>(initial: S | (() => S)): [S, (value: S) => void];
+declare function pick<T, K extends keyof T>(obj: T, k1: K, k2: K): { [P in K]: T[P] };
+declare function make<T = string>(): T;
+declare function bounded<T extends number>(): T;
+
+declare const user: User;
+
+const i1 = identity(1);
+//    ^? I1
+const i2 = box(1);
+//    ^? I2
+const i3 = first(null, user);
+//    ^? I3
+const state = useState(null as User | null);
+const i4 = state[0];
+//    ^? I4
+const obj = { a: 1, b: "s", c: true };
+const picked = pick(obj, "a", "b");
+const i5 = picked.a;
+//    ^? I5
+const i6 = picked.b;
+//    ^? I6
+const i7 = make();
+//    ^? I7
+const i8 = bounded();
+//    ^? I8
@@ -0,0 +1,42 @@
+interface A {
+  a: string;
+}
+
+interface B extends A {
+  b: number;
+}
+
+interface Box<T> {
+  value: T;
+}
+
+interface NumberBox extends Box<number> {}
+
+interface Callable {
+  (x: number): boolean;
+}
+
+function f(b: B, nb: NumberBox, sb: Box<string>, c: Callable) {
+  const i1 = b;
+  //    ^? I1
+  const i2 = b.a;
+  //    ^? I2
+  const i3 = nb.value;
+  //    ^? I3
+  const i4 = sb;
+  //    ^? I4
+  const i5 = sb.value;
+  //    ^? I5
+  const i6 = c(1);
+  //    ^? I6
+}
+
+interface C1 {
+  p: string;
+}
+
+interface C2 {
+  p: number;
+}
+
+interface C3 extends C1, C2 {}
@@ -21,3 +21,17 @@ const t5: string & ('a' | number)
 const t6: (1 | 2 | 'a' | 1n) & ('a' | 1 | bigint)
    t6
 // ^? T6
+
+const id: string & { __brand: "Id" };
+const t7 = id.__brand;
+//    ^? T7
+const t8 = id.length;
+//    ^? T8
+
+const both: { a: string; b: number } & { a: "x" };
+const t9 = both.a;
+//    ^? T9
+const t10 = both.b;
+//    ^? T10
+const t11 = id.toUpperCase();
+//    ^? T11
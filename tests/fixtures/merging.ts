@@ -0,0 +1,84 @@
+export {};
+
+interface Point {
+  x: number;
+}
+
+interface Point {
+  y: number;
+}
+
+interface Box<T> {
+  value: T;
+}
+
+interface Box<U> {
+  other: U;
+}
+
+interface Picker {
+  pick(x: string): string;
+}
+
+interface Picker {
+  pick(x: string): number;
+}
+
+namespace N {
+  export const a = 1;
+  export interface I {
+    i: boolean;
+  }
+}
+
+namespace N {
+  export function g(): string {
+    return "s";
+  }
+}
+
+function h(): number {
+  return 1;
+}
+
+namespace h {
+  export const extra = "e";
+}
+
+declare global {
+  interface Global {
+    g1: number;
+  }
+}
+
+declare global {
+  interface Global {
+    g2: string;
+  }
+}
+
+function f(p: Point, b: Box<string>, o: Picker, i: N.I, g: Global) {
+  const m1 = p.y;
+  //    ^? M1
+  const m2 = b.other;
+  //    ^? M2
+  const m3 = o.pick("s");
+  //    ^? M3
+  const m4 = i.i;
+  //    ^? M4
+  const m5 = g.g1;
+  //    ^? M5
+}
+
+const m6 = N.a;
+//    ^? M6
+const m7 = N.g();
+//    ^? M7
+const m8 = h.extra;
+//    ^? M8
+const m9 = h();
+//    ^? M9
+const m10 = N;
+//    ^? M10
+
+let missing: N.Missing;
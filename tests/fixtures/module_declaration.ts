@@ -0,0 +1,37 @@
+import { Options, version, create } from "lib";
+import * as lib from "lib";
+import { missing } from "lib";
+
+declare module "lib" {
+  export interface Options {
+    verbose: boolean;
+  }
+  export const version: string;
+  export function create(options: Options): number;
+}
+
+declare module "lib" {
+  export interface Options {
+    level: number;
+  }
+}
+
+declare const options: Options;
+const a = options.level;
+//    ^? A
+
+const b = options.verbose;
+//    ^? B
+
+const c = version;
+//    ^? C
+
+const d = create(options);
+//    ^? D
+
+declare const other: lib.Options;
+const e = other.level;
+//    ^? E
+
+const f = lib.version;
+//    ^? F
@@ -0,0 +1,66 @@
+declare const maybe: { value: number } | undefined;
+declare const text: string | undefined;
+declare const mixed: string | number | null;
+declare const flag: boolean;
+declare const input: unknown;
+
+if (maybe) {
+  const a = maybe;
+  //    ^? A
+} else {
+  const b = maybe;
+  //    ^? B
+}
+
+if (text !== undefined) {
+  const c = text;
+  //    ^? C
+}
+
+if (typeof mixed === "string") {
+  const d = mixed;
+  //    ^? D
+} else {
+  const e = mixed;
+  //    ^? E
+}
+
+if (mixed != null) {
+  const f = mixed;
+  //    ^? F
+}
+
+const g = maybe && maybe.value;
+//    ^? G
+
+const h = text === undefined ? "" : text;
+//    ^? H
+
+if (!flag) {
+  const i = flag;
+  //    ^? I
+}
+
+if (typeof input === "number") {
+  const j = input;
+  //    ^? J
+}
+
+function early(value: string | null) {
+  if (value === null) {
+    return "";
+  }
+  return value;
+}
+const k = early(null);
+//    ^? K
+
+let l: string | undefined;
+if (l) {
+  l = undefined;
+  const m = l;
+  //    ^? M
+}
+
+const n = text;
+//    ^? N
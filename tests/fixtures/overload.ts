@@ -0,0 +1,34 @@
+function f(a: string): 1;
+function f(a: number): 2;
+function f(a: any) {
+  return a;
+}
+
+const t1 = f("a");
+//    ^? T1
+
+const t2 = f(1);
+//    ^? T2
+
+const t3 = f(true);
+//    ^? T3
+
+const g: {
+  (a: string): "s";
+  (a: number): "n";
+};
+
+const t4 = g(1);
+//    ^? T4
+
+declare function on(event: "click", listener: (x: number) => void): void;
+declare function on(event: "key", listener: (x: string) => void): void;
+on("key", (x) => {
+  const t5 = x;
+  //    ^? T5
+});
+
+declare function call(a: string): "s";
+declare function call(a: () => void): "f";
+const t6 = call(() => {});
+//    ^? T6
@@ -0,0 +1,4 @@
+const t1 = /a/;
+//    ^? T1
+
+class A {}
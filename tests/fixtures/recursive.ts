@@ -0,0 +1,32 @@
+type Json = string | number | { [key: string]: Json };
+const json: Json;
+const r1 = json;
+//    ^? R1
+
+type JsonObject = { [key: string]: JsonObject | number };
+const object: JsonObject;
+const r2 = object.a;
+//    ^? R2
+
+type Tree = { left: Tree; value: number };
+const tree: Tree;
+const r3 = tree.left.left.value;
+//    ^? R3
+
+type DeepPartial<T> = T extends object ? { [K in keyof T]?: DeepPartial<T[K]> } : T;
+type R4 = DeepPartial<{ a: { b: string } }>;
+//   ^? R4
+
+type BuildTuple<N extends number, T extends unknown[] = []> = T["length"] extends N
+  ? T
+  : BuildTuple<N, [...T, unknown]>;
+type Add<A extends number, B extends number> = [...BuildTuple<A>, ...BuildTuple<B>]["length"];
+type R5 = Add<2, 3>;
+//   ^? R5
+
+type Loop<T> = Loop<[T]>;
+const loop: Loop<1>;
+const r6 = loop.value;
+//    ^? R6
+
+type Circular = Circular;
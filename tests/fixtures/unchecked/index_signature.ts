@@ -0,0 +1,5 @@
+const dict: { [key: string]: number; known: string };
+const u1 = dict.other;
+//    ^? U1
+const u2 = dict.known;
+//    ^? U2
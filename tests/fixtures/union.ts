@@ -9,3 +9,11 @@ let b: string | "a";
 let c: string | (bigint | symbol);
   c
 //^? C
+
+let d: { a: string } | { a: string };
+  d
+//^? D
+
+let e: true | false;
+  e
+//^? E
@@ -0,0 +1,22 @@
+declare const thenable: { then: (onfulfilled: (value: number) => void) => void };
+declare const value: number;
+declare const unknownValue: unknown;
+async function load() {
+  return 1;
+}
+
+async function main() {
+  await thenable;
+  await load();
+  await value;
+  await unknownValue;
+  await "literal";
+}
+
+interface SelfThenable {
+  then(onfulfilled: (value: SelfThenable) => void): void;
+}
+declare const selfThenable: SelfThenable;
+async function awaitSelf() {
+  const value = await selfThenable;
+}
@@ -0,0 +1,9 @@
+declare const value: unknown;
+declare function take(point: { x: number }): void;
+
+const a = <string>value;
+const b = value as string;
+const c = { x: 1 } as { x: number };
+const d = { x: 1 } as unknown;
+take({ x: 1 } as { x: number });
+const e = <const>["a"];
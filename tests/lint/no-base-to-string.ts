@@ -0,0 +1,11 @@
+declare const object: { value: number };
+declare const custom: { value: number; toString(): string };
+declare const maybe: { value: number } | string;
+declare const text: string;
+
+const a = `${object}`;
+const b = `${custom}`;
+const c = "value: " + object;
+const d = object.toString();
+const e = text + maybe;
+const f = `${text}`;
@@ -0,0 +1,15 @@
+declare function log(message: string): void;
+declare function read(): string;
+
+log("a");
+const a = log("b");
+const b = read() || log("c");
+const arrow = () => log("d");
+const block = () => {
+  log("e");
+};
+function f() {
+  return log("f");
+}
+void log("g");
+const g = read() ? log("h") : undefined;
@@ -0,0 +1,38 @@
+type Thenable = {
+  then: (onfulfilled: (value: number) => void, onrejected: (reason: unknown) => void) => void;
+};
+declare const thenable: Thenable;
+async function load() {
+  return 1;
+}
+const loadLater = async () => 2;
+function compute() {
+  return 1;
+}
+
+load();
+loadLater();
+thenable;
+compute();
+void load();
+thenable.then(() => {}, () => {});
+load().catch(() => {});
+
+const service = {
+  async fetch() {
+    return "data";
+  },
+};
+let pending = load();
+function fetchLater(): Promise<number> {
+  return load();
+}
+function run(promise: Promise<string>) {
+  promise;
+}
+
+service.fetch();
+pending;
+fetchLater();
+load().then(() => {});
+load().finally(() => {});
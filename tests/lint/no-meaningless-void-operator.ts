@@ -0,0 +1,7 @@
+declare function log(message: string): void;
+declare function read(): string;
+declare const nothing: undefined;
+
+void log("a");
+void read();
+void nothing;
@@ -0,0 +1,31 @@
+declare function onClick(listener: () => void): void;
+declare function onLoad(listener: () => number): void;
+async function handle() {
+  return 1;
+}
+declare const thenable: { then: (onfulfilled: (value: number) => void) => void };
+
+onClick(async () => {});
+onClick(handle);
+onClick(() => {});
+onLoad(async () => 1);
+if (thenable) {
+}
+const value = thenable ? 1 : 2;
+if (handle()) {
+}
+
+const service = {
+  async save() {},
+};
+let pending = handle();
+declare function fetchLater(): Promise<number>;
+function check(promise: Promise<void>) {
+  if (promise) {
+  }
+}
+
+onClick(service.save);
+onClick(fetchLater);
+if (pending) {
+}
@@ -0,0 +1,20 @@
+enum Numbers {
+  A,
+  B = 2,
+}
+
+enum Strings {
+  A = "a",
+  B = "b",
+}
+
+enum Mixed {
+  A,
+  B = "b",
+  C = 3,
+}
+
+enum StringFirst {
+  A = "a",
+  B = 1,
+}
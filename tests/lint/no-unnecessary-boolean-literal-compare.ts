@@ -0,0 +1,10 @@
+declare const flag: boolean;
+declare const maybe: boolean | undefined;
+declare const text: string;
+
+const a = flag === true;
+const b = flag !== false;
+const c = false === flag;
+const d = flag != true;
+const e = maybe === true;
+const f = text === "true";
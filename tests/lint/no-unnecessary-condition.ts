@@ -0,0 +1,37 @@
+declare const object: { value: number };
+declare const maybe: { value: number } | undefined;
+declare const flag: boolean;
+declare const nothing: undefined;
+declare const text: string;
+declare const maybeText: string | undefined;
+
+if (object) {
+}
+if (nothing) {
+}
+if (flag && object) {
+}
+if (maybe) {
+}
+const a = object && flag;
+const b = text ?? "default";
+const c = nothing ?? "default";
+object?.value;
+maybe?.value;
+const d = flag ? 1 : 2;
+declare const nested: { inner: { value: number } } | undefined;
+nested?.inner?.value;
+object /* ?. */?.value;
+if (maybe) {
+  if (maybe) {
+  }
+  maybe?.value;
+}
+if (maybe !== undefined) {
+  maybe?.value;
+} else {
+  maybe?.value;
+}
+if (typeof maybeText === "string") {
+  const e = maybeText ?? "default";
+}
@@ -0,0 +1,9 @@
+declare const text: string;
+declare const maybe: string | undefined;
+
+const a = text as string;
+const b = <string>text;
+const c = text!;
+const d = maybe!;
+const e = maybe as string;
+const f = text as unknown;
@@ -0,0 +1,14 @@
+declare const value: any;
+declare const nothing: undefined;
+declare function takeNumber(input: number): void;
+declare function takeUnknown(input: unknown): void;
+const broken = nothing.value;
+
+takeNumber(value);
+takeUnknown(value);
+takeNumber(broken);
+takeNumber(1);
+declare function takeBoth(first: number, second: number): void;
+declare const values: number[];
+takeBoth(value, ...values);
+takeBoth(...values, value);
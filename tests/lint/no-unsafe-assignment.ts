@@ -0,0 +1,15 @@
+declare const value: any;
+declare const nothing: undefined;
+const broken = nothing.value;
+
+const inferred = value;
+const annotated: number = value;
+const accepted: unknown = value;
+let target = 1;
+target = value;
+const fromError = broken;
+const fromCall = value();
+declare const text: string;
+const length = text.length;
+const upper = text.toUpperCase();
+const unmodeled = text.normalize();
@@ -0,0 +1,10 @@
+declare const value: any;
+declare const nothing: undefined;
+declare function run(): void;
+const broken = nothing.value;
+
+value();
+value.method();
+new value();
+broken();
+run();
@@ -0,0 +1,25 @@
+enum Fruit {
+  Apple,
+  Banana,
+}
+
+enum Vegetable {
+  Carrot,
+}
+
+enum Color {
+  Red = "red",
+  Blue = "blue",
+}
+
+declare const fruit: Fruit;
+declare const color: Color;
+declare const text: string;
+
+fruit === Fruit.Apple;
+fruit === 0;
+fruit === Vegetable.Carrot;
+color === "red";
+color !== Color.Blue;
+fruit < 1;
+text === "red";
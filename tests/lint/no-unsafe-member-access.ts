@@ -0,0 +1,9 @@
+declare const value: any;
+declare const nothing: undefined;
+declare const key: string;
+const broken = nothing.value;
+
+value.a;
+value.a.b;
+value[key];
+broken.a;
@@ -0,0 +1,16 @@
+declare const value: any;
+declare const nothing: undefined;
+
+function inferred() {
+  return value;
+}
+function annotated(): number {
+  return value;
+}
+function accepted(): unknown {
+  return value;
+}
+function broken() {
+  return nothing.value;
+}
+const arrow = () => value;
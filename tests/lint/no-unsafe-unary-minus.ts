@@ -0,0 +1,11 @@
+declare const count: number;
+declare const big: bigint;
+declare const text: string;
+declare const value: any;
+declare const mixed: number | string;
+
+const a = -count;
+const b = -big;
+const c = -text;
+const d = -value;
+const e = -mixed;
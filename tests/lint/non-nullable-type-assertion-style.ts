@@ -0,0 +1,8 @@
+declare const maybe: string | undefined;
+declare const nullable: number | null;
+declare const text: string;
+
+const a = maybe as string;
+const b = <number>nullable;
+const c = text as string;
+const d = maybe as "a";
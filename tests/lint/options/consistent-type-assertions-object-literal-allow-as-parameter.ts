@@ -0,0 +1,10 @@
+declare const value: unknown;
+declare function take(point: { x: number }): void;
+
+const a = <string>value;
+const b = value as string;
+const c = { x: 1 } as { x: number };
+const d = { x: 1 } as unknown;
+const e = value as const;
+take({ x: 1 } as { x: number });
+take(<{ x: number }>{ x: 1 });
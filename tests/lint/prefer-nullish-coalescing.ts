@@ -0,0 +1,18 @@
+declare const maybeText: string | undefined;
+declare const text: string;
+declare let maybeCount: number | null;
+
+const a = maybeText || "default";
+const b = text || "default";
+if (maybeText || text) {
+}
+maybeCount ||= 0;
+const c = maybeText ?? "default";
+const d = maybeText /* || */ || "default";
+if (maybeText !== undefined) {
+  const e = maybeText || "default";
+}
+if (maybeCount != null) {
+  const f = maybeCount || 0;
+}
+const g = maybeText && maybeText || "default";
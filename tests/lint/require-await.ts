@@ -0,0 +1,22 @@
+declare const thenable: { then: (onfulfilled: (value: number) => void) => void };
+
+async function noAwait() {
+  return 1;
+}
+async function withAwait() {
+  await thenable;
+}
+async function returnsThenable() {
+  return thenable;
+}
+async function nested() {
+  const inner = async () => {
+    await thenable;
+  };
+  return inner;
+}
+const arrow = async () => 1;
+const awaitingArrow = async () => await thenable;
+function sync() {
+  return 1;
+}
@@ -0,0 +1,12 @@
+declare const count: number;
+declare const text: string;
+declare const big: bigint;
+declare const sym: symbol;
+declare const value: any;
+declare let total: number;
+
+const a = count + text;
+const b = text + sym;
+const c = count + big;
+const d = value + count;
+total += big;
@@ -0,0 +1,8 @@
+declare const count: number;
+declare const sym: symbol;
+declare const maybe: unknown;
+declare const flag: boolean;
+
+const a = `${count} ${flag}`;
+const b = `${sym}`;
+const c = `${maybe} and ${count}`;
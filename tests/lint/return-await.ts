@@ -0,0 +1,30 @@
+declare const thenable: { then: (onfulfilled: (value: number) => void) => void };
+async function load() {
+  return 1;
+}
+
+async function inTry() {
+  try {
+    return load();
+  } catch {
+    return thenable;
+  }
+}
+async function inCatchWithFinally() {
+  try {
+    return await load();
+  } catch {
+    return thenable;
+  } finally {
+  }
+}
+async function outsideTry() {
+  return load();
+}
+function notAsync() {
+  try {
+    return thenable;
+  } catch {
+    return 1;
+  }
+}
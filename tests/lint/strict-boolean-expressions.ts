@@ -0,0 +1,47 @@
+declare const flag: boolean;
+declare const maybeFlag: boolean | undefined;
+declare const text: string;
+declare const maybeText: string | null;
+declare const count: number;
+declare const maybeCount: number | undefined;
+declare const object: { value: number };
+declare const maybeObject: { value: number } | undefined;
+declare const value: any;
+declare const mixed: string | number;
+
+if (flag) {
+}
+if (maybeFlag) {
+}
+if (text && count) {
+}
+if (maybeText) {
+}
+if (maybeCount) {
+}
+if (object) {
+}
+if (maybeObject) {
+}
+if (value) {
+}
+if (mixed) {
+}
+const a = !maybeFlag;
+const b = maybeText || "default";
+if (maybeText !== null) {
+  if (maybeText) {
+  }
+}
+if (maybeCount === undefined) {
+} else if (maybeCount) {
+}
+if (typeof maybeFlag === "boolean" && maybeFlag) {
+}
+function guarded(value: string | null) {
+  if (value === null) {
+    return;
+  }
+  if (value) {
+  }
+}
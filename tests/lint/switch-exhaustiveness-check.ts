@@ -0,0 +1,31 @@
+type Direction = "up" | "down" | "left";
+declare const direction: Direction;
+declare const flag: boolean;
+declare const text: string;
+
+switch (direction) {
+  case "up":
+    break;
+}
+
+switch (direction) {
+  case "up":
+  case "down":
+  case "left":
+    break;
+}
+
+switch (flag) {
+  case true:
+    break;
+}
+
+switch (direction) {
+  default:
+    break;
+}
+
+switch (text) {
+  case "a":
+    break;
+}
@@ -1,21 +1,32 @@
-use std::{fs, sync::LazyLock};
+use std::{fs, rc::Rc, sync::LazyLock};
 
 use insta::{assert_snapshot, glob, Settings};
+use line_index::LineIndex;
 use oxc::{
   allocator::Allocator,
   ast::{ast::Statement, NONE},
   codegen::Codegen,
+  parser::Parser,
+  semantic::SemanticBuilder,
   span::{SourceType, SPAN},
 };
 use regex::Regex;
-use simple_ts::{analyze, Config};
+use simple_ts::{
+  analyze, emit_dts,
+  lint::{
+    get_rule,
+    rules::{AssertionStyle, ConsistentTypeAssertions, ObjectLiteralTypeAssertions},
+    Rule, TypeAwareLinter,
+  },
+  Config, Diagnostic,
+};
 
 static TYPE_QUERY_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\^\? (\w+)").unwrap());
 
-pub fn serialize_queried_types(code: String) -> String {
+pub fn serialize_queried_types(code: String, config: Config) -> String {
   let allocator = Allocator::default();
   let code = allocator.alloc(code);
-  let mut analyzer = analyze(&allocator, code, Config::default());
+  let mut analyzer = analyze(&allocator, code, config);
   let codegen = Codegen::new();
 
   let mut snapshot_stmts = analyzer.ast_builder.vec();
@@ -37,7 +48,7 @@ pub fn serialize_queried_types(code: String) -> String {
     )));
   }
 
-  codegen
+  let mut output = codegen
     .build(&analyzer.ast_builder.program(
       SPAN,
       SourceType::tsx(),
@@ -47,7 +58,131 @@ pub fn serialize_queried_types(code: String) -> String {
       analyzer.ast_builder.vec(),
       snapshot_stmts,
     ))
-    .code
+    .code;
+  output.push_str(&serialize_diagnostics(analyzer.sorted_diagnostics(), &analyzer.line_index));
+  output
+}
+
+pub fn serialize_type_queries(code: String) -> String {
+  let allocator = Allocator::default();
+  let code = allocator.alloc(code);
+  let mut analyzer = analyze(&allocator, code, Config::default());
+
+  let mut output = String::new();
+  for query in TYPE_QUERY_RE.find_iter(code) {
+    let name = &query.as_str()[3..];
+    let mut line_col = analyzer.line_index.line_col(query.start().try_into().unwrap());
+    line_col.line -= 1;
+    let offset: usize = analyzer.line_index.offset(line_col).unwrap().into();
+    let span = analyzer.pos_to_span[offset];
+    let ty = analyzer.type_of_span(span).unwrap_or_else(|| {
+      panic!("Type query `{}` at {}:{} not found", name, line_col.line + 1, line_col.col + 1)
+    });
+    output.push_str(&format!("{name}: {}\n", ty.print(&mut analyzer)));
+
+    let predicates = [
+      ("any", ty.is_any()),
+      ("error", ty.is_error()),
+      ("unknown", ty.is_unknown()),
+      ("never", ty.is_never()),
+      ("nullable", ty.is_nullable(&mut analyzer)),
+      ("string-like", ty.is_string_like(&mut analyzer)),
+      ("number-like", ty.is_number_like(&mut analyzer)),
+      ("boolean-like", ty.is_boolean_like(&mut analyzer)),
+      ("callable", ty.is_callable(&mut analyzer)),
+      ("promise-like", ty.is_promise_like(&mut analyzer)),
+    ];
+    let predicates =
+      predicates.into_iter().filter(|(_, holds)| *holds).map(|(name, _)| name).collect::<Vec<_>>();
+    if !predicates.is_empty() {
+      output.push_str(&format!("  is: {}\n", predicates.join(", ")));
+    }
+
+    if let Some(contextual) = analyzer.contextual_type_of_span(span) {
+      output.push_str(&format!("  contextual: {}\n", contextual.print(&mut analyzer)));
+    }
+
+    if let Some(signature) = analyzer.resolved_signature(&span).cloned() {
+      let mut params = vec![];
+      for index in 0..signature.param_count() {
+        let optional = if signature.is_param_optional(index) { "?" } else { "" };
+        let ty = signature.param(index).unwrap().print(&mut analyzer);
+        params.push(format!("a{index}{optional}: {ty}"));
+      }
+      if let Some(rest) = signature.rest_param() {
+        params.push(format!("...rest: {}", rest.print(&mut analyzer)));
+      }
+      let ret = signature.return_type().print(&mut analyzer);
+      output.push_str(&format!("  signature: ({}) => {ret}\n", params.join(", ")));
+    }
+  }
+  output
+}
+
+pub fn emit_declarations(code: String, config: Config) -> String {
+  let allocator = Allocator::default();
+  let code = allocator.alloc(code);
+  let (mut output, analyzer) = emit_dts(&allocator, code, config);
+  output.push_str(&serialize_diagnostics(analyzer.sorted_diagnostics(), &analyzer.line_index));
+  output
+}
+
+pub fn render_diagnostics(code: String, file_name: &str) -> String {
+  let allocator = Allocator::default();
+  let code = allocator.alloc(code);
+  let config = Config { check_assignability: true, ..Default::default() };
+  let analyzer = analyze(&allocator, code, config);
+  let mut output = analyzer.render_diagnostics(file_name);
+  output.push_str(&analyzer.diagnostics_to_json());
+  output.push('\n');
+  output
+}
+
+pub fn lint(code: String, rule: Box<dyn Rule>) -> String {
+  let allocator = Allocator::default();
+  let code = allocator.alloc(code);
+  // Parsed here, as a linter would do
+  let parsed = allocator.alloc(Parser::new(&allocator, code, SourceType::ts()).parse());
+  let semantic = SemanticBuilder::new().build(&parsed.program).semantic;
+  let linter = TypeAwareLinter::new(vec![rule]);
+  let diagnostics = linter.run(&allocator, &parsed.program, Rc::new(semantic));
+  serialize_diagnostics(&diagnostics, &LineIndex::new(code))
+}
+
+fn serialize_diagnostics<'d>(
+  diagnostics: impl IntoIterator<Item = &'d Diagnostic>,
+  line_index: &LineIndex,
+) -> String {
+  let mut output = String::new();
+  for diagnostic in diagnostics {
+    let start = line_index.line_col(diagnostic.span.start.into());
+    let end = line_index.line_col(diagnostic.span.end.into());
+    output.push_str(&format!(
+      "// {} {} at {}:{}-{}:{}\n",
+      diagnostic.code,
+      diagnostic.message,
+      start.line + 1,
+      start.col + 1,
+      end.line + 1,
+      end.col + 1
+    ));
+    if let Some(fix) = &diagnostic.fix {
+      output.push_str(&format!("//   fix: {}\n", fix.message));
+      for edit in &fix.edits {
+        let start = line_index.line_col(edit.span.start.into());
+        let end = line_index.line_col(edit.span.end.into());
+        output.push_str(&format!(
+          "//     {}:{}-{}:{} -> {:?}\n",
+          start.line + 1,
+          start.col + 1,
+          end.line + 1,
+          end.col + 1,
+          edit.replacement
+        ));
+      }
+    }
+  }
+  output
 }
 
 #[test]
@@ -55,11 +190,120 @@ fn test() {
   glob!("fixtures/**/*.ts", |path| {
     println!("Testing {}", path.display());
     let input = fs::read_to_string(path).unwrap();
+    // Fixtures in `check/` are analyzed in checker mode, those in `recovery/` with error recovery,
+    // and those in `unchecked/` with `noUncheckedIndexedAccess`
+    let dir = path.parent().unwrap();
+    let config = Config {
+      check_assignability: dir.ends_with("check"),
+      error_recovery: dir.ends_with("recovery"),
+      no_unchecked_indexed_access: dir.ends_with("unchecked"),
+      ..Default::default()
+    };
+    let mut settings = Settings::clone_current();
+    settings.set_omit_expression(true);
+    settings.set_prepend_module_to_snapshot(false);
+    settings.bind(|| {
+      assert_snapshot!(serialize_queried_types(input, config));
+    })
+  });
+}
+
+#[test]
+fn query() {
+  glob!("query/**/*.ts", |path| {
+    println!("Querying {}", path.display());
+    let input = fs::read_to_string(path).unwrap();
+    let mut settings = Settings::clone_current();
+    settings.set_omit_expression(true);
+    settings.set_prepend_module_to_snapshot(false);
+    settings.bind(|| {
+      assert_snapshot!(serialize_type_queries(input));
+    })
+  });
+}
+
+#[test]
+fn lint_rules() {
+  glob!("lint/*.ts", |path| {
+    println!("Linting {}", path.display());
+    let input = fs::read_to_string(path).unwrap();
+    // Fixtures are named after the rule
+    let rule = path.file_stem().unwrap().to_str().unwrap();
+    let rule = get_rule(rule).unwrap_or_else(|| panic!("Rule `{rule}` not found"));
+    let mut settings = Settings::clone_current();
+    settings.set_omit_expression(true);
+    settings.set_prepend_module_to_snapshot(false);
+    settings.bind(|| {
+      assert_snapshot!(lint(input, rule));
+    })
+  });
+}
+
+#[test]
+fn lint_rule_options() {
+  glob!("lint/options/*.ts", |path| {
+    println!("Linting {}", path.display());
+    let input = fs::read_to_string(path).unwrap();
+    // Fixtures are named after the rule and its options
+    let rule: Box<dyn Rule> = match path.file_stem().unwrap().to_str().unwrap() {
+      "consistent-type-assertions-angle-bracket" => Box::new(ConsistentTypeAssertions {
+        assertion_style: AssertionStyle::AngleBracket,
+        ..Default::default()
+      }),
+      "consistent-type-assertions-never" => Box::new(ConsistentTypeAssertions {
+        assertion_style: AssertionStyle::Never,
+        object_literal_type_assertions: ObjectLiteralTypeAssertions::Never,
+      }),
+      "consistent-type-assertions-object-literal-never" => Box::new(ConsistentTypeAssertions {
+        object_literal_type_assertions: ObjectLiteralTypeAssertions::Never,
+        ..Default::default()
+      }),
+      "consistent-type-assertions-object-literal-allow-as-parameter" => {
+        Box::new(ConsistentTypeAssertions {
+          object_literal_type_assertions: ObjectLiteralTypeAssertions::AllowAsParameter,
+          ..Default::default()
+        })
+      }
+      name => panic!("Options `{name}` not found"),
+    };
+    let mut settings = Settings::clone_current();
+    settings.set_omit_expression(true);
+    settings.set_prepend_module_to_snapshot(false);
+    settings.bind(|| {
+      assert_snapshot!(lint(input, rule));
+    })
+  });
+}
+
+#[test]
+fn diagnostics() {
+  glob!("diagnostics/*.ts", |path| {
+    println!("Rendering {}", path.display());
+    let input = fs::read_to_string(path).unwrap();
+    let file_name = path.file_name().unwrap().to_str().unwrap();
+    let mut settings = Settings::clone_current();
+    settings.set_omit_expression(true);
+    settings.set_prepend_module_to_snapshot(false);
+    settings.bind(|| {
+      assert_snapshot!(render_diagnostics(input, file_name));
+    })
+  });
+}
+
+#[test]
+fn dts() {
+  glob!("dts/**/*.ts", |path| {
+    println!("Emitting {}", path.display());
+    let input = fs::read_to_string(path).unwrap();
+    // Classes and imports from other files are not analyzed, so their fixtures in `recovery/` are
+    // emitted with error recovery
+    let dir = path.parent().unwrap();
+    let config = Config { error_recovery: dir.ends_with("recovery"), ..Default::default() };
     let mut settings = Settings::clone_current();
     settings.set_omit_expression(true);
     settings.set_prepend_module_to_snapshot(false);
     settings.bind(|| {
-      assert_snapshot!(serialize_queried_types(input));
+      assert_snapshot!(emit_declarations(input, config));
     })
   });
 }
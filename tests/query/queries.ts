@@ -0,0 +1,16 @@
+declare function parse(input: string): number;
+declare function parse(input: number): string;
+declare const maybe: string | undefined;
+declare const thenable: { then: (onfulfilled: (value: number) => void) => void };
+
+const a = parse(1);
+//             ^? A
+
+const b = maybe;
+//        ^? B
+
+const c = thenable;
+//        ^? C
+
+const d: number = 1;
+//                ^? D